@@ -0,0 +1,68 @@
+//! `animation` example.
+//!
+//! Loops a 4-frame spinner with [`NcAnimation`].
+//!
+//! Use space to pause and resume, `+` and `-` to change the speed,
+//! and 'q' to quit.
+//!
+//! Needs the `animation` feature:
+//! `cargo run --example animation --features animation`
+
+use libnotcurses_sys::*;
+
+/// The side of the frames, in pixels.
+const SIDE: u32 = 4;
+
+fn main() -> NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let stdplane = unsafe { nc.stdplane() };
+    stdplane.putstr_yx(
+        Some(0),
+        Some(0),
+        "space: pause/resume | +/-: speed | q: quit",
+    )?;
+
+    // a lit quadrant going around clockwise
+    let mut frames = vec![];
+    for (qy, qx) in [(0, 0), (0, 1), (1, 1), (1, 0)] {
+        let mut rgba = vec![];
+        for y in 0..SIDE {
+            for x in 0..SIDE {
+                let lit = y / (SIDE / 2) == qy && x / (SIDE / 2) == qx;
+                rgba.extend(if lit { [0xFF, 0xAA, 0x00, 0xFF] } else { [0x22, 0x22, 0x44, 0xFF] });
+            }
+        }
+        frames.push(NcVisual::from_rgba(&rgba, SIDE, SIDE * 4, SIDE)?);
+    }
+
+    let plane = NcPlane::new_child_sized(stdplane, 2, 2, SIDE / 2, SIDE)?;
+    let mut animation = plane.bind_animation(frames, 4.0)?;
+    animation.play();
+
+    let mut input = NcInput::new_empty();
+    loop {
+        if animation.update(nc)? {
+            nc.render()?;
+        }
+        let received = nc.get(Some(NcTime::new(0, 10_000_000)), Some(&mut input))?;
+        if input.evtype == NcInputType::Release as u32 {
+            continue;
+        }
+        match received {
+            NcReceived::Char(' ') if animation.is_playing() => animation.pause(),
+            NcReceived::Char(' ') => animation.play(),
+            NcReceived::Char('+') => animation.set_fps((animation.fps() * 2.0).min(64.0))?,
+            NcReceived::Char('-') => animation.set_fps((animation.fps() / 2.0).max(0.5))?,
+            NcReceived::Char('q') => break,
+            _ => (),
+        }
+    }
+
+    let (plane, frames) = animation.stop();
+    plane.destroy()?;
+    for frame in frames {
+        frame.destroy();
+    }
+    unsafe { nc.stop()? };
+    Ok(())
+}
@@ -46,6 +46,10 @@ fn main() -> NcResult<()> {
                     _ => (),
                 }
             }
+            NcReceived::Resize => {
+                let (rows, cols) = nc.refresh()?;
+                putstrln!(splane, "resized: {0}x{1}\n", rows, cols)?;
+            }
             NcReceived::NoInput => (),
         }
     }
@@ -0,0 +1,55 @@
+//! `NcMenuBar` example.
+//!
+//! Shows a menu bar that can be navigated with the keyboard.
+//!
+//! press Alt+f or Alt+h to unroll a section, Enter to select an item,
+//! and Ctrl+q or 'q' to quit at any moment.
+
+use libnotcurses_sys::{widgets::*, *};
+
+fn main() -> NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+
+    let mut bar = NcMenuBar::new(
+        nc,
+        &[
+            NcMenuBarItem::new("File")
+                .shortcut(NcInput::with_alt('f'))
+                .submenu(vec![
+                    NcMenuBarItem::new("New").shortcut(NcInput::with_ctrl('n')),
+                    NcMenuBarItem::new("Open").shortcut(NcInput::with_ctrl('o')),
+                    NcMenuBarItem::separator(),
+                    NcMenuBarItem::new("Quit").shortcut(NcInput::with_ctrl('q')),
+                ]),
+            NcMenuBarItem::new("Help")
+                .shortcut(NcInput::with_alt('h'))
+                .submenu(vec![NcMenuBarItem::new("About")]),
+        ],
+    )?;
+
+    let mut input = NcInput::new_empty();
+    loop {
+        let stdplane = unsafe { bar.nc().stdplane() };
+        bar.nc().render()?;
+
+        let received = bar.nc().get_blocking(Some(&mut input))?;
+        if input.evtype == NcInputType::Release as u32 {
+            continue;
+        }
+        let selected = bar.handle_input(&input).map(String::from);
+
+        stdplane.erase();
+        match selected.as_deref() {
+            Some("Quit") => break,
+            Some(item) => {
+                stdplane.putstr_yx(Some(2), Some(0), &format!["selected: {item}"])?;
+            }
+            None if received == NcReceived::Char('q') => break,
+            None => (),
+        }
+    }
+
+    drop(bar);
+    unsafe { nc.stop()? };
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! `render_diff` example.
+//!
+//! Compares the time it takes to show a partially changed plane by copying
+//! all of its cells, and by applying only the cells that changed, with
+//! `NcPlane.diff` and `NcPlane.apply_diff`.
+//!
+//! The content is drawn off-screen, in its own pile, and copied to the
+//! standard plane before rendering each frame.
+
+use libnotcurses_sys::*;
+use std::time::{Duration, Instant};
+
+/// The number of frames rendered with each method.
+const FRAMES: u32 = 200;
+
+/// The number of cells changed on each frame.
+const CHANGED: u32 = 20;
+
+fn main() -> NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let stdplane = unsafe { nc.stdplane() };
+    let (rows, cols) = stdplane.dim_yx();
+
+    let model = NcPlane::new_pile_sized(nc, 0, 0, rows, cols)?;
+    for y in 0..rows {
+        let line: String = (0..cols)
+            .map(|x| (b'a' + ((x + y) % 26) as u8) as char)
+            .collect();
+        model.putstr_yx(Some(y), Some(0), &line)?;
+    }
+    let mut seed = 0x2545_F491_u32;
+
+    // copies every cell, on every frame
+    let full = time(|| {
+        for _ in 0..FRAMES {
+            change(model, &mut seed)?;
+            // a snapshot of another size makes diff return every cell
+            let all = model.diff(&NcPlaneSnapshot::default())?;
+            stdplane.apply_diff(&all)?;
+            nc.render()?;
+        }
+        Ok(())
+    })?;
+
+    // copies only the cells changed since the last frame
+    let mut previous = model.snapshot()?;
+    let diffed = time(|| {
+        for _ in 0..FRAMES {
+            change(model, &mut seed)?;
+            let changed = model.diff(&previous)?;
+            stdplane.apply_diff(&changed)?;
+            nc.render()?;
+            previous = model.snapshot()?;
+        }
+        Ok(())
+    })?;
+
+    model.destroy()?;
+    unsafe { nc.stop()? };
+
+    println!["{FRAMES} frames of {rows}x{cols} cells, {CHANGED} cells changed per frame:"];
+    println!["  full copy:  {:?}", full];
+    println!["  diff-apply: {:?}", diffed];
+    Ok(())
+}
+
+/// Returns the time it takes to run `f`.
+fn time(mut f: impl FnMut() -> NcResult<()>) -> NcResult<Duration> {
+    let start = Instant::now();
+    f()?;
+    Ok(start.elapsed())
+}
+
+/// Writes a random letter in `CHANGED` random cells of the `plane`.
+fn change(plane: &mut NcPlane, seed: &mut u32) -> NcResult<()> {
+    let (rows, cols) = plane.dim_yx();
+    for _ in 0..CHANGED {
+        let (y, x, c) = (next(seed) % rows, next(seed) % cols, next(seed) % 26);
+        plane.putchar_yx(y, x, (b'A' + c as u8) as char)?;
+    }
+    Ok(())
+}
+
+/// A xorshift pseudo-random number generator.
+fn next(seed: &mut u32) -> u32 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 17;
+    *seed ^= *seed << 5;
+    *seed
+}
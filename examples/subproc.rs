@@ -0,0 +1,29 @@
+//! `subproc` example.
+//!
+//! Runs `ls` and shows its output in a plane.
+//!
+//! press any key to quit.
+
+use libnotcurses_sys::*;
+
+fn main() -> NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let stdplane = unsafe { nc.stdplane() };
+    let (rows, cols) = stdplane.dim_yx();
+
+    stdplane.putstr_yx(Some(0), Some(0), "$ ls -l")?;
+    let plane = NcPlane::new_child_sized(stdplane, 1, 0, rows - 1, cols)?;
+
+    let subproc = NcSubproc::new(plane, "ls", &["-l"])?;
+    loop {
+        nc.render()?;
+        if nc.get_nblock(None)? != NcReceived::NoInput {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    subproc.destroy()?;
+
+    unsafe { nc.stop()? };
+    Ok(())
+}
@@ -0,0 +1,180 @@
+//! `tui_demo` example.
+//!
+//! Shows several widgets working together in a full-screen application:
+//! a menu bar, a selector on the left, a content plane on the right, and a
+//! status bar with a spinner and a progress bar at the bottom.
+//!
+//! Use the arrow keys to move in the selector and Enter to show an item,
+//! Alt+f or Alt+h to unroll the menus, and 'q' to quit at any moment.
+
+use libnotcurses_sys::{widgets::*, *};
+
+const TOPICS: [(&str, &str); 4] = [
+    ("Planes", "stacked surfaces"),
+    ("Colors", "RGB & palettes"),
+    ("Boxes", "box-drawing glyphs"),
+    ("Widgets", "menus & selectors"),
+];
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// The width of the selector.
+const LEFT_COLS: u32 = 30;
+
+fn main() -> NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let stdplane = unsafe { nc.stdplane() };
+    let (rows, cols) = stdplane.dim_yx();
+    if rows < 12 || cols < LEFT_COLS + 20 {
+        unsafe { nc.stop()? };
+        return Err(NcError::new_msg("the terminal is too small"));
+    }
+
+    let mut bar = NcMenuBar::new(
+        nc,
+        &[
+            NcMenuBarItem::new("File")
+                .shortcut(NcInput::with_alt('f'))
+                .submenu(vec![
+                    NcMenuBarItem::new("Quit").shortcut(NcInput::with_ctrl('q'))
+                ]),
+            NcMenuBarItem::new("Help")
+                .shortcut(NcInput::with_alt('h'))
+                .submenu(vec![NcMenuBarItem::new("About")]),
+        ],
+    )?;
+
+    let mut selector = new_selector(stdplane, rows, 0)?;
+
+    let content = NcPlane::new_child_sized(stdplane, 2, LEFT_COLS as i32 + 1, 1, 1)?;
+    let progbar_plane = NcPlane::new_child_sized(stdplane, 0, 0, 1, 1)?;
+    let progbar = NcProgBar::new(progbar_plane);
+    let status = NcPlane::new_child_sized(stdplane, 0, 0, 1, 1)?;
+    let status_channels = NcChannels::from_rgb(0xFFFFFF, 0x204080);
+    status.set_channels(status_channels);
+    status.set_base(" ", NcStyle::None, status_channels)?;
+    layout(content, progbar.plane(), status, rows, cols)?;
+
+    let mut shown = ("Welcome".to_string(), "Select a topic on the left.");
+    show(content, &shown.0, shown.1)?;
+
+    let mut input = NcInput::new_empty();
+    let mut last = String::from("nothing selected");
+    let mut tick = 0_usize;
+    loop {
+        tick += 1;
+        progbar.set_progress((tick % 101) as f64 / 100.0)?;
+        status.erase();
+        status.putstr_yx(
+            Some(0),
+            Some(0),
+            &format![" {} {} | q: quit", SPINNER[tick % SPINNER.len()], last],
+        )?;
+        bar.nc().render()?;
+
+        let time = NcTime::new(0, 100_000_000);
+        let received = bar.nc().get(Some(time), Some(&mut input))?;
+        if received == NcReceived::NoInput || input.evtype == NcInputType::Release as u32 {
+            continue;
+        }
+        if input.is_resize() {
+            let (rows, cols) = bar.nc().refresh()?;
+            // the selector sizes its own plane, so it's created again to fit
+            let selected = selector
+                .selected_item()
+                .and_then(|topic| TOPICS.iter().position(|(o, _)| *o == topic))
+                .unwrap_or(0);
+            selector.destroy()?;
+            selector = new_selector(stdplane, rows, selected as u32)?;
+            layout(content, progbar.plane(), status, rows, cols)?;
+            show(content, &shown.0, shown.1)?;
+            last = format!["resized to {rows}x{cols}"];
+            continue;
+        }
+
+        // an unrolled menu gets all the input
+        let menu_unrolled = bar.selected().is_some();
+        match bar.handle_input(&input) {
+            Some("Quit") => break,
+            Some("About") => {
+                shown = ("About".into(), "A demo of libnotcurses-sys widgets.");
+                show(content, &shown.0, shown.1)?;
+                last = "About".into();
+                continue;
+            }
+            _ if menu_unrolled => continue,
+            _ => (),
+        }
+
+        let result = selector.handle_input(&input);
+        if let (true, Some(topic)) = (result.accepted, &result.selected) {
+            let desc = TOPICS
+                .iter()
+                .find(|(o, _)| *o == topic.as_str())
+                .map_or("", |t| t.1);
+            shown = (topic.clone(), desc);
+            show(content, &shown.0, shown.1)?;
+            last = format!["showing {topic}"];
+        } else if received == NcReceived::Char('q') {
+            break;
+        }
+    }
+
+    progbar.destroy();
+    selector.destroy()?;
+    drop(bar);
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+/// Creates the selector on the left, showing as many topics as fit in a
+/// terminal of `rows`, with the `selected` topic.
+fn new_selector<'a>(
+    stdplane: &mut NcPlane,
+    rows: u32,
+    selected: u32,
+) -> NcResult<&'a mut NcSelector> {
+    let plane: &'a mut NcPlane =
+        NcPlane::new_child_sized(stdplane, 2, 0, rows.saturating_sub(5).max(1), LEFT_COLS)?;
+    // the box, the title and the footer take 4 rows
+    let mut builder = NcSelector::builder()
+        .title("topics")
+        .footer("Enter: show")
+        .default_item(selected)
+        .max_display(rows.saturating_sub(9).max(1));
+    for (option, desc) in TOPICS {
+        builder = builder.item(option, desc);
+    }
+    builder.finish(plane)
+}
+
+/// Resizes the content plane on the right, and places the progress bar and
+/// the status bar at the bottom, for a terminal of `rows`×`cols`.
+fn layout(
+    content: &mut NcPlane,
+    progbar: &mut NcPlane,
+    status: &mut NcPlane,
+    rows: u32,
+    cols: u32,
+) -> NcResult<()> {
+    let right_cols = cols.saturating_sub(LEFT_COLS + 1).max(1);
+    content.resize_simple(rows.saturating_sub(5).max(5), right_cols)?;
+
+    progbar.resize_simple(1, cols)?;
+    progbar.move_yx(rows as i32 - 2, 0)?;
+
+    status.resize_simple(1, cols)?;
+    status.move_yx(rows as i32 - 1, 0)?;
+    Ok(())
+}
+
+/// Shows a `title` and a `text` inside the content plane.
+fn show(content: &mut NcPlane, title: &str, text: &str) -> NcResult<()> {
+    content.erase();
+    content.perimeter_rounded(NcStyle::None, NcChannels::new(), NcBoxMask::None)?;
+    content.set_styles(NcStyle::Bold);
+    content.putstr_yx(Some(1), Some(2), title)?;
+    content.set_styles(NcStyle::None);
+    content.putstr_yx(Some(3), Some(2), text)?;
+    Ok(())
+}
@@ -379,6 +379,7 @@ pub use ffi::{
     ncplane_move_family_above,
     ncplane_move_family_below,
     ncplane_move_yx,
+    ncplane_name,
     ncplane_notcurses,
     ncplane_notcurses_const,
     ncplane_off_styles,
@@ -416,11 +417,14 @@ pub use ffi::{
     ncplane_set_bg_palindex,
     ncplane_set_bg_rgb,
     ncplane_set_bg_rgb8,
+    ncplane_set_bg_rgb8_clipped,
     ncplane_set_fg_alpha,
     ncplane_set_fg_default,
     ncplane_set_fg_palindex,
     ncplane_set_fg_rgb,
     ncplane_set_fg_rgb8,
+    ncplane_set_fg_rgb8_clipped,
+    ncplane_set_name,
     ncplane_set_resizecb,
     ncplane_set_scrolling,
     ncplane_set_styles,
@@ -791,7 +795,7 @@ pub use ffi::{
     notcurses_str_blitter,
     notcurses_str_scalemode,
     notcurses_supported_styles,
-    // notcurses_ucs32_to_utf8,
+    notcurses_ucs32_to_utf8,
     notcurses_version,
     notcurses_version_components,
 };
@@ -3,7 +3,7 @@
 use crate::{
     c_api::{self, nccell_load, NcChannels_u64, NCRESULT_ERR},
     cstring, error, rstring, NcAlpha, NcCell, NcChannel, NcChannels, NcError, NcPaletteIndex,
-    NcPlane, NcResult, NcRgb, NcStyle,
+    NcPlane, NcResult, NcRgb, NcRgba, NcStyle,
 };
 
 #[cfg(not(feature = "std"))]
@@ -103,13 +103,34 @@ impl NcCell {
     ///
     /// Both must be or will be bound to `common_plane`.
     ///
+    /// An `EGC` longer than 4 bytes is stored in the plane's pool, and
+    /// the cell only holds an offset into it, so a cell must only be used
+    /// with the plane it's bound to. Use [`duplicate_into`] to copy a cell
+    /// to a different plane.
+    ///
     /// *C style function: [nccell_duplicate()][c_api::nccell_duplicate].*
+    ///
+    /// [`duplicate_into`]: NcCell#method.duplicate_into
     pub fn duplicate(&self, common_plane: &mut NcPlane) -> NcResult<NcCell> {
         let mut target = NcCell::new();
         let res = unsafe { c_api::nccell_duplicate(common_plane, &mut target, self) };
         error![res, "NcCell.duplicate()", target]
     }
 
+    /// Duplicate this `NcCell`, bound to `src_plane`, into a new one bound
+    /// to `dst_plane`.
+    ///
+    /// The `EGC` is copied into the pool of `dst_plane`, so the new cell
+    /// remains valid after `src_plane` is destroyed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn duplicate_into(&self, src_plane: &NcPlane, dst_plane: &mut NcPlane) -> NcResult<NcCell> {
+        let egc = self.egc(src_plane).to_string();
+        let mut target = NcCell::new();
+        let res = c_api::nccell_prime(dst_plane, &mut target, &egc, self.stylemask, self.channels);
+        error![res, &format!["NcCell.duplicate_into({:?})", egc], target]
+    }
+
     /// Initializes (zeroes out) this `NcCell`.
     ///
     /// *C style function: [nccell_init()][c_api::nccell_init].*
@@ -120,6 +141,9 @@ impl NcCell {
 
     /// Releases resources held by the current cell in the [NcPlane] `plane`.
     ///
+    /// It must be the plane the cell is bound to, otherwise an unrelated
+    /// part of its pool would be released.
+    ///
     /// *C style function: [nccell_release()][c_api::nccell_release].*
     pub fn release(&mut self, plane: &mut NcPlane) {
         unsafe {
@@ -192,6 +216,15 @@ impl NcCell {
         c_api::nccell_bg_default_p(self)
     }
 
+    /// Is the background [`NcChannel`] using the "default background color"?
+    ///
+    /// This is the same as [`bg_default_p`][NcCell#method.bg_default_p].
+    ///
+    /// *C style function: [nccell_bg_default_p()][c_api::nccell_bg_default_p].*
+    pub fn has_bg_default(&self) -> bool {
+        self.bg_default_p()
+    }
+
     /// Gets the [`NcPaletteIndex`] of the background [`NcChannel`].
     ///
     /// *C style function: [nccell_bg_palindex()][c_api::nccell_bg_palindex].*
@@ -214,6 +247,23 @@ impl NcCell {
         c_api::nccell_bg_rgb(self).into()
     }
 
+    /// Gets the background color as an [`NcRgba`], with the alpha component
+    /// mapped like in [`NcChannel::rgba`], or `None` if it's the default
+    /// color or a palette-indexed color.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn bg_rgba(&self) -> Option<NcRgba> {
+        (!self.bg_default_p() && !self.bg_palindex_p()).then(|| self.bchannel().rgba())
+    }
+
+    /// Gets the [`NcPaletteIndex`] of the background, or `None` if it's not
+    /// a palette-indexed color.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn bg_palette_index(&self) -> Option<NcPaletteIndex> {
+        self.bg_palindex_p().then(|| self.bg_palindex())
+    }
+
     /// Gets the foreground [`NcAlpha`] (shifted to LSBs).
     ///
     /// *C style function: [nccell_fg_alpha()][c_api::nccell_fg_alpha].*
@@ -228,6 +278,15 @@ impl NcCell {
         c_api::nccell_fg_default_p(self)
     }
 
+    /// Is the foreground [`NcChannel`] using the "default foreground color"?
+    ///
+    /// This is the same as [`fg_default_p`][NcCell#method.fg_default_p].
+    ///
+    /// *C style function: [nccell_fg_default_p()][c_api::nccell_fg_default_p].*
+    pub fn has_fg_default(&self) -> bool {
+        self.fg_default_p()
+    }
+
     /// Gets the [`NcPaletteIndex`] of the foreground [`NcChannel`].
     ///
     /// *C style function: [nccell_fg_palindex()][c_api::nccell_fg_palindex].*
@@ -250,6 +309,23 @@ impl NcCell {
         c_api::nccell_fg_rgb(self).into()
     }
 
+    /// Gets the foreground color as an [`NcRgba`], with the alpha component
+    /// mapped like in [`NcChannel::rgba`], or `None` if it's the default
+    /// color or a palette-indexed color.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fg_rgba(&self) -> Option<NcRgba> {
+        (!self.fg_default_p() && !self.fg_palindex_p()).then(|| self.fchannel().rgba())
+    }
+
+    /// Gets the [`NcPaletteIndex`] of the foreground, or `None` if it's not
+    /// a palette-indexed color.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fg_palette_index(&self) -> Option<NcPaletteIndex> {
+        self.fg_palindex_p().then(|| self.fg_palindex())
+    }
+
     /// Sets the background [`NcAlpha`].
     ///
     /// *C style function: [nccell_set_bg_alpha()][c_api::nccell_set_bg_alpha].*
@@ -334,6 +410,31 @@ impl NcCell {
         c_api::nccellcmp(plane1, cell1, plane2, cell2)
     }
 
+    /// Returns true if both cells have the same `EGC`, regardless of their
+    /// styles and channels.
+    ///
+    /// Like in [`compare`][NcCell#method.compare], the cells can belong to
+    /// different planes, and only the expanded `EGC`s are compared.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn same_glyph(&self, other: &NcCell, self_plane: &NcPlane, other_plane: &NcPlane) -> bool {
+        self.egc(self_plane) == other.egc(other_plane)
+    }
+
+    /// Returns true if both cells have the same [`NcStyle`].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn styles_eq(&self, other: &NcCell) -> bool {
+        self.styles() == other.styles()
+    }
+
+    /// Returns true if both cells have the same [`NcChannels`].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn channels_eq(&self, other: &NcCell) -> bool {
+        self.channels() == other.channels()
+    }
+
     /// Saves the [`NcStyle`] and the [`NcChannels`], and returns the duplicatd `EGC`.
     ///
     /// *C style function: [nccell_fg_alpha()][c_api::nccell_fg_alpha].*
@@ -425,7 +526,7 @@ impl NcCell {
     ///
     /// *C style function: [nccell_wide_left_p()][c_api::nccell_wide_left_p].*
     pub fn wide_left_p(&self) -> bool {
-        c_api::nccell_wide_right_p(self)
+        c_api::nccell_wide_left_p(self)
     }
 
     /// Is this the right side of a wide character?
@@ -434,6 +535,39 @@ impl NcCell {
     pub fn wide_right_p(&self) -> bool {
         c_api::nccell_wide_right_p(self)
     }
+
+    /// Does this NcCell contain a wide codepoint, being its left half?
+    ///
+    /// This is the same as [`wide_left_p`][NcCell#method.wide_left_p].
+    ///
+    /// *C style function: [nccell_wide_left_p()][c_api::nccell_wide_left_p].*
+    pub fn is_wide(&self) -> bool {
+        c_api::nccell_wide_left_p(self)
+    }
+
+    /// Is this the trailing right half of a wide character?
+    ///
+    /// This is the same as [`wide_right_p`][NcCell#method.wide_right_p].
+    ///
+    /// *C style function: [nccell_wide_right_p()][c_api::nccell_wide_right_p].*
+    pub fn is_double_wide_right(&self) -> bool {
+        c_api::nccell_wide_right_p(self)
+    }
+
+    /// Returns the number of columns of the character: 2 for both halves of
+    /// a wide character, and 1 otherwise.
+    ///
+    /// *C style function: [nccell_cols()][c_api::nccell_cols].*
+    pub fn width(&self) -> u32 {
+        self.cols().clamp(1, 2) as u32
+    }
+
+    /// Is the `EGC` of this NcCell empty or a space?
+    ///
+    /// *(No equivalent C style function)*
+    pub fn is_blank(&self, plane: &NcPlane) -> bool {
+        matches![self.egc(plane), "" | " "]
+    }
 }
 
 /// # `NcCell` methods: boxes
@@ -99,6 +99,10 @@ pub(crate) mod reimplemented;
 /// At any `NcCell`, we can have a theoretically arbitrarily long UTF-8 string,
 /// a foreground color, a background color, and an [`NcStyle`][crate::NcStyle] attribute set.
 ///
+/// There's no room for anything else, like an OSC 8 hyperlink, and the
+/// renderer doesn't emit escape sequences stored in the `EGC`, so cells
+/// can't be hyperlinked.
+///
 /// Valid grapheme cluster contents include:
 ///
 /// - A NUL terminator,
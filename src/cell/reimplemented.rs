@@ -363,7 +363,7 @@ pub const fn nccell_cols(cell: &NcCell) -> u8 {
 /// *Method: NcCell.[double_wide_p()][NcCell#method.double_wide_p].*
 #[inline]
 pub const fn nccell_double_wide_p(cell: &NcCell) -> bool {
-    cell.width > 0
+    cell.width >= 2
 }
 
 /// Is this the right half of a wide character?
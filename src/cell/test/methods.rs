@@ -71,3 +71,140 @@ fn constructors() -> crate::NcResult<()> {
     unsafe { nc.stop()? };
     Ok(())
 }
+
+#[test]
+#[serial]
+fn duplicate_into() -> crate::NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let src = NcPlane::new_pile_sized(nc, 0, 0, 10, 10)?;
+    let dst = NcPlane::new_pile_sized(nc, 0, 0, 10, 10)?;
+
+    // a wide glyph longer than 4 bytes, stored in the plane's pool
+    let mut cell = NcCell::new();
+    NcCell::prime(
+        src,
+        &mut cell,
+        "🤦🏼‍♂️",
+        NcStyle::Bold,
+        NcChannels::from_rgb_both(0x112233),
+    )?;
+
+    let mut copy = cell.duplicate_into(src, dst)?;
+    cell.release(src);
+    src.destroy()?;
+
+    assert_eq!["🤦🏼‍♂️", copy.egc(dst)];
+    assert_eq![NcStyle::Bold, copy.styles()];
+    assert_eq![NcChannels::from_rgb_both(0x112233), copy.channels()];
+
+    copy.release(dst);
+    dst.destroy()?;
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn width_predicates() -> crate::NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 10)?;
+    plane.putstr_yx(Some(0), Some(0), "漢a")?;
+
+    let (mut left, mut right, mut narrow, mut blank) =
+        (NcCell::new(), NcCell::new(), NcCell::new(), NcCell::new());
+    plane.at_yx_cell(0, 0, &mut left)?;
+    let _ = plane.at_yx_cell(0, 1, &mut right);
+    plane.at_yx_cell(0, 2, &mut narrow)?;
+    let _ = plane.at_yx_cell(1, 0, &mut blank);
+
+    assert![left.is_wide()];
+    assert![!left.is_double_wide_right()];
+    assert![right.is_double_wide_right()];
+    assert![!right.is_wide()];
+    assert![!narrow.is_wide()];
+    assert![!narrow.is_double_wide_right()];
+    assert_eq![left.wide_left_p(), left.is_wide()];
+    assert_eq![right.wide_left_p(), right.is_wide()];
+    assert_eq![(left.width(), right.width(), narrow.width()), (2, 2, 1)];
+
+    assert![!left.is_blank(plane)];
+    assert![!narrow.is_blank(plane)];
+    assert![blank.is_blank(plane)];
+
+    for cell in [&mut left, &mut right, &mut narrow, &mut blank] {
+        cell.release(plane);
+    }
+    plane.destroy()?;
+    unsafe { nc.stop()? };
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn optional_colors() {
+    use crate::NcRgba;
+
+    // default colors
+    let mut cell = NcCell::new();
+    assert![cell.has_fg_default() && cell.has_bg_default()];
+    assert_eq![(cell.fg_rgba(), cell.bg_rgba()), (None, None)];
+    assert_eq![
+        (cell.fg_palette_index(), cell.bg_palette_index()),
+        (None, None)
+    ];
+
+    // explicitly set colors
+    cell.set_fg_rgb(0x112233);
+    cell.set_bg_rgb(0xFF0080);
+    assert![!cell.has_fg_default() && !cell.has_bg_default()];
+    assert_eq![cell.fg_rgba(), Some(NcRgba::new(0x11, 0x22, 0x33, 0xFF))];
+    assert_eq![cell.bg_rgba(), Some(NcRgba::new(0xFF, 0, 0x80, 0xFF))];
+    assert_eq![cell.fg_palette_index(), None];
+
+    // palette colors
+    cell.set_fg_palindex(7);
+    assert_eq![cell.fg_rgba(), None];
+    assert_eq![cell.fg_palette_index(), Some(7)];
+    assert![!cell.has_fg_default()];
+    assert_eq![cell.bg_palette_index(), None];
+}
+
+#[test]
+#[serial]
+fn attribute_eq() -> crate::NcResult<()> {
+    let nc = unsafe { Nc::new()? };
+    let p1 = NcPlane::new_pile_sized(nc, 0, 0, 1, 1)?;
+    let p2 = NcPlane::new_pile_sized(nc, 0, 0, 1, 1)?;
+
+    let channels = NcChannels::from_rgb_both(0x112233);
+    let mut base = NcCell::new();
+    NcCell::prime(p1, &mut base, "🤦🏼‍♂️", NcStyle::Bold, channels)?;
+
+    // only the glyph differs, in another plane
+    let mut glyph = NcCell::new();
+    NcCell::prime(p2, &mut glyph, "🚀", NcStyle::Bold, channels)?;
+    assert![!base.same_glyph(&glyph, p1, p2)];
+    assert![base.styles_eq(&glyph) && base.channels_eq(&glyph)];
+
+    // only the styles differ
+    let mut styles = NcCell::new();
+    NcCell::prime(p2, &mut styles, "🤦🏼‍♂️", NcStyle::Italic, channels)?;
+    assert![base.same_glyph(&styles, p1, p2)];
+    assert![!base.styles_eq(&styles) && base.channels_eq(&styles)];
+
+    // only the channels differ
+    let mut colors = NcCell::new();
+    NcCell::prime(p2, &mut colors, "🤦🏼‍♂️", NcStyle::Bold, channels)?;
+    colors.set_bg_rgb(0x445566);
+    assert![base.same_glyph(&colors, p1, p2)];
+    assert![base.styles_eq(&colors) && !base.channels_eq(&colors)];
+
+    base.release(p1);
+    glyph.release(p2);
+    styles.release(p2);
+    colors.release(p2);
+    p1.destroy()?;
+    p2.destroy()?;
+    unsafe { nc.stop()? };
+    Ok(())
+}
@@ -3,7 +3,7 @@
 
 use crate::{
     c_api::{self, NcChannel_u32},
-    NcAlpha, NcChannels, NcPaletteIndex, NcRgb,
+    NcAlpha, NcChannels, NcPaletteIndex, NcRgb, NcRgba,
 };
 
 // NcChannel
@@ -37,11 +37,11 @@ use crate::{
 pub struct NcChannel(pub NcChannel_u32);
 
 mod core_impls {
-    use super::{NcChannel, NcChannel_u32};
+    use super::{NcAlpha, NcChannel, NcChannel_u32, NcRgba};
 
     impl Default for NcChannel {
         fn default() -> Self {
-            Self::with_default()
+            Self::with_default().set_alpha(NcAlpha::Transparent)
         }
     }
 
@@ -69,6 +69,18 @@ mod core_impls {
         }
     }
 
+    /// The alpha component is mapped to the closest [`NcAlpha`]:
+    /// `0xFF` is opaque, `0x00` is transparent, and anything else is blended.
+    ///
+    /// [`NcAlpha`]: crate::NcAlpha
+    impl From<NcRgba> for NcChannel {
+        fn from(rgba: NcRgba) -> Self {
+            let mut channel = NcChannel::new();
+            channel.set_rgba(rgba);
+            channel
+        }
+    }
+
     crate::from_primitive![NcChannel, NcChannel_u32];
     crate::unit_impl_from![NcChannel, NcChannel_u32];
     crate::unit_impl_fmt![bases+display; NcChannel];
@@ -194,6 +206,40 @@ impl NcChannel {
         *self
     }
 
+    /// Gets the [`NcRgb`] and the [`NcAlpha`] as an [`NcRgba`].
+    ///
+    /// The alpha component is `0xFF` for opaque and high-contrast channels,
+    /// `0x00` for transparent channels, and `0x80` for blended channels.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn rgba(&self) -> NcRgba {
+        let (r, g, b) = self.rgb8();
+        let a = match self.alpha() {
+            NcAlpha::Opaque | NcAlpha::HighContrast => 0xFF,
+            NcAlpha::Blend => 0x80,
+            NcAlpha::Transparent => 0x00,
+        };
+        NcRgba::new(r, g, b, a)
+    }
+
+    /// Sets the [`NcRgb`] and the [`NcAlpha`] from an [`NcRgba`], and marks
+    /// the NcChannel as NOT using the "default color".
+    ///
+    /// The alpha component is mapped to the closest [`NcAlpha`]:
+    /// `0xFF` is opaque, `0x00` is transparent, and anything else is blended.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_rgba(&mut self, rgba: impl Into<NcRgba>) -> Self {
+        let rgba = rgba.into();
+        let alpha = match rgba.0 >> 24 {
+            0xFF => NcAlpha::Opaque,
+            0x00 => NcAlpha::Transparent,
+            _ => NcAlpha::Blend,
+        };
+        self.set(NcRgb(rgba.0 & Self::RGB_MASK));
+        self.set_alpha(alpha)
+    }
+
     // u8
 
     /// Gets the three components.
@@ -272,6 +318,15 @@ impl NcChannel {
         c_api::ncchannel_default_p(self.0)
     }
 
+    /// Is this `NcChannel` using the "default color" rather than RGB/palette-indexed?
+    ///
+    /// This is the same as [`default_p`][NcChannel#method.default_p].
+    ///
+    /// *C style function: [ncchannel_default_p()][c_api::ncchannel_default_p].*
+    pub fn is_default(&self) -> bool {
+        self.default_p()
+    }
+
     /// Marks this `NcChannel` as using its "default color",
     /// which also marks it opaque.
     ///
@@ -313,6 +368,27 @@ impl NcChannel {
         c_api::ncchannel_palindex_p(self.0)
     }
 
+    /// Is this NcChannel using palette-indexed color rather a than RGB?
+    ///
+    /// This is the same as [`palindex_p`][NcChannel#method.palindex_p].
+    ///
+    /// *C style function: [ncchannel_palindex_p()][c_api::ncchannel_palindex_p].*
+    pub fn is_palette_indexed(&self) -> bool {
+        self.palindex_p()
+    }
+
+    /// Returns the [`NcPaletteIndex`] if the channel is palette-indexed,
+    /// or `None` otherwise.
+    ///
+    /// *C style function: [ncchannel_palindex()][c_api::ncchannel_palindex].*
+    pub fn checked_palindex(&self) -> Option<NcPaletteIndex> {
+        if self.palindex_p() {
+            Some(self.palindex())
+        } else {
+            None
+        }
+    }
+
     /// Sets the [`NcPaletteIndex`] of the [`NcChannel`], and the channel into
     /// palette-indexed mode.
     ///
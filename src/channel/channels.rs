@@ -93,7 +93,8 @@ use crate::{
 pub struct NcChannels(pub NcChannels_u64);
 
 mod core_impls {
-    use super::{NcChannels, NcChannels_u64};
+    use super::{NcChannel, NcChannels, NcChannels_u64};
+    use crate::NcRgba;
 
     impl Default for NcChannels {
         fn default() -> Self {
@@ -105,6 +106,26 @@ mod core_impls {
     crate::unit_impl_from![NcChannels, NcChannels_u64];
     crate::unit_impl_fmt![bases+display; NcChannels];
 
+    /// (foreground, background)
+    impl From<(NcChannel, NcChannel)> for NcChannels {
+        fn from(t: (NcChannel, NcChannel)) -> Self {
+            Self::pack(t.0, t.1)
+        }
+    }
+    impl From<NcChannels> for (NcChannel, NcChannel) {
+        #[inline]
+        fn from(channels: NcChannels) -> Self {
+            channels.unpack()
+        }
+    }
+
+    /// (foreground, background)
+    impl From<(NcRgba, NcRgba)> for NcChannels {
+        fn from(t: (NcRgba, NcRgba)) -> Self {
+            Self::pack(t.0, t.1)
+        }
+    }
+
     // Different background and foreground:
 
     impl From<NcChannels> for [u8; 6] {
@@ -220,6 +241,23 @@ impl NcChannels {
         c_api::ncchannels_combine(fchannel.into().0, bchannel.into().0).into()
     }
 
+    /// Packs a foreground and a background [`NcChannel`] into an `NcChannels`.
+    ///
+    /// This is the same as [`combine`][NcChannels#method.combine],
+    /// and the inverse of [`unpack`][NcChannels#method.unpack].
+    ///
+    /// *C style function: [channels_combine()][c_api::ncchannels_combine].*
+    pub fn pack(fchannel: impl Into<NcChannel>, bchannel: impl Into<NcChannel>) -> Self {
+        Self::combine(fchannel, bchannel)
+    }
+
+    /// Unpacks the `NcChannels` into its foreground and background [`NcChannel`]s.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn unpack(&self) -> (NcChannel, NcChannel) {
+        (self.fchannel(), self.bchannel())
+    }
+
     /// Returns the `NcChannels` with the fore- and background's color
     /// information swapped, but without touching housekeeping bits.
     ///
@@ -454,13 +492,22 @@ impl NcChannels {
 
     // default color
 
-    /// Is the background using the "default background color"?
+    /// Is the foreground using the "default foreground color"?
     ///
     /// *C style function: [channels_fg_default_p()][c_api::ncchannels_fg_default_p].*
     pub fn fg_default_p(&self) -> bool {
         c_api::ncchannels_fg_default_p(self.0)
     }
 
+    /// Is the foreground using the "default foreground color"?
+    ///
+    /// This is the same as [`fg_default_p`][NcChannels#method.fg_default_p].
+    ///
+    /// *C style function: [channels_fg_default_p()][c_api::ncchannels_fg_default_p].*
+    pub fn is_fg_default(&self) -> bool {
+        self.fg_default_p()
+    }
+
     /// Is the background using the "default background color"?
     ///
     /// The "default background color" must generally be used to take advantage
@@ -471,6 +518,15 @@ impl NcChannels {
         c_api::ncchannels_bg_default_p(self.0)
     }
 
+    /// Is the background using the "default background color"?
+    ///
+    /// This is the same as [`bg_default_p`][NcChannels#method.bg_default_p].
+    ///
+    /// *C style function: [channels_bg_default_p()][c_api::ncchannels_bg_default_p].*
+    pub fn is_bg_default(&self) -> bool {
+        self.bg_default_p()
+    }
+
     /// Marks the foreground as using its "default color", and
     /// returns the new [`NcChannels`].
     ///
@@ -490,7 +546,7 @@ impl NcChannels {
     /// Marks the foreground as NOT using its "default color", and
     /// returns the new [`NcChannels`].
     ///
-    /// *C style function: [channels_set_fg_default()][c_api::ncchannels_set_fg_default].*
+    /// *C style function: [channels_set_fg_not_default()][c_api::ncchannels_set_fg_not_default].*
     //
     // Not in the C API
     pub fn set_fg_not_default(&mut self) -> Self {
@@ -1,10 +1,81 @@
 //! Test `NcChannel*` methods and associated functions.
 
-// use crate::{NcChannel, NcChannels};
-//
-// use serial_test::serial;
-//
-// #[test]
-// #[serial]
-// fn () {
-// }
+use crate::{NcAlpha, NcChannel, NcChannels, NcRgba};
+
+#[test]
+fn channel_rgba() {
+    let mut c = NcChannel::from(NcRgba::new(0x11, 0x22, 0x33, 0xFF));
+    assert_eq![c.rgb8(), (0x11, 0x22, 0x33)];
+    assert_eq![c.alpha(), NcAlpha::Opaque];
+    assert![!c.is_default()];
+    assert_eq![c.rgba(), NcRgba::new(0x11, 0x22, 0x33, 0xFF)];
+
+    c.set_rgba(NcRgba::new(0x44, 0x55, 0x66, 0x00));
+    assert_eq![c.rgb8(), (0x44, 0x55, 0x66)];
+    assert_eq![c.alpha(), NcAlpha::Transparent];
+
+    c.set_rgba(NcRgba::new(0x44, 0x55, 0x66, 0x7F));
+    assert_eq![c.alpha(), NcAlpha::Blend];
+    assert_eq![c.rgba(), NcRgba::new(0x44, 0x55, 0x66, 0x80)];
+}
+
+#[test]
+fn channel_default_palindex() {
+    assert_eq![NcChannel::default().alpha(), NcAlpha::Transparent];
+
+    let mut c = NcChannel::with_default();
+    assert![c.is_default()];
+    assert![!c.is_palette_indexed()];
+    assert_eq![c.checked_palindex(), None];
+
+    c.set_palindex(42);
+    assert![!c.is_default()];
+    assert![c.is_palette_indexed()];
+    assert_eq![c.checked_palindex(), Some(42)];
+
+    c.set_rgb([1, 2, 3]);
+    assert_eq![c.checked_palindex(), None];
+}
+
+#[test]
+fn channels_pack_unpack() {
+    let fg = NcChannel::from_rgb_alpha(0x112233, NcAlpha::Blend);
+    let bg = NcChannel::from_rgb(0xAABBCC);
+
+    let channels = NcChannels::pack(fg, bg);
+    assert_eq![channels.0, 0x5011_2233_40AA_BBCC];
+    assert_eq![channels.unpack(), (fg, bg)];
+    assert_eq![NcChannels::from((fg, bg)), channels];
+
+    let channels = NcChannels::from((
+        NcRgba::new(0x11, 0x22, 0x33, 0x80),
+        NcRgba::new(0xAA, 0xBB, 0xCC, 0xFF),
+    ));
+    assert_eq![channels.unpack(), (fg, bg)];
+}
+
+#[test]
+fn channels_default() {
+    let fg_mask = (NcChannels::BG_DEFAULT_MASK as u64) << 32;
+    let bg_mask = NcChannels::BG_DEFAULT_MASK as u64;
+
+    let mut channels = NcChannels::with_default();
+    assert![channels.is_fg_default() && channels.is_bg_default()];
+    assert_eq![channels.0 & (fg_mask | bg_mask), 0];
+
+    channels.set_fg_not_default();
+    assert![!channels.is_fg_default() && channels.is_bg_default()];
+    assert_eq![channels.0 & (fg_mask | bg_mask), fg_mask];
+
+    channels.set_bg_not_default();
+    assert![!channels.is_fg_default() && !channels.is_bg_default()];
+    assert_eq![channels.0 & (fg_mask | bg_mask), fg_mask | bg_mask];
+
+    channels.set_fg_default();
+    assert![channels.is_fg_default() && !channels.is_bg_default()];
+    assert_eq![channels.0 & (fg_mask | bg_mask), bg_mask];
+
+    channels.set_bg_default();
+    assert![channels.is_fg_default() && channels.is_bg_default()];
+    assert_eq![channels.0 & (fg_mask | bg_mask), 0];
+}
@@ -0,0 +1,58 @@
+//! Extended grapheme cluster helpers.
+
+use crate::{c_api, cstring};
+
+/// Returns the number of columns occupied by `string`.
+pub(crate) fn str_width(string: &str) -> u32 {
+    let (mut validbytes, mut validwidth) = (0, 0);
+    // short strings, like single graphemes, are NUL-terminated on the stack
+    let mut buf = [0u8; 32];
+    if string.len() < buf.len() && !string.contains('\0') {
+        buf[..string.len()].copy_from_slice(string.as_bytes());
+        let ptr = buf.as_ptr() as *const core::ffi::c_char;
+        unsafe { c_api::ncstrwidth(ptr, &mut validbytes, &mut validwidth) };
+    } else {
+        let cs = cstring![string];
+        unsafe { c_api::ncstrwidth(cs.as_ptr(), &mut validbytes, &mut validwidth) };
+    }
+    validwidth.max(0) as u32
+}
+
+/// Returns the length in bytes of the first extended grapheme cluster
+/// of `string`, or 0 if it's empty.
+///
+/// The zero-width characters, the emoji modifiers, the characters following
+/// a zero width joiner, and the second of a pair of regional indicators are
+/// joined to the preceding character.
+pub(crate) fn egc_len(string: &str) -> usize {
+    let is_regional = |ch: char| ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch);
+    let mut buf = [0; 4];
+    let mut chars = string.char_indices();
+    let mut prev = match chars.next() {
+        Some((_, ch)) => ch,
+        None => return 0,
+    };
+    let mut regional_pair = false;
+    for (i, ch) in chars {
+        let joined = prev == '\u{200D}'
+            || ('\u{1F3FB}'..='\u{1F3FF}').contains(&ch)
+            || (is_regional(prev) && is_regional(ch) && !regional_pair)
+            || (!ch.is_control() && str_width(ch.encode_utf8(&mut buf)) == 0);
+        if !joined {
+            return i;
+        }
+        regional_pair = is_regional(prev) && is_regional(ch);
+        prev = ch;
+    }
+    string.len()
+}
+
+/// Returns the prefix of `string` made of the extended grapheme clusters
+/// that start within its first `num_bytes` bytes.
+pub(crate) fn egc_prefix(string: &str, num_bytes: usize) -> &str {
+    let mut end = 0;
+    while end < num_bytes.min(string.len()) {
+        end += egc_len(&string[end..]);
+    }
+    &string[..end]
+}
@@ -1,7 +1,10 @@
 //! Error handling with `NcError`, `NcResult` & `NcResult_i32`
 
 #[cfg(not(feature = "std"))]
-use alloc::string::{String, ToString};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 /// The result type for the Rust methods API.
 pub type NcResult<T> = Result<T, NcError>;
@@ -38,6 +41,29 @@ impl NcError {
     }
 }
 
+/// Extension methods for [`NcResult`].
+pub trait NcResultExt<T> {
+    /// Adds context to the error message, prefixing it with the string
+    /// returned by `f`, which is only called on error.
+    fn with_context<F: FnOnce() -> String>(self, f: F) -> NcResult<T>;
+
+    /// Converts the error into its error number.
+    fn ok_or_code(self) -> Result<T, c_api::NcResult_i32>;
+}
+
+impl<T> NcResultExt<T> for NcResult<T> {
+    fn with_context<F: FnOnce() -> String>(self, f: F) -> NcResult<T> {
+        self.map_err(|e| {
+            let msg = if e.msg.is_empty() { f() } else { format!["{}: {}", f(), e.msg] };
+            NcError { int: e.int, msg }
+        })
+    }
+
+    fn ok_or_code(self) -> Result<T, c_api::NcResult_i32> {
+        self.map_err(|e| e.int)
+    }
+}
+
 mod core_impls {
     use super::NcError;
     use core::fmt;
@@ -52,13 +78,68 @@ mod core_impls {
 #[cfg(feature = "std")]
 mod std_impls {
     use super::NcError;
-    use std::error::Error;
+    use std::{error::Error, io};
 
     impl Error for NcError {
         fn description(&self) -> &str {
             &self.msg
         }
     }
+
+    /// Errors convert to [`io::ErrorKind::Other`], keeping the `NcError`
+    /// as the inner error.
+    impl From<NcError> for io::Error {
+        fn from(e: NcError) -> Self {
+            io::Error::new(io::ErrorKind::Other, e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{c_api::NCRESULT_ERR, NcError, NcResult, NcResultExt};
+
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+
+    #[test]
+    fn with_context() {
+        let ok: NcResult<u32> = Ok(1);
+        assert_eq![ok.with_context(|| unreachable!()).unwrap(), 1];
+
+        let err: NcResult<u32> = Err(NcError::with_msg(-2, "NcPlane.move_yx(1, 2)"));
+        let err = err
+            .with_context(|| String::from("drawing the menu"))
+            .unwrap_err();
+        assert_eq![err.int, -2];
+        assert_eq![err.msg, "drawing the menu: NcPlane.move_yx(1, 2)"];
+
+        let err: NcResult<()> = Err(NcError::new());
+        assert_eq![
+            err.with_context(|| String::from("rendering"))
+                .unwrap_err()
+                .msg,
+            "rendering"
+        ];
+    }
+
+    #[test]
+    fn ok_or_code() {
+        assert_eq![NcResult::Ok(3).ok_or_code(), Ok(3)];
+        assert_eq![
+            NcResult::<()>::Err(NcError::new()).ok_or_code(),
+            Err(NCRESULT_ERR)
+        ];
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn into_io_error() {
+        let err: std::io::Error = NcError::new_msg("NcPlane.putstr(\"x\")").into();
+        assert_eq![err.kind(), std::io::ErrorKind::Other];
+        assert![err.to_string().contains("NcPlane.putstr(\"x\")")];
+        assert![err.into_inner().unwrap().downcast::<NcError>().is_ok()];
+    }
 }
 
 pub(crate) mod c_api {
@@ -1,10 +1,164 @@
-//! `NcVisual*` methods and associated functions.
+//! `NcFdPlane*` & `NcSubproc*` methods and associated functions.
 
-use core::ffi::c_void;
+use core::{
+    ffi::{c_char, c_void},
+    marker::PhantomData,
+    ptr::null,
+    slice,
+};
 
-use crate::{NcSubproc, NcSubprocOptions};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, ffi::CString, format, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::ffi::CString;
 
-/// # NcVisualOptions Constructors
+use crate::{
+    c_api::{self, NcResult_i32, NCRESULT_ERR, NCRESULT_OK},
+    cstring, error, error_ref_mut, NcError, NcFd, NcFdPlane, NcFdPlaneOptions, NcPlane, NcResult,
+    NcSubproc, NcSubprocOptions,
+};
+
+/// A Rust closure called with the data read by an [`NcFdPlane`] or an
+/// [`NcSubproc`], and the plane they write to.
+struct NcFdCallback {
+    callback: Box<dyn FnMut(&mut NcPlane, &[u8]) -> NcResult<()> + Send>,
+}
+
+impl NcFdCallback {
+    /// Moves the closure to the heap and returns it as a `curry` pointer.
+    fn into_curry<F>(callback: F) -> *mut NcFdCallback
+    where
+        F: FnMut(&mut NcPlane, &[u8]) -> NcResult<()> + Send + 'static,
+    {
+        Box::into_raw(Box::new(NcFdCallback { callback: Box::new(callback) }))
+    }
+}
+
+/// The C data callback that calls the Rust closure in the `curry`.
+//
+// Returning an error makes the reading thread exit, but the widget is still
+// destroyed by the Rust side.
+unsafe extern "C" fn ncfdplane_callback_trampoline(
+    fdplane: *mut NcFdPlane,
+    buf: *const c_void,
+    len: usize,
+    curry: *mut c_void,
+) -> NcResult_i32 {
+    let state = &mut *(curry as *mut NcFdCallback);
+    let plane = &mut *c_api::ncfdplane_plane(fdplane);
+    match (state.callback)(plane, slice::from_raw_parts(buf as *const u8, len)) {
+        Ok(()) => NCRESULT_OK,
+        Err(_) => NCRESULT_ERR,
+    }
+}
+
+/// The C done callback, which doesn't do anything.
+unsafe extern "C" fn ncfdplane_done_trampoline(
+    _fdplane: *mut NcFdPlane,
+    _fderrno: i32,
+    _curry: *mut c_void,
+) -> NcResult_i32 {
+    NCRESULT_OK
+}
+
+/// Writes the `buf`fer to the `plane` as text.
+///
+/// Invalid UTF-8 sequences, including the ones split between two reads,
+/// are replaced with `U+FFFD`.
+fn ncfdplane_write_text(plane: &mut NcPlane, buf: &[u8]) -> NcResult<()> {
+    plane.putstr(&String::from_utf8_lossy(buf)).map(|_| ())
+}
+
+/// # `NcFdPlaneOptions` Constructors
+impl NcFdPlaneOptions {
+    /// New `NcFdPlaneOptions`.
+    ///
+    /// If `follow` is true, the reading continues after reaching the end of
+    /// the file, like `tail -f`.
+    pub fn new(curry: *mut c_void, follow: bool) -> Self {
+        Self { curry, follow, flags: 0 }
+    }
+}
+
+/// # `NcFdPlane` Constructors & Destructors
+impl NcFdPlane {
+    /// Starts reading the file descriptor `fd` and writing its output as
+    /// text to the `plane`, which is made scrolling.
+    ///
+    /// The `fd` is closed when the returned [`NcFdPlaneGuard`] is destroyed.
+    ///
+    /// If `follow` is true, the reading continues after reaching the end of
+    /// the file, like `tail -f`.
+    ///
+    /// *C style function: [ncfdplane_create()][c_api::ncfdplane_create].*
+    pub fn new(plane: &mut NcPlane, fd: NcFd, follow: bool) -> NcResult<NcFdPlaneGuard<'_>> {
+        plane.set_scrolling(true);
+        Self::with_callback(plane, fd, follow, ncfdplane_write_text)
+    }
+
+    /// Starts reading the file descriptor `fd`, calling the `callback` with
+    /// every read chunk of data, and the `plane`.
+    ///
+    /// The `callback` is called from a thread managed by notcurses. If it
+    /// returns an error, the reading stops.
+    ///
+    /// The `fd` is closed when the returned [`NcFdPlaneGuard`] is destroyed.
+    ///
+    /// *C style function: [ncfdplane_create()][c_api::ncfdplane_create].*
+    pub fn with_callback<F>(
+        plane: &mut NcPlane,
+        fd: NcFd,
+        follow: bool,
+        callback: F,
+    ) -> NcResult<NcFdPlaneGuard<'_>>
+    where
+        F: FnMut(&mut NcPlane, &[u8]) -> NcResult<()> + Send + 'static,
+    {
+        let curry = NcFdCallback::into_curry(callback);
+        let options = NcFdPlaneOptions::new(curry as *mut c_void, follow);
+        let fdplane = unsafe {
+            c_api::ncfdplane_create(
+                plane,
+                &options,
+                fd,
+                Some(ncfdplane_callback_trampoline),
+                Some(ncfdplane_done_trampoline),
+            )
+        };
+        if fdplane.is_null() {
+            drop(unsafe { Box::from_raw(curry) });
+            return Err(NcError::new_msg(&format![
+                "NcFdPlane.with_callback(plane, {})",
+                fd
+            ]));
+        }
+        Ok(NcFdPlaneGuard { inner: NcFdInner::FdPlane(fdplane), curry, _plane: PhantomData })
+    }
+
+    /// Destroys this `NcFdPlane`, closing its file descriptor.
+    ///
+    /// The plane it writes to is not destroyed.
+    ///
+    /// *C style function: [ncfdplane_destroy()][c_api::ncfdplane_destroy].*
+    pub(crate) fn destroy(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncfdplane_destroy(self) },
+            "NcFdPlane.destroy()"
+        ]
+    }
+}
+
+/// # `NcFdPlane` Methods
+impl NcFdPlane {
+    /// Returns the [`NcPlane`] this `NcFdPlane` writes to.
+    ///
+    /// *C style function: [ncfdplane_plane()][c_api::ncfdplane_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncfdplane_plane(self) }, "NcFdPlane.plane()"]
+    }
+}
+
+/// # `NcSubprocOptions` Constructors
 impl NcSubprocOptions {
     ///
     pub fn new(curry: *mut c_void, restart_period: u64, flags: u64) -> Self {
@@ -18,8 +172,142 @@ impl NcSubprocOptions {
     }
 }
 
-/// # NcSubproc Constructors & Destructors
-impl NcSubproc {}
+/// # `NcSubproc` Constructors & Destructors
+impl NcSubproc {
+    /// Spawns the program `bin`, searched in the `PATH`, with the `args`,
+    /// and writes its output as text to the `plane`, which is made scrolling.
+    ///
+    /// The process is killed when the returned [`NcFdPlaneGuard`] is destroyed,
+    /// if it's still running.
+    ///
+    /// *C style function: [ncsubproc_createvp()][c_api::ncsubproc_createvp].*
+    pub fn new<'a>(
+        plane: &'a mut NcPlane,
+        bin: &str,
+        args: &[&str],
+    ) -> NcResult<NcFdPlaneGuard<'a>> {
+        plane.set_scrolling(true);
+        Self::with_callback(plane, bin, args, ncfdplane_write_text)
+    }
+
+    /// Spawns the program `bin`, searched in the `PATH`, with the `args`,
+    /// calling the `callback` with every chunk of its output, and the `plane`.
+    ///
+    /// The `callback` is called from a thread managed by notcurses. If it
+    /// returns an error, the reading stops.
+    ///
+    /// *C style function: [ncsubproc_createvp()][c_api::ncsubproc_createvp].*
+    pub fn with_callback<'a, F>(
+        plane: &'a mut NcPlane,
+        bin: &str,
+        args: &[&str],
+        callback: F,
+    ) -> NcResult<NcFdPlaneGuard<'a>>
+    where
+        F: FnMut(&mut NcPlane, &[u8]) -> NcResult<()> + Send + 'static,
+    {
+        // the first argument is the program name, and the list ends with null
+        let cstrings: Vec<CString> = core::iter::once(bin)
+            .chain(args.iter().copied())
+            .map(|s| cstring![s])
+            .collect();
+        let mut argv: Vec<*const c_char> = cstrings.iter().map(|cs| cs.as_ptr()).collect();
+        argv.push(null());
+
+        let curry = NcFdCallback::into_curry(callback);
+        let options = NcSubprocOptions::new(curry as *mut c_void, 0, 0);
+        let subproc = unsafe {
+            c_api::ncsubproc_createvp(
+                plane,
+                &options,
+                cstrings[0].as_ptr(),
+                argv.as_ptr(),
+                Some(ncfdplane_callback_trampoline),
+                Some(ncfdplane_done_trampoline),
+            )
+        };
+        if subproc.is_null() {
+            drop(unsafe { Box::from_raw(curry) });
+            return Err(NcError::new_msg(&format![
+                "NcSubproc.with_callback(plane, {:?}, {:?})",
+                bin, args
+            ]));
+        }
+        Ok(NcFdPlaneGuard { inner: NcFdInner::Subproc(subproc), curry, _plane: PhantomData })
+    }
 
-/// # NcSubproc Methods
-impl NcSubproc {}
+    /// Destroys this `NcSubproc`, killing the process if it's still running.
+    ///
+    /// The plane it writes to is not destroyed.
+    ///
+    /// *C style function: [ncsubproc_destroy()][c_api::ncsubproc_destroy].*
+    pub(crate) fn destroy(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncsubproc_destroy(self) },
+            "NcSubproc.destroy()"
+        ]
+    }
+}
+
+/// # `NcSubproc` Methods
+impl NcSubproc {
+    /// Returns the [`NcPlane`] this `NcSubproc` writes to.
+    ///
+    /// *C style function: [ncsubproc_plane()][c_api::ncsubproc_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncsubproc_plane(self) }, "NcSubproc.plane()"]
+    }
+}
+
+/// The widget owned by an [`NcFdPlaneGuard`].
+enum NcFdInner {
+    FdPlane(*mut NcFdPlane),
+    Subproc(*mut NcSubproc),
+    Destroyed,
+}
+
+/// An [`NcFdPlane`] or an [`NcSubproc`] writing to a borrowed plane, that
+/// is destroyed along with its callback when dropped.
+///
+/// Created with [`NcFdPlane::new`], [`NcSubproc::new`] and their
+/// `with_callback` variants.
+pub struct NcFdPlaneGuard<'a> {
+    inner: NcFdInner,
+    curry: *mut NcFdCallback,
+    _plane: PhantomData<&'a mut NcPlane>,
+}
+
+impl NcFdPlaneGuard<'_> {
+    /// Returns the [`NcPlane`] being written to.
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        match self.inner {
+            NcFdInner::FdPlane(fdplane) => unsafe { (*fdplane).plane() },
+            NcFdInner::Subproc(subproc) => unsafe { (*subproc).plane() },
+            NcFdInner::Destroyed => unreachable!(),
+        }
+    }
+
+    /// Stops reading, and destroys the widget and its callback.
+    ///
+    /// This is the same as dropping it, but returns the result.
+    pub fn destroy(mut self) -> NcResult<()> {
+        self.destroy_inner()
+    }
+
+    fn destroy_inner(&mut self) -> NcResult<()> {
+        let res = match core::mem::replace(&mut self.inner, NcFdInner::Destroyed) {
+            NcFdInner::FdPlane(fdplane) => unsafe { (*fdplane).destroy() },
+            NcFdInner::Subproc(subproc) => unsafe { (*subproc).destroy() },
+            NcFdInner::Destroyed => return Ok(()),
+        };
+        // the reading thread has been joined, so the callback is not in use
+        drop(unsafe { Box::from_raw(self.curry) });
+        res
+    }
+}
+
+impl Drop for NcFdPlaneGuard<'_> {
+    fn drop(&mut self) {
+        let _ = self.destroy_inner();
+    }
+}
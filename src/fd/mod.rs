@@ -1,16 +1,16 @@
 // functions already exported by bindgen : 8
 // -----------------------------------------
-// (W) wrap: 0
+// (W) wrap: 6
 // (#) test: 0
 // -----------------------------------------
-//   ncfdplane_create
-//   ncfdplane_destroy
-//   ncfdplane_plane
+//W  ncfdplane_create
+//W  ncfdplane_destroy
+//W  ncfdplane_plane
 //   ncsubproc_createv,
-//   ncsubproc_createvp,
+//W  ncsubproc_createvp,
 //   ncsubproc_createvpe,
-//   ncsubproc_destroy,
-//   ncsubproc_plane,
+//W  ncsubproc_destroy,
+//W  ncsubproc_plane,
 
 //! from: <https://notcurses.com/notcurses_fds.3.html>
 //!
@@ -37,6 +37,8 @@ use crate::c_api::ffi;
 
 mod methods;
 
+pub use methods::NcFdPlaneGuard;
+
 /// A raw file descriptor, as returned by [`Nc.inputready_fd`] and
 /// [`NcDirect.inputready_fd`].
 ///
@@ -15,7 +15,7 @@
 
 use core::ffi::CStr;
 
-use crate::NcKeyMod;
+use crate::{NcKey, NcKeyMod};
 
 pub(crate) mod reimplemented;
 
@@ -146,6 +146,15 @@ impl NcInput {
         raw_char.filter(|&ch| !ch.is_ascii_control())
     }
 
+    /// Returns true if it's a terminal resize event.
+    ///
+    /// See [`NcReceived::Resize`] for how to handle it.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn is_resize(&self) -> bool {
+        self.id == NcKey::Resize.into()
+    }
+
     /// Returns true if there are no modifiers present.
     ///
     /// *C style function: [ncinput_nomod_p()][crate::c_api::ncinput_nomod_p].*
@@ -18,6 +18,16 @@ pub enum NcReceived {
 
     /// A valid [`char`] was received.
     Char(char),
+
+    /// The terminal was resized.
+    ///
+    /// This is the [`NcKey::Resize`] event. When it's received, the
+    /// application should call [`Nc.refresh`] to get the new dimensions
+    /// and redraw, or else [`render`] again, which also picks them up.
+    ///
+    /// [`Nc.refresh`]: crate::Nc#method.refresh
+    /// [`render`]: crate::Nc#method.render
+    Resize,
 }
 
 mod core_impls {
@@ -40,6 +50,7 @@ mod core_impls {
             let string = match self {
                 Key(k) => format!["{k}"],
                 Char(c) => format!["{c:?}"],
+                Resize => "Resize".to_string(),
                 NoInput => "NoInput".to_string(),
             };
             write!(f, "{}", string)
@@ -51,6 +62,7 @@ mod core_impls {
             let string = match self {
                 Key(k) => format!["Key({k})"],
                 Char(c) => format!["Char({c:?})"],
+                Resize => "Resize".to_string(),
                 NoInput => "NoInput".to_string(),
             };
             write!(f, "NcReceived::{}", string)
@@ -79,6 +91,7 @@ mod core_impls {
             match r {
                 Char(c) => c.into(),
                 Key(k) => k.into(),
+                Resize => NcKey::Resize.into(),
                 NoInput => 0,
             }
         }
@@ -89,6 +102,8 @@ mod core_impls {
             use NcReceived::*;
             if num == 0 {
                 NoInput
+            } else if num == NcKey::Resize.into() {
+                Resize
             } else if NcKey::is(num) {
                 Key(NcKey::new(num).unwrap())
             } else if let Some(c) = core::char::from_u32(num) {
@@ -99,3 +114,25 @@ mod core_impls {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{NcInput, NcKey, NcReceived};
+
+    #[test]
+    fn resize() {
+        let mut input = NcInput::new_empty();
+        input.id = NcKey::Resize.into();
+        assert![input.is_resize()];
+        assert_eq![NcReceived::from(&input), NcReceived::Resize];
+        assert_eq![u32::from(NcReceived::Resize), input.id];
+
+        let input = NcInput::new('r');
+        assert![!input.is_resize()];
+        assert_eq![NcReceived::from(input), NcReceived::Char('r')];
+        assert_eq![
+            NcReceived::from(u32::from(NcKey::Up)),
+            NcReceived::Key(NcKey::Up)
+        ];
+    }
+}
@@ -0,0 +1,318 @@
+//! `NcLayout`, `NcLayoutItem` & `NcDirection`
+
+use crate::{Nc, NcError, NcPlane, NcRect, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// The main axis along which an [`NcLayout`] places its children.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcDirection {
+    /// The children are placed side by side, from left to right.
+    #[default]
+    Horizontal,
+
+    /// The children are placed one above the other, from top to bottom.
+    Vertical,
+}
+
+/// A plane managed by an [`NcLayout`], with its sizing constraints.
+///
+/// The sizes are measured along the main axis of the layout.
+#[derive(Debug)]
+pub struct NcLayoutItem<'a> {
+    /// The managed plane.
+    pub plane: &'a mut NcPlane,
+
+    /// The weight of the plane when distributing the free space.
+    ///
+    /// A plane with a weight of `0.0` doesn't grow beyond its `min_size`.
+    pub flex_grow: f32,
+
+    /// The minimum size of the plane.
+    pub min_size: u32,
+
+    /// The maximum size of the plane, if any.
+    pub max_size: Option<u32>,
+}
+
+impl<'a> NcLayoutItem<'a> {
+    /// New `NcLayoutItem` with a `flex_grow` of `1.0` and no size constraints.
+    pub fn new(plane: &'a mut NcPlane) -> Self {
+        Self { plane, flex_grow: 1.0, min_size: 0, max_size: None }
+    }
+
+    /// Sets the weight of the plane when distributing the free space.
+    pub fn flex_grow(mut self, flex_grow: f32) -> Self {
+        self.flex_grow = flex_grow.max(0.0);
+        self
+    }
+
+    /// Sets the minimum size of the plane.
+    pub fn min_size(mut self, min_size: u32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the maximum size of the plane.
+    pub fn max_size(mut self, max_size: u32) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Returns the minimum size, clamped to the maximum size.
+    fn clamped_min(&self) -> u32 {
+        self.max_size
+            .map_or(self.min_size, |max| self.min_size.min(max))
+    }
+}
+
+/// A flexbox-inspired layout, that places and sizes a list of planes
+/// along a [direction][NcDirection].
+///
+/// Each child gets its minimum size first, and then the remaining space is
+/// distributed among the children proportionally to their `flex_grow` weights,
+/// never growing beyond their maximum size. On the cross axis every child
+/// spans the whole area.
+///
+/// If the minimum sizes don't fit in the area, the last children are
+/// truncated.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::*;
+/// # fn main() -> NcResult<()> {
+/// # let nc = unsafe { Nc::new()? };
+/// # let stdplane = unsafe { nc.stdplane() };
+/// let sidebar = NcPlane::new_child_sized(stdplane, 0, 0, 1, 1)?;
+/// let main = NcPlane::new_child_sized(stdplane, 0, 0, 1, 1)?;
+///
+/// let mut layout = NcLayout::new(NcDirection::Horizontal);
+/// layout.push(NcLayoutItem::new(sidebar).min_size(10).max_size(20));
+/// layout.push(NcLayoutItem::new(main).flex_grow(3.0));
+/// let (rows, cols) = stdplane.dim_yx();
+/// let size = NcYx::new(NcRow(rows), NcCol(cols));
+/// layout.apply(nc, NcRect::new(NcYx::default(), size))?;
+/// # unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct NcLayout<'a> {
+    /// The main axis.
+    pub direction: NcDirection,
+
+    /// The managed planes, in order.
+    pub children: Vec<NcLayoutItem<'a>>,
+}
+
+impl<'a> NcLayout<'a> {
+    /// New empty `NcLayout`.
+    pub fn new(direction: NcDirection) -> Self {
+        Self { direction, children: Vec::new() }
+    }
+
+    /// Adds a child at the end of the layout.
+    pub fn push(&mut self, item: NcLayoutItem<'a>) {
+        self.children.push(item);
+    }
+
+    /// Returns the sizes of the children along the main axis,
+    /// for the `available` space.
+    pub fn sizes(&self, available: u32) -> Vec<u32> {
+        // every child gets its minimum size, in order, while there's space
+        let mut remaining = available;
+        let mut sizes: Vec<u32> = self
+            .children
+            .iter()
+            .map(|child| {
+                let size = child.clamped_min().min(remaining);
+                remaining -= size;
+                size
+            })
+            .collect();
+
+        // the free space is distributed proportionally to the weights
+        while remaining > 0 {
+            let growing: Vec<usize> = (0..sizes.len())
+                .filter(|&i| {
+                    let child = &self.children[i];
+                    child.flex_grow > 0.0 && child.max_size.map_or(true, |max| sizes[i] < max)
+                })
+                .collect();
+            let total_grow: f32 = growing.iter().map(|&i| self.children[i].flex_grow).sum();
+            if growing.is_empty() {
+                break;
+            }
+
+            let mut distributed = 0;
+            for &i in &growing {
+                let share = (remaining as f32 * self.children[i].flex_grow / total_grow) as u32;
+                distributed += self.grow(&mut sizes, i, share);
+            }
+            // the rounding left some space: hand it out one cell at a time
+            if distributed == 0 {
+                for &i in &growing {
+                    distributed += self.grow(&mut sizes, i, 1);
+                    if distributed == remaining {
+                        break;
+                    }
+                }
+            }
+            remaining -= distributed;
+        }
+        sizes
+    }
+
+    /// Grows the size of the child `i` by up to `by`, respecting its maximum
+    /// size, and returns how much it grew.
+    fn grow(&self, sizes: &mut [u32], i: usize, by: u32) -> u32 {
+        let max = self.children[i].max_size.unwrap_or(u32::MAX);
+        let grown = by.min(max.saturating_sub(sizes[i]));
+        sizes[i] += grown;
+        grown
+    }
+
+    /// Resizes and moves the children to fill the `rect` area, relative to
+    /// the origin of their parents.
+    ///
+    /// The `nc` context of the planes is borrowed so that it can't render
+    /// while they are being laid out.
+    ///
+    /// Children that are left without any space are not modified.
+    pub fn apply(&mut self, _nc: &mut Nc, rect: NcRect) -> NcResult<()> {
+        let NcRect { origin, size } = rect;
+        let available = match self.direction {
+            NcDirection::Horizontal => size.x.0,
+            NcDirection::Vertical => size.y.0,
+        };
+        if size.y.0 == 0 || size.x.0 == 0 {
+            return Err(NcError::new_msg(&format![
+                "NcLayout.apply(nc, {:?}) empty area",
+                rect
+            ]));
+        }
+
+        let sizes = self.sizes(available);
+        let mut offset = 0;
+        for (child, len) in self.children.iter_mut().zip(sizes) {
+            if len == 0 {
+                continue;
+            }
+            let (y, x, len_y, len_x) = match self.direction {
+                NcDirection::Horizontal => (origin.y.0, origin.x.0 + offset, size.y.0, len),
+                NcDirection::Vertical => (origin.y.0 + offset, origin.x.0, len, size.x.0),
+            };
+            child.plane.resize_simple(len_y, len_x)?;
+            child.plane.move_yx(y as i32, x as i32)?;
+            offset += len;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NcDirection, NcLayout, NcLayoutItem};
+    use crate::{
+        c_api::{notcurses_init_test, notcurses_stop},
+        NcCol, NcPlane, NcRect, NcRow, NcYx,
+    };
+    use serial_test::serial;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    fn rect(y: u32, x: u32, len_y: u32, len_x: u32) -> NcRect {
+        NcRect::new(
+            NcYx::new(NcRow(y), NcCol(x)),
+            NcYx::new(NcRow(len_y), NcCol(len_x)),
+        )
+    }
+
+    #[test]
+    #[serial]
+    fn layout() {
+        unsafe {
+            let nc = notcurses_init_test();
+            let root = NcPlane::new_pile_sized(nc, 0, 0, 10, 30).unwrap();
+            let a = NcPlane::new_child_sized(root, 0, 0, 1, 1).unwrap();
+            let b = NcPlane::new_child_sized(root, 0, 0, 1, 1).unwrap();
+            let c = NcPlane::new_child_sized(root, 0, 0, 1, 1).unwrap();
+
+            // equal weights
+            let mut layout = NcLayout::new(NcDirection::Horizontal);
+            layout.push(NcLayoutItem::new(a));
+            layout.push(NcLayoutItem::new(b));
+            layout.push(NcLayoutItem::new(c));
+            assert_eq![layout.sizes(30), [10, 10, 10]];
+            assert_eq![layout.sizes(31).iter().sum::<u32>(), 31];
+
+            layout.apply(nc, rect(1, 2, 8, 27)).unwrap();
+            let planes: Vec<_> = layout
+                .children
+                .iter()
+                .map(|c| (c.plane.yx(), c.plane.dim_yx()))
+                .collect();
+            assert_eq![
+                planes,
+                [((1, 2), (8, 9)), ((1, 11), (8, 9)), ((1, 20), (8, 9))]
+            ];
+
+            // weighted
+            layout.children[0].flex_grow = 2.0;
+            layout.children[1].flex_grow = 1.0;
+            layout.children[2].flex_grow = 0.0;
+            layout.children[2].min_size = 3;
+            assert_eq![layout.sizes(30), [18, 9, 3]];
+
+            layout.direction = NcDirection::Vertical;
+            layout.apply(nc, rect(0, 0, 9, 30)).unwrap();
+            let planes: Vec<_> = layout
+                .children
+                .iter()
+                .map(|c| (c.plane.yx(), c.plane.dim_yx()))
+                .collect();
+            assert_eq![
+                planes,
+                [((0, 0), (4, 30)), ((4, 0), (2, 30)), ((6, 0), (3, 30))]
+            ];
+
+            notcurses_stop(nc);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn layout_clamped() {
+        unsafe {
+            let nc = notcurses_init_test();
+            let root = NcPlane::new_pile_sized(nc, 0, 0, 10, 30).unwrap();
+            let a = NcPlane::new_child_sized(root, 0, 0, 1, 1).unwrap();
+            let b = NcPlane::new_child_sized(root, 0, 0, 1, 1).unwrap();
+            let c = NcPlane::new_child_sized(root, 0, 0, 1, 1).unwrap();
+
+            let mut layout = NcLayout::new(NcDirection::Horizontal);
+            layout.push(NcLayoutItem::new(a).max_size(5));
+            layout.push(NcLayoutItem::new(b).min_size(12));
+            layout.push(NcLayoutItem::new(c).min_size(8).max_size(9));
+
+            // the first one stops growing at its max size
+            assert_eq![layout.sizes(30), [5, 16, 9]];
+            // min sizes
+            assert_eq![layout.sizes(20), [0, 12, 8]];
+            // overflow truncates the last children
+            assert_eq![layout.sizes(15), [0, 12, 3]];
+            assert_eq![layout.sizes(10), [0, 10, 0]];
+
+            // the truncated child keeps its size
+            layout.apply(nc, rect(0, 0, 2, 10)).unwrap();
+            assert_eq![layout.children[1].plane.dim_yx(), (2, 10)];
+            assert_eq![layout.children[2].plane.dim_yx(), (1, 1)];
+
+            assert![layout.apply(nc, rect(0, 0, 0, 10)).is_err()];
+
+            notcurses_stop(nc);
+        }
+    }
+}
@@ -27,6 +27,10 @@
 //! and `NcDirect` objects, and the `destroy()` method for the rest of types that
 //! allocate, (like `NcPlane`, `NcMenu`…) at the end of their scope.
 //!
+//! The exceptions are the Rust types that own one of them, like `NcMenuBar`,
+//! `NcChecklist`, `NcTextField` and `NcFdPlaneGuard`, which destroy it when
+//! dropped.
+//!
 //! But they do implement methods and use `NcResult` as the return type,
 //! for handling errors in the way we are used to in Rust.
 //!
@@ -86,6 +90,7 @@ mod capabilities;
 mod cell;
 mod channel;
 mod direct;
+mod egc;
 mod error;
 mod fade;
 mod fd;
@@ -93,6 +98,7 @@ mod fd;
 mod file;
 mod input;
 mod key;
+mod layout;
 mod log_level;
 mod macros;
 mod metric;
@@ -108,6 +114,7 @@ mod string;
 mod style;
 mod time;
 mod visual;
+mod yx;
 
 pub mod widgets;
 
@@ -123,19 +130,29 @@ pub use capabilities::NcCapabilities;
 pub use cell::NcCell;
 pub use channel::{NcChannel, NcChannels};
 pub use direct::{NcDirect, NcDirectFlag};
-pub use error::{NcError, NcResult};
+pub use error::{NcError, NcResult, NcResultExt};
 pub use fade::{NcFadeCb, NcFadeCtx};
-pub use fd::{NcFd, NcFdPlane, NcFdPlaneOptions, NcSubproc, NcSubprocOptions};
+pub use fd::{NcFd, NcFdPlane, NcFdPlaneGuard, NcFdPlaneOptions, NcSubproc, NcSubprocOptions};
 #[cfg(feature = "std")]
 #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
 pub use file::NcFile;
 pub use input::{NcInput, NcInputType, NcMiceEvents, NcReceived};
 pub use key::{NcKey, NcKeyMod};
+pub use layout::{NcDirection, NcLayout, NcLayoutItem};
 pub use log_level::NcLogLevel;
+#[cfg(feature = "tokio")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "tokio")))]
+pub use notcurses::NcInputStream;
 pub use notcurses::{Nc, NcFlag, NcOptions, NcOptionsBuilder};
 pub use palette::{NcPalette, NcPaletteIndex};
 pub use pixel::{NcPixel, NcPixelGeometry, NcPixelImpl};
-pub use plane::{NcPlane, NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
+#[cfg(feature = "animation")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "animation")))]
+pub use plane::NcAnimation;
+pub use plane::{
+    NcCellDiff, NcCursorShape, NcLogPlane, NcPlane, NcPlaneFlag, NcPlaneOptions,
+    NcPlaneOptionsBuilder, NcPlaneRow, NcPlaneSnapshot, NcSpan, NcTextInputMode,
+};
 pub use r#box::NcBoxMask;
 pub use resizecb::NcResizeCb;
 pub use rgb::{NcRgb, NcRgba};
@@ -145,8 +162,10 @@ pub use string::NcString;
 pub use style::NcStyle;
 pub use time::NcTime;
 pub use visual::{
-    NcStreamCb, NcVisual, NcVisualFlag, NcVisualGeometry, NcVisualOptions, NcVisualOptionsBuilder,
+    NcStreamCb, NcVisual, NcVisualFlag, NcVisualFrameIter, NcVisualGeometry, NcVisualOptions,
+    NcVisualOptionsBuilder,
 };
+pub use yx::{NcCol, NcRect, NcRow, NcYx};
 
 pub mod c_api {
     //! The `C API`, including structs, constants, functions and type aliases.
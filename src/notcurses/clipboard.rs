@@ -0,0 +1,137 @@
+//! `Nc` clipboard integration, with OSC 52 escape sequences.
+//!
+//! The OSC 52 sequence asks the terminal to copy some text to the system
+//! clipboard, or to reply with the text in it. Not every terminal supports
+//! it: some ignore it, some need it to be enabled in their configuration
+//! (e.g. xterm's `allowWindowOps`), many refuse to reply to the queries for
+//! security reasons, and terminal multiplexers may need to be configured to
+//! pass it through.
+
+use std::time::{Duration, Instant};
+
+use super::{base64, helpers::write_escape};
+use crate::{Nc, NcError, NcInput, NcInputType, NcKey, NcReceived, NcResult, NcTime};
+
+/// How long to wait for the reply of the terminal to a query.
+const REPLY_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// The XTGETTCAP query for the `Ms` terminfo capability, which is the
+/// sequence that sets the clipboard, with the name hex-encoded.
+const MS_QUERY: &str = "\x1bP+q4d73\x1b\\";
+
+/// The start of the reply to [`MS_QUERY`] when the capability is present.
+const MS_REPLY: &str = "\x1bP1+r4d73";
+
+/// The OSC 52 query for the text in the clipboard.
+const OSC52_QUERY: &str = "\x1b]52;c;?\x07";
+
+/// The start of the reply to [`OSC52_QUERY`], followed by the selection.
+const OSC52_REPLY: &str = "\x1b]52;";
+
+/// # `Nc` methods: clipboard
+impl Nc {
+    /// Returns `true` if the terminal reports that it can set the clipboard
+    /// with [`clipboard_set`][Nc#method.clipboard_set].
+    ///
+    /// The terminal is asked for its `Ms` capability with an XTGETTCAP query,
+    /// and the reply is awaited for up to half a second. Any other input
+    /// received meanwhile is discarded. A terminal that doesn't reply, or
+    /// whose reply is consumed by the notcurses input layer, is considered
+    /// not to support it.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "clipboard")))]
+    pub fn clipboard_available(&mut self) -> bool {
+        matches![self.query_reply(MS_QUERY, MS_REPLY), Ok(Some(_))]
+    }
+
+    /// Copies the `text` to the system clipboard, sending an OSC 52 escape
+    /// sequence to the terminal through the output stream of notcurses,
+    /// between renders.
+    ///
+    /// There's no confirmation from the terminal, so this succeeds as long
+    /// as the sequence is written, even if the terminal ignores it.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "clipboard")))]
+    pub fn clipboard_set(&mut self, text: &str) -> NcResult<()> {
+        write_escape(&osc52_set(text)).map_err(|e| {
+            NcError::new_msg(&format![
+                "Nc.clipboard_set({} bytes): {}",
+                text.len(),
+                e.msg
+            ])
+        })
+    }
+
+    /// Returns the text in the system clipboard, asking the terminal for it
+    /// with an OSC 52 query.
+    ///
+    /// The reply is awaited for up to half a second, and any other input
+    /// received meanwhile is discarded. Returns `None` if the terminal
+    /// doesn't reply in time, which is what most terminals do by default.
+    ///
+    /// It's an error if the reply is not valid base64-encoded UTF-8.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "clipboard")))]
+    pub fn clipboard_get(&mut self) -> NcResult<Option<String>> {
+        match self.query_reply(OSC52_QUERY, OSC52_REPLY)? {
+            Some(reply) => osc52_text(&reply).map(Some).ok_or_else(|| {
+                NcError::new_msg(&format!["Nc.clipboard_get(): invalid reply {:?}", reply])
+            }),
+            None => Ok(None),
+        }
+    }
+}
+
+/// # `Nc` clipboard internals
+impl Nc {
+    /// Writes the `query` to the terminal, and returns its reply, from the
+    /// end of `prefix` to the string terminator, or `None` if there's no
+    /// reply before the timeout.
+    fn query_reply(&mut self, query: &str, prefix: &str) -> NcResult<Option<String>> {
+        write_escape(query)?;
+        let deadline = Instant::now() + REPLY_TIMEOUT;
+        let (mut reply, mut input) = (String::new(), NcInput::new_empty());
+        while Instant::now() < deadline {
+            let received = self.get(Some(NcTime::new(0, 10_000_000)), Some(&mut input))?;
+            if input.evtype == NcInputType::Release as u32 {
+                continue;
+            }
+            match received {
+                NcReceived::Char(ch) => reply.push(ch),
+                NcReceived::Key(NcKey::Esc) => reply.push('\x1b'),
+                _ => continue,
+            }
+            if let Some(body) = reply_body(&reply, prefix) {
+                return Ok(Some(body.into()));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Returns the OSC 52 escape sequence that copies the `text` to the clipboard.
+pub(crate) fn osc52_set(text: &str) -> String {
+    format!["\x1b]52;c;{}\x07", base64::encode(text.as_bytes())]
+}
+
+/// Returns the body of a reply received in `input`, between the end of
+/// `prefix` and the string terminator, BEL or ST, if it's complete.
+pub(crate) fn reply_body<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let body = &input[input.find(prefix)? + prefix.len()..];
+    let end = body
+        .find(|ch| ch == '\x07' || ch == '\u{9c}')
+        .into_iter()
+        .chain(body.find("\x1b\\"))
+        .min()?;
+    Some(&body[..end])
+}
+
+/// Returns the text of the body of an OSC 52 reply, which is the selection,
+/// a semicolon, and the base64-encoded text.
+pub(crate) fn osc52_text(body: &str) -> Option<String> {
+    let (_selection, encoded) = body.split_once(';')?;
+    String::from_utf8(base64::decode(encoded)?).ok()
+}
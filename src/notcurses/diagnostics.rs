@@ -0,0 +1,224 @@
+//! `Nc` diagnostics of the planes.
+
+use core::fmt::Write;
+
+use crate::{
+    c_api, plane::userdata::NcPlaneUserData, rstring_free, Nc, NcAlign, NcPlane, NcResult, NcStats,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+/// A plane of the standard pile, with its depth in the hierarchy.
+struct PlaneInfo {
+    plane: *const NcPlane,
+    /// The number of ancestors.
+    depth: usize,
+}
+
+/// # `Nc` methods: diagnostics
+impl Nc {
+    /// Returns the hierarchy of the planes of the standard pile as an
+    /// indented tree, with one plane per line, and its size and position
+    /// relative to its parent.
+    ///
+    /// The children of a plane are listed from the top to the bottom.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn plane_tree(&self) -> String {
+        let planes = self.pile_planes();
+        let mut tree = String::new();
+        for info in &planes {
+            let _ = writeln![
+                tree,
+                "{:indent$}{}",
+                "",
+                plane_summary(info.plane),
+                indent = info.depth * 2
+            ];
+        }
+        tree
+    }
+
+    /// Returns a table with the geometry, z-order and user data of the planes
+    /// of the standard pile, followed by the render stats.
+    ///
+    /// It's meant for debugging layered interfaces. The output is freeform,
+    /// and subject to change.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn diagnostics(&self) -> String {
+        let planes = self.pile_planes();
+        let names: Vec<String> = planes
+            .iter()
+            .map(|info| {
+                let name = plane_name(unsafe { &*info.plane });
+                format!["{:indent$}{}", "", name, indent = info.depth * 2]
+            })
+            .collect();
+        let name_width = names
+            .iter()
+            .map(|n| n.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        let mut out = String::new();
+        let _ = writeln![
+            out,
+            "{:>3} {:name_width$} {:>5} {:>5} {:>5} {:>5} user data",
+            "z", "name", "y", "x", "rows", "cols",
+        ];
+        // the z-order is counted from the top
+        for (z, (info, name)) in planes.iter().zip(&names).enumerate() {
+            let plane = unsafe { &*info.plane };
+            let (y, x) = plane.yx();
+            let (rows, cols) = plane.dim_yx();
+            let _ = writeln![
+                out,
+                "{:>3} {:name_width$} {:>5} {:>5} {:>5} {:>5} {}",
+                z,
+                name,
+                y,
+                x,
+                rows,
+                cols,
+                user_data_summary(info.plane),
+            ];
+        }
+
+        let stats = unsafe { &mut *c_api::notcurses_stats_alloc(self) };
+        // notcurses_stats only locks the stats of the context to copy them
+        unsafe { c_api::notcurses_stats(self as *const Nc as *mut Nc, stats) };
+        let _ = writeln![
+            out,
+            "renders: {} ({} failed, {} ns), writeouts: {} ({} failed, {} ns), raster: {} bytes ({} ns)",
+            stats.renders,
+            stats.failed_renders,
+            stats.render_ns,
+            stats.writeouts,
+            stats.failed_writeouts,
+            stats.writeout_ns,
+            stats.raster_bytes,
+            stats.raster_ns,
+        ];
+        unsafe { c_api::ffi::free(stats as *mut NcStats as *mut core::ffi::c_void) };
+        out
+    }
+
+    /// Writes the [`diagnostics`] to `plane`, one line per row starting at
+    /// the row of the cursor, [`NcAlign`]ed on *x* as a block.
+    ///
+    /// It is an error if the plane doesn't have enough rows or columns.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`diagnostics`]: Nc#method.diagnostics
+    pub fn print_diagnostics(
+        &self,
+        plane: &mut NcPlane,
+        align: impl Into<NcAlign>,
+    ) -> NcResult<()> {
+        let align = align.into();
+        let diagnostics = self.diagnostics();
+        let width = diagnostics
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let y = plane.cursor_y();
+        for (row, line) in (y..).zip(diagnostics.lines()) {
+            plane.putstr_aligned(Some(row), align, &format!["{:width$}", line])?;
+        }
+        Ok(())
+    }
+
+    /// Returns the planes of the standard pile in depth-first order, with
+    /// the children of each plane ordered from the top to the bottom.
+    fn pile_planes(&self) -> Vec<PlaneInfo> {
+        // the planes are walked once, from the bottom to the top
+        let stdplane = unsafe { c_api::notcurses_stdplane_const(self) } as *mut NcPlane;
+        let mut zorder: Vec<*const NcPlane> = Vec::new();
+        let mut index = BTreeMap::new();
+        let mut plane = unsafe { c_api::ncpile_bottom(stdplane) };
+        while !plane.is_null() {
+            index.insert(plane as *const NcPlane, zorder.len());
+            zorder.push(plane);
+            plane = unsafe { c_api::ncplane_above(plane) };
+        }
+
+        // the roots and the children of each plane, from the top to the bottom
+        let mut roots = Vec::new();
+        let mut children = vec![Vec::new(); zorder.len()];
+        for (i, plane) in zorder.iter().enumerate().rev() {
+            let parent = unsafe { c_api::ncplane_parent_const(*plane) };
+            match index.get(&parent) {
+                Some(&p) if parent != *plane => children[p].push(i),
+                _ => roots.push(i),
+            }
+        }
+
+        let mut planes = Vec::with_capacity(zorder.len());
+        // the planes are popped from the stack from the top to the bottom
+        let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|i| (i, 0)).collect();
+        while let Some((i, depth)) = stack.pop() {
+            stack.extend(children[i].iter().rev().map(|c| (*c, depth + 1)));
+            planes.push(PlaneInfo { plane: zorder[i], depth });
+        }
+        planes
+    }
+}
+
+/// Returns the name of the `plane`, or `-` if it has none.
+fn plane_name(plane: &NcPlane) -> String {
+    let name = unsafe { c_api::ncplane_name(plane) };
+    if name.is_null() {
+        "-".to_string()
+    } else {
+        rstring_free![name]
+    }
+}
+
+/// Returns a line describing the `plane`, its size and its position.
+fn plane_summary(plane: *const NcPlane) -> String {
+    let plane = unsafe { &*plane };
+    let (y, x) = plane.yx();
+    let (rows, cols) = plane.dim_yx();
+    format!["{} {}x{} at {},{}", plane_name(plane), rows, cols, y, x]
+}
+
+/// Returns the kinds of Rust data stored in the user pointer of the `plane`.
+fn user_data_summary(plane: *const NcPlane) -> String {
+    let mut kinds = Vec::new();
+    if let Some(userdata) = NcPlaneUserData::get_ref(unsafe { &*plane }) {
+        if userdata.data.is_some() {
+            kinds.push("data");
+        }
+        if userdata.resize_fn.is_some() {
+            kinds.push("resize callback");
+        }
+        if userdata.scrollback.is_some() {
+            kinds.push("scrollback");
+        }
+        if userdata.input_mode.is_some() {
+            kinds.push("input mode");
+        }
+        #[cfg(feature = "regex")]
+        if userdata.highlights.is_some() {
+            kinds.push("highlights");
+        }
+    }
+    if kinds.is_empty() {
+        "-".to_string()
+    } else {
+        kinds.join(", ")
+    }
+}
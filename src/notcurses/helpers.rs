@@ -1,4 +1,11 @@
-use crate::{c_api::notcurses_init, Nc, NcFlag, NcOptions};
+use crate::{
+    c_api::{self, ffi::stdout, notcurses_init},
+    Nc, NcError, NcFlag, NcOptions, NcResult,
+};
+use core::ffi::c_void;
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
 
 /// Helper function for initializing Nc on C style tests.
 #[allow(dead_code)]
@@ -8,3 +15,26 @@ pub(crate) unsafe fn notcurses_init_test<'a>() -> &'a mut Nc {
         core::ptr::null_mut(),
     )
 }
+
+/// Writes an escape `sequence` to the terminal through the C standard output
+/// stream, the same one notcurses renders to, and flushes it.
+///
+/// Notcurses flushes its output at the end of every render, so when called
+/// with exclusive access to the [`Nc`] context the sequence can't end up in
+/// the middle of a frame.
+pub(crate) fn write_escape(sequence: &str) -> NcResult<()> {
+    let len = sequence.len();
+    let written = unsafe {
+        let written = c_api::ffi::fwrite(sequence.as_ptr() as *const c_void, 1, len as _, stdout);
+        c_api::ffi::fflush(stdout);
+        written
+    };
+    if written as usize == len {
+        Ok(())
+    } else {
+        Err(NcError::new_msg(&format![
+            "wrote {} of {} bytes",
+            written, len
+        ]))
+    }
+}
@@ -0,0 +1,85 @@
+//! `NcInputStream`
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::os::unix::io::{AsRawFd, RawFd};
+
+use futures_core::Stream;
+use tokio::io::unix::AsyncFd;
+
+use crate::{Nc, NcError, NcInput, NcKey, NcReceived, NcResult};
+
+/// An asynchronous stream of input events from an [`Nc`] context.
+///
+/// It's created with [`Nc.input_stream()`][Nc#method.input_stream], and must
+/// be polled from within a `tokio` runtime.
+///
+/// Each item is the received key and its details, or an error. For Unicode
+/// input the key holds the code point of the character.
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "tokio")))]
+pub struct NcInputStream<'nc> {
+    nc: &'nc mut Nc,
+    fd: AsyncFd<InputFd>,
+}
+
+// The input file descriptor of the notcurses context, which it owns.
+struct InputFd(RawFd);
+
+impl AsRawFd for InputFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+impl<'nc> Stream for NcInputStream<'nc> {
+    type Item = NcResult<(NcKey, NcInput)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            // drain the events already buffered before waiting on the fd,
+            // since notcurses may have read more than one event from it.
+            let mut input = NcInput::new_empty();
+            match this.nc.get_nblock(Some(&mut input)) {
+                Ok(NcReceived::NoInput) => (),
+                Ok(_) => return Poll::Ready(Some(Ok((NcKey(input.id), input)))),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+
+            let mut guard = match this.fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(e)) => {
+                    return Poll::Ready(Some(Err(NcError::new_msg(&format![
+                        "NcInputStream.poll_next() {}",
+                        e
+                    ]))))
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+            guard.clear_ready();
+        }
+    }
+}
+
+/// # `Nc` asynchronous input
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "tokio")))]
+impl Nc {
+    /// Returns an asynchronous [`Stream`] of input events.
+    ///
+    /// It waits for the file descriptor returned by
+    /// [`inputready_fd`][Nc#method.inputready_fd] to become readable,
+    /// and then drains the available events with
+    /// [`get_nblock`][Nc#method.get_nblock].
+    ///
+    /// It must be called from within a `tokio` runtime.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn input_stream(&mut self) -> NcResult<NcInputStream<'_>> {
+        let fd = self.inputready_fd()?;
+        let fd = AsyncFd::new(InputFd(fd))
+            .map_err(|e| NcError::new_msg(&format!["Nc.input_stream() {}", e]))?;
+        Ok(NcInputStream { nc: self, fd })
+    }
+}
@@ -4,9 +4,11 @@ use core::ptr::{null, null_mut};
 
 use crate::{
     c_api::{self, notcurses_init},
-    cstring, error, error_ref_mut, rstring, rstring_free, Nc, NcAlign, NcBlitter, NcCapabilities,
-    NcChannels, NcError, NcFd, NcFlag, NcInput, NcLogLevel, NcMiceEvents, NcOptions, NcPixelImpl,
-    NcPlane, NcReceived, NcResult, NcRgb, NcScale, NcStats, NcStyle, NcTime, NcVisual,
+    cstring, error, error_ref_mut,
+    plane::userdata::NcPlaneUserData,
+    rstring, rstring_free, Nc, NcAlign, NcBlitter, NcCapabilities, NcChannels, NcError, NcFd,
+    NcFlag, NcInput, NcLogLevel, NcMiceEvents, NcOptions, NcPalette, NcPaletteIndex, NcPixelImpl,
+    NcPlane, NcReceived, NcResult, NcRgb, NcRgba, NcScale, NcStats, NcStyle, NcTime, NcVisual,
     NcVisualGeometry, NcVisualOptions,
 };
 
@@ -14,6 +16,7 @@ use crate::{
 use alloc::{
     format,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 
@@ -107,11 +110,15 @@ impl Nc {
 
     /// Destroys the notcurses context.
     ///
+    /// Any Rust data stored by the safe API in the user pointers of the planes
+    /// of the standard pile is dropped.
+    ///
     /// # Safety
     /// You must not call this method repeatedly on the same `Nc` instance.
     ///
     /// *C style function: [notcurses_stop()][c_api::notcurses_stop].*
     pub unsafe fn stop(&mut self) -> NcResult<()> {
+        NcPlaneUserData::drop_pile(c_api::notcurses_stdplane(self));
         error![c_api::notcurses_stop(self)]
     }
 
@@ -185,6 +192,15 @@ impl Nc {
         c_api::notcurses_canchangecolor(self)
     }
 
+    /// Returns true if it's possible to set the "hardware" palette.
+    ///
+    /// This is the same as [`canchangecolor`][Nc#method.canchangecolor].
+    ///
+    /// *C style function: [notcurses_canchangecolor()][c_api::notcurses_canchangecolor].*
+    pub fn can_change_color(&self) -> bool {
+        self.canchangecolor()
+    }
+
     /// Returns true if fading is possible.
     ///
     /// Fading requires either the "rgb" or "ccc" terminfo capability.
@@ -277,6 +293,59 @@ impl Nc {
         unsafe { c_api::notcurses_check_pixel_support(self) }.into()
     }
 
+    /// Returns the concrete blitter that [`NcBlitter::Default`] resolves to
+    /// on the current terminal, when blitting a visual with `scale`.
+    ///
+    /// It's resolved like notcurses does:
+    /// - if lacking UTF-8, [`Ascii`].
+    /// - otherwise, if not [`NcScale::Stretch`], [`Half`].
+    /// - otherwise, if sextants are not known to be good, [`Quadrant`].
+    /// - otherwise [`Sextant`].
+    ///
+    /// The default blitter never uses pixels, which must always be requested
+    /// explicitly.
+    ///
+    /// *C style function: [ncvisual_media_defblitter()][c_api::ncvisual_media_defblitter].*
+    ///
+    /// [`Ascii`]: NcBlitter::Ascii
+    /// [`Half`]: NcBlitter::Half
+    /// [`Quadrant`]: NcBlitter::Quadrant
+    /// [`Sextant`]: NcBlitter::Sextant
+    pub fn default_blitter(&self, scale: impl Into<NcScale>) -> NcBlitter {
+        NcVisual::media_defblitter(self, scale)
+    }
+
+    /// Returns the blitters usable on the current terminal, from the highest
+    /// to the lowest fidelity: [`Pixel`], [`Braille`], [`Sextant`],
+    /// [`Quadrant`], [`Half`] and [`Ascii`], which is always usable.
+    ///
+    /// The [`_4x1`] and [`_8x1`] blitters are not included, since they're
+    /// only usable by plots.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`Pixel`]: NcBlitter::Pixel
+    /// [`Braille`]: NcBlitter::Braille
+    /// [`Sextant`]: NcBlitter::Sextant
+    /// [`Quadrant`]: NcBlitter::Quadrant
+    /// [`Half`]: NcBlitter::Half
+    /// [`Ascii`]: NcBlitter::Ascii
+    /// [`_4x1`]: NcBlitter::_4x1
+    /// [`_8x1`]: NcBlitter::_8x1
+    pub fn supported_blitters(&self) -> Vec<NcBlitter> {
+        [
+            (NcBlitter::Pixel, self.canpixel()),
+            (NcBlitter::Braille, self.canbraille()),
+            (NcBlitter::Sextant, self.cansextant()),
+            (NcBlitter::Quadrant, self.canquadrant()),
+            (NcBlitter::Half, self.canhalfblock()),
+            (NcBlitter::Ascii, true),
+        ]
+        .into_iter()
+        .filter_map(|(blitter, supported)| supported.then_some(blitter))
+        .collect()
+    }
+
     /// Returns the default foreground color, if it is known.
     pub fn default_foreground(&self) -> Option<NcRgb> {
         let mut fg = 0;
@@ -318,6 +387,61 @@ impl Nc {
         error![unsafe { c_api::notcurses_cursor_enable(self, y as i32, x as i32) }]
     }
 
+    /// Returns the position of the terminal's cursor, or `None` if it's
+    /// disabled.
+    ///
+    /// *C style function: [notcurses_cursor_yx()][c_api::notcurses_cursor_yx].*
+    pub fn cursor_yx(&self) -> Option<(u32, u32)> {
+        let (mut y, mut x) = (0, 0);
+        let res = unsafe { c_api::notcurses_cursor_yx(self, &mut y, &mut x) };
+        if res == c_api::NCRESULT_ERR {
+            None
+        } else {
+            Some((y as u32, x as u32))
+        }
+    }
+
+    /// Returns true if the terminal's cursor is enabled.
+    ///
+    /// *C style function: [notcurses_cursor_yx()][c_api::notcurses_cursor_yx].*
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_yx().is_some()
+    }
+
+    /// Enables the terminal's cursor, placing it at `y`, `x`.
+    ///
+    /// This is the same as [`cursor_enable`], but checks the position first.
+    /// It is an error if `y`, `x` lies outside the standard plane, or if the
+    /// terminal doesn't support it.
+    ///
+    /// *C style function: [notcurses_cursor_enable()][c_api::notcurses_cursor_enable].*
+    ///
+    /// [`cursor_enable`]: Nc#method.cursor_enable
+    pub fn show_cursor(&mut self, y: u32, x: u32) -> NcResult<()> {
+        let (rows, cols) = self.stdplane_ref().dim_yx();
+        if y >= rows || x >= cols {
+            return Err(NcError::new_msg(&format![
+                "Nc.show_cursor({}, {}) outside of the standard plane ({}, {})",
+                y, x, rows, cols
+            ]));
+        }
+        error![
+            unsafe { c_api::notcurses_cursor_enable(self, y as i32, x as i32) },
+            &format!["Nc.show_cursor({}, {})", y, x]
+        ]
+    }
+
+    /// Disables the terminal's cursor.
+    ///
+    /// This is the same as [`cursor_disable`].
+    ///
+    /// *C style function: [notcurses_cursor_disable()][c_api::notcurses_cursor_disable].*
+    ///
+    /// [`cursor_disable`]: Nc#method.cursor_disable
+    pub fn hide_cursor(&mut self) -> NcResult<()> {
+        self.cursor_disable()
+    }
+
     /// Shifts to the alternate screen, if available.
     ///
     /// If already using the alternate screen, this returns Ok(()) immediately.
@@ -356,6 +480,33 @@ impl Nc {
         }
     }
 
+    /// Returns the notcurses state, as dumped by [`debug`], in a `String`.
+    ///
+    /// The state is dumped into a temporary file, which is read back.
+    ///
+    /// *C style function: [notcurses_debug()][c_api::notcurses_debug].*
+    ///
+    /// [`debug`]: Nc#method.debug
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn debug_to_string(&mut self) -> NcResult<String> {
+        use std::io::Read;
+
+        let file_ptr = unsafe { libc::tmpfile() };
+        if file_ptr.is_null() {
+            return Err(NcError::new_msg(
+                "Nc.debug_to_string() couldn't create a tmpfile",
+            ));
+        }
+        let mut file = unsafe { NcFile::from_libc(file_ptr) };
+        self.debug(&mut file);
+
+        let mut string = String::new();
+        file.read_to_string(&mut string)
+            .map_err(|e| NcError::new_msg(&format!["Nc.debug_to_string() {}", e]))?;
+        Ok(string)
+    }
+
     /// Returns the name of the user under which we are running.
     ///
     /// *C style function: [notcurses_accountname()][c_api::notcurses_accountname].*
@@ -461,10 +612,17 @@ impl Nc {
     /// This file descriptor is not necessarily the file descriptor associated
     /// with stdin (but it might be!).
     ///
+    /// It's meant to be registered in an external event loop (`poll`, `epoll`,
+    /// `mio`…). Whenever it becomes readable, all the pending events must be
+    /// drained by calling [get_nblock()][Nc#method.get_nblock] until it
+    /// returns [`NcReceived::NoInput`], since a single read may buffer more
+    /// than one event. The descriptor is owned by notcurses and must not be
+    /// closed.
+    ///
     /// *C style function: [notcurses_inputready_fd()][c_api::notcurses_inputready_fd].*
     pub fn inputready_fd(&mut self) -> NcResult<NcFd> {
         let res = unsafe { c_api::notcurses_inputready_fd(self) };
-        error![res, "", res]
+        error![res, "Nc.inputready_fd()", res]
     }
 
     /// Returns an [`NcBlitter`] from a string representation.
@@ -485,10 +643,18 @@ impl Nc {
     /// There can be either a single number, which will define all margins equally,
     /// or there can be four numbers separated by commas.
     ///
+    /// Returns the `(top, right, bottom, left)` margins, which can be passed
+    /// to [`NcOptionsBuilder::margins`][crate::NcOptionsBuilder#method.margins].
+    ///
     /// *C style function: [notcurses_lex_margins()][c_api::notcurses_lex_margins].*
-    pub fn lex_margins(margins_str: &str, options: &mut NcOptions) -> NcResult<()> {
+    pub fn lex_margins(margins_str: &str) -> NcResult<(u32, u32, u32, u32)> {
+        let mut options = NcOptions::new();
         let cs = cstring![margins_str];
-        error![unsafe { c_api::notcurses_lex_margins(cs.as_ptr(), options) }]
+        error![
+            unsafe { c_api::notcurses_lex_margins(cs.as_ptr(), &mut options) },
+            &format!["Nc.lex_margins({:?})", margins_str],
+            options.margins()
+        ]
     }
 
     /// Returns an [`NcScale`] from a string representation.
@@ -545,7 +711,20 @@ impl Nc {
     }
 
     /// Disables signals originating from the terminal's line discipline, i.e.
-    /// SIGINT (^C), SIGQUIT (^), and SIGTSTP (^Z). They are enabled by default.
+    /// SIGINT (^C), SIGQUIT (^\\), and SIGTSTP (^Z). They are enabled by default.
+    ///
+    /// While disabled, those keys are received as input instead.
+    ///
+    /// This is independent of the [`NoQuitSigHandlers`] flag, which only
+    /// prevents notcurses from installing its handlers for SIGINT, SIGQUIT
+    /// and other fatal signals, that restore the terminal before exiting.
+    /// With the line signals enabled and that flag set, ^C terminates the
+    /// process without restoring the terminal, unless the application
+    /// handles the signal itself.
+    ///
+    /// It succeeds without doing anything if there's no controlling terminal.
+    ///
+    /// [`NoQuitSigHandlers`]: crate::NcFlag#associatedconstant.NoQuitSigHandlers
     ///
     /// *C style function: [notcurses_linesigs_disable()][c_api::notcurses_linesigs_disable].*
     pub fn linesigs_disable(&mut self) -> NcResult<()> {
@@ -553,7 +732,12 @@ impl Nc {
     }
 
     /// Restores signals originating from the terminal's line discipline, i.e.
-    /// SIGINT (^C), SIGQUIT (^), and SIGTSTP (^Z), if disabled.
+    /// SIGINT (^C), SIGQUIT (^\\), and SIGTSTP (^Z), if disabled.
+    ///
+    /// See [`linesigs_disable`][Nc#method.linesigs_disable] for the interaction
+    /// with the [`NoQuitSigHandlers`] flag.
+    ///
+    /// [`NoQuitSigHandlers`]: crate::NcFlag#associatedconstant.NoQuitSigHandlers
     ///
     /// *C style function: [notcurses_linesigs_enable()][c_api::notcurses_linesigs_enable].*
     pub fn linesigs_enable(&mut self) -> NcResult<()> {
@@ -596,6 +780,41 @@ impl Nc {
         Ok(res)
     }
 
+    /// Returns a snapshot of the current [`NcPalette`].
+    ///
+    /// *C style function: [ncpalette_new()][c_api::ncpalette_new].*
+    pub fn palette(&mut self) -> NcResult<NcPalette> {
+        let palette = unsafe { c_api::ncpalette_new(self) };
+        if palette.is_null() {
+            return Err(NcError::new_msg("Nc.palette()"));
+        }
+        let snapshot = NcPalette { chans: unsafe { (*palette).chans } };
+        unsafe { c_api::ncpalette_free(palette) };
+        Ok(snapshot)
+    }
+
+    /// Sets the palette entry at `index` to the RGB components of `color`,
+    /// and configures the terminal with the modified palette.
+    ///
+    /// Returns an error if the terminal doesn't allow to
+    /// [change the palette][Nc#method.can_change_color].
+    ///
+    /// *C style function: [ncpalette_use()][c_api::ncpalette_use].*
+    pub fn set_palette_entry(&mut self, index: NcPaletteIndex, color: NcRgba) -> NcResult<()> {
+        if !self.can_change_color() {
+            return Err(NcError::new_msg(&format![
+                "Nc.set_palette_entry({}, {:?}): the palette can't be changed",
+                index, color
+            ]));
+        }
+        let mut palette = self.palette()?;
+        palette.set(index, NcRgb(color.0 & 0xFF_FFFF));
+        error![
+            unsafe { c_api::ncpalette_use(self, &palette) },
+            &format!["Nc.set_palette_entry({}, {:?})", index, color]
+        ]
+    }
+
     /// Refreshes the physical screen to match what was last rendered (i.e.,
     /// without reflecting any changes since the last call to
     /// [`render`][crate::Nc#method.render]).
@@ -603,7 +822,7 @@ impl Nc {
     /// Returns the current screen geometry (`y`, `x`).
     ///
     /// This is primarily useful if the screen is externally corrupted, or if an
-    /// [NcKey::Resize][crate::NcKey#associatedconstant.Resize] event
+    /// [`NcReceived::Resize`][crate::NcReceived::Resize] event
     /// has been read and you're not yet ready to render.
     ///
     /// *C style function: [notcurses_refresh()][c_api::notcurses_refresh].*
@@ -623,6 +842,36 @@ impl Nc {
         error![c_api::notcurses_render(self), "Nc.render()"]
     }
 
+    /// Renders the standard pile, and writes the resulting frame in its
+    /// entirety to the file at `path`, which is created or truncated.
+    ///
+    /// The pile is not rasterized, so the terminal is not updated.
+    ///
+    /// *C style function: [ncpile_render_to_file()][c_api::ncpile_render_to_file].*
+    #[cfg(feature = "std")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn render_to_file(&mut self, path: impl AsRef<std::path::Path>) -> NcResult<()> {
+        use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+        let path = path.as_ref();
+        let cpath = CString::new(path.as_os_str().as_bytes())
+            .map_err(|e| NcError::new_msg(&format!["Nc.render_to_file({:?}) {}", path, e]))?;
+        let file_ptr = unsafe { libc::fopen(cpath.as_ptr(), "w\0".as_ptr() as *const _) };
+        if file_ptr.is_null() {
+            return Err(NcError::new_msg(&format![
+                "Nc.render_to_file({:?}) {}",
+                path,
+                std::io::Error::last_os_error()
+            ]));
+        }
+        // the file is closed when dropped
+        let mut file = unsafe { NcFile::from_libc(file_ptr) };
+
+        let stdplane = self.stdplane_mut();
+        stdplane.render()?;
+        stdplane.render_to_file(&mut file)
+    }
+
     /// Acquires an atomic snapshot of the notcurses object's stats.
     ///
     /// *C style function: [notcurses_stats()][c_api::notcurses_stats].*
@@ -708,6 +957,29 @@ impl Nc {
         &*c_api::notcurses_stdplane_const(self)
     }
 
+    /// Returns a mutable reference to the standard [`NcPlane`] for this
+    /// terminal, bound to the lifetime of this `Nc`.
+    ///
+    /// Unlike [`stdplane`][Nc#method.stdplane], it's safe, since the borrow
+    /// checker prevents any other use of the context while it's alive.
+    ///
+    /// *C style function: [notcurses_stdplane()][c_api::notcurses_stdplane].*
+    pub fn stdplane_mut(&mut self) -> &mut NcPlane {
+        unsafe { &mut *c_api::notcurses_stdplane(self) }
+    }
+
+    /// Returns a reference to the standard [`NcPlane`] for this terminal,
+    /// bound to the lifetime of this `Nc`.
+    ///
+    /// Unlike [`stdplane_const`][Nc#method.stdplane_const], it's safe, since
+    /// the borrow checker prevents any exclusive use of the context while
+    /// it's alive.
+    ///
+    /// *C style function: [notcurses_stdplane_const()][c_api::notcurses_stdplane_const].*
+    pub fn stdplane_ref(&self) -> &NcPlane {
+        unsafe { &*c_api::notcurses_stdplane_const(self) }
+    }
+
     /// Gets the name of an [`NcBlitter`] blitter.
     ///
     /// *C style function: [notcurses_str_blitter()][c_api::notcurses_str_blitter].*
@@ -778,6 +1050,41 @@ impl Nc {
         c_api::notcurses_top(self)
     }
 
+    /// Converts a series of UCS-32 `codepoints`, like the ones returned by the
+    /// input functions, to an UTF-8 `String`.
+    ///
+    /// It is an error if any of the `codepoints` is not a valid Unicode
+    /// scalar value.
+    ///
+    /// *C style function: [notcurses_ucs32_to_utf8()][c_api::notcurses_ucs32_to_utf8].*
+    pub fn ucs32_to_utf8(codepoints: &[u32]) -> NcResult<String> {
+        if codepoints.is_empty() {
+            return Ok(String::new());
+        }
+        // 6 bytes per code point future-proofs against Unicode expansion
+        let mut buf = vec![0u8; codepoints.len() * 6];
+        let res = unsafe {
+            c_api::notcurses_ucs32_to_utf8(
+                codepoints.as_ptr(),
+                codepoints.len() as u32,
+                buf.as_mut_ptr(),
+                buf.len(),
+            )
+        };
+        if res < 0 {
+            let invalid = codepoints.iter().find(|cp| char::from_u32(**cp).is_none());
+            return Err(NcError::with_msg(
+                res,
+                &match invalid {
+                    Some(cp) => format!["Nc.ucs32_to_utf8() invalid code point {:#X}", cp],
+                    None => "Nc.ucs32_to_utf8()".to_string(),
+                },
+            ));
+        }
+        buf.truncate(res as usize);
+        String::from_utf8(buf).map_err(|e| NcError::new_msg(&format!["Nc.ucs32_to_utf8() {}", e]))
+    }
+
     /// Returns a human-readable string describing the running notcurses version.
     ///
     /// *C style function: [notcurses_version()][c_api::notcurses_version].*
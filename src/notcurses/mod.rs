@@ -2,16 +2,16 @@
 
 // total: 55
 // ---------------------------------------------------
-// (X)  1 : wont do
+// (X)  0 : wont do
 // (…)  4 : TODO / WIP
 //
-// (f) 36 : unsafe ffi function exported by bindgen
+// (f) 37 : unsafe ffi function exported by bindgen
 // (w)  0 : safely wrapped ffi function
 // (r) 15 : static function manually reimplemented
 //
-// (m) 42 : method implemented
+// (m) 44 : method implemented
 //
-// (t) 13 : unit test done for the function
+// (t) 18 : unit test done for the function
 // (T)  0 : unit test done also for the method
 // ---------------------------------------------------
 // fm  notcurses_at_yx
@@ -28,7 +28,7 @@
 //~f   notcurses_core_init
 // fm  notcurses_cursor_disable
 // fm  notcurses_cursor_enable
-// f   notcurses_cursor_yx
+// fmt notcurses_cursor_yx
 // fmt notcurses_debug
 // fm  notcurses_default_background
 // fm  notcurses_default_foreground
@@ -39,10 +39,10 @@
 // fmt notcurses_init
 // fm  notcurses_inputready_fd
 // fm  notcurses_lex_blitter
-// fm  notcurses_lex_margins
+// fmt notcurses_lex_margins
 // fm  notcurses_lex_scalemode
-// fm  notcurses_linesigs_disable
-// fm  notcurses_linesigs_enable
+// fmt notcurses_linesigs_disable
+// fmt notcurses_linesigs_enable
 // fm  notcurses_mice_enable
 // rm  notcurses_mice_disable
 // fm  notcurses_osversion
@@ -59,7 +59,7 @@
 // fm  notcurses_str_scalemode
 // fm  notcurses_supported_styles
 // rm  notcurses_top
-//X    notcurses_ucs32_to_utf8 (not needed in rust)
+// fmt notcurses_ucs32_to_utf8
 // fmt notcurses_version
 // fm  notcurses_version_components
 // rmt notcurses_align
@@ -70,7 +70,16 @@
 //~r   notcurses_stddim_yx_const     //
 // rm  notcurses_term_dim_yx
 
+#[cfg(feature = "clipboard")]
+mod base64;
+#[cfg(feature = "clipboard")]
+mod clipboard;
+mod diagnostics;
+#[cfg(feature = "tokio")]
+mod input_stream;
 mod methods;
+#[cfg(feature = "std")]
+mod title;
 
 pub(crate) mod helpers;
 pub(crate) mod options;
@@ -79,6 +88,8 @@ pub(crate) mod reimplemented;
 #[cfg(test)]
 mod test;
 
+#[cfg(feature = "tokio")]
+pub use input_stream::NcInputStream;
 pub use options::{NcFlag, NcOptions, NcOptionsBuilder};
 
 /// Notcurses state for a given terminal, composed of [`NcPlane`]s.
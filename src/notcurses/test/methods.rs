@@ -1,4 +1,374 @@
 //! Test `Notcurses` methods and associated functions.
 
-// use crate::Notcurses;
-// use serial_test::serial;
+use crate::{c_api::notcurses_init_test, Nc, NcRgb, NcRgba};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use serial_test::serial;
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn debug_to_string() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let state = nc.debug_to_string().unwrap();
+        nc.stop().unwrap();
+
+        assert![state.contains("notcurses debug state")];
+        // the standard plane is named "std"
+        assert![state.contains("std")];
+    }
+}
+
+#[test]
+fn ucs32_to_utf8() {
+    // ASCII, 2-byte, 3-byte, and astral-plane code points
+    let codepoints = [0x41, 0x7A, 0xE9, 0x2588, 0x1F600, 0x10348];
+    assert_eq![Nc::ucs32_to_utf8(&codepoints).unwrap(), "Azé█😀𐍈"];
+    assert_eq![Nc::ucs32_to_utf8(&[]).unwrap(), ""];
+
+    // surrogates and values beyond U+10FFFF are not valid
+    assert![Nc::ucs32_to_utf8(&[0x41, 0xD800]).is_err()];
+    assert![Nc::ucs32_to_utf8(&[0x110000]).is_err()];
+}
+
+#[test]
+fn lex_margins() {
+    // top, right, bottom, left
+    assert_eq![Nc::lex_margins("10,20,30,40").unwrap(), (10, 20, 30, 40)];
+
+    // a single value applies to all margins
+    assert_eq![Nc::lex_margins("5").unwrap(), (5, 5, 5, 5)];
+
+    assert![Nc::lex_margins("10,20,x,40").is_err()];
+    assert![Nc::lex_margins("10,20").is_err()];
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "tokio")]
+fn input_stream() {
+    use crate::{c_api::libc, NcKey};
+    use core::{future::poll_fn, pin::Pin, task::Poll};
+    use futures_core::Stream;
+
+    unsafe {
+        // a raw pseudoterminal replaces the standard input while the context
+        // is alive, so that keystrokes can be written to its master side
+        let master = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+        assert![master >= 0];
+        assert_eq![libc::grantpt(master), 0];
+        assert_eq![libc::unlockpt(master), 0];
+        let slave = libc::open(libc::ptsname(master), libc::O_RDWR | libc::O_NOCTTY);
+        assert![slave >= 0];
+        let mut termios = core::mem::zeroed();
+        assert_eq![libc::tcgetattr(slave, &mut termios), 0];
+        libc::cfmakeraw(&mut termios);
+        assert_eq![libc::tcsetattr(slave, libc::TCSANOW, &termios), 0];
+        let stdin = libc::dup(libc::STDIN_FILENO);
+        libc::dup2(slave, libc::STDIN_FILENO);
+        libc::close(slave);
+
+        let nc = notcurses_init_test();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        let received = runtime.block_on(async {
+            let mut stream = nc.input_stream().unwrap();
+
+            // without any input, the stream waits for the input fd
+            let pending =
+                poll_fn(|cx| Poll::Ready(Pin::new(&mut stream).poll_next(cx).is_pending())).await;
+            assert![pending];
+
+            assert_eq![libc::write(master, b"a".as_ptr() as *const _, 1), 1];
+            poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await
+        });
+        assert_eq![received.unwrap().unwrap().0, NcKey('a' as u32)];
+        nc.stop().unwrap();
+
+        libc::dup2(stdin, libc::STDIN_FILENO);
+        libc::close(stdin);
+        libc::close(master);
+    }
+}
+
+#[test]
+#[serial]
+fn inputready_fd() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let fd = nc.inputready_fd().unwrap();
+        assert![fd >= 0];
+        nc.stop().unwrap();
+    }
+}
+
+#[test]
+#[serial]
+fn default_blitter() {
+    use crate::{NcBlitter, NcScale};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        for scale in [NcScale::None, NcScale::Scale, NcScale::Stretch] {
+            let blitter = nc.default_blitter(scale);
+            assert_ne![blitter, NcBlitter::Default];
+            assert_ne![blitter, NcBlitter::Pixel];
+        }
+        if !nc.canutf8() {
+            assert_eq![nc.default_blitter(NcScale::Stretch), NcBlitter::Ascii];
+        } else {
+            assert_eq![nc.default_blitter(NcScale::None), NcBlitter::Half];
+            assert_eq![nc.default_blitter(NcScale::Scale), NcBlitter::Half];
+            let stretch = nc.default_blitter(NcScale::Stretch);
+            assert![[NcBlitter::Quadrant, NcBlitter::Sextant].contains(&stretch)];
+        }
+        nc.stop().unwrap();
+    }
+}
+
+#[test]
+#[serial]
+fn supported_blitters() {
+    use crate::{NcBlitter, NcScale};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let blitters = nc.supported_blitters();
+        assert_eq![blitters.last(), Some(&NcBlitter::Ascii)];
+        assert_eq![blitters.contains(&NcBlitter::Half), nc.canhalfblock()];
+        if nc.canutf8() {
+            assert![blitters.contains(&NcBlitter::Half)];
+        }
+        // the default blitter for unstretched visuals is always usable
+        assert![blitters.contains(&nc.default_blitter(NcScale::None))];
+        nc.stop().unwrap();
+    }
+}
+
+#[test]
+#[serial]
+fn cursor() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let (rows, cols) = nc.stdplane().dim_yx();
+
+        // the cursor is disabled by default
+        assert![!nc.cursor_visible()];
+        assert_eq![nc.cursor_yx(), None];
+        assert![nc.show_cursor(rows, 0).is_err()];
+        assert![nc.show_cursor(0, cols).is_err()];
+
+        // it's only enabled if the terminal supports it
+        if nc.show_cursor(1, 2).is_ok() {
+            assert![nc.cursor_visible()];
+            assert_eq![nc.cursor_yx(), Some((1, 2))];
+            nc.hide_cursor().unwrap();
+            assert![!nc.cursor_visible()];
+        } else {
+            assert![!nc.cursor_visible()];
+        }
+        nc.stop().unwrap();
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn render_to_file() {
+    // named after the process, so that concurrent test runs don't clash
+    let path = std::env::temp_dir().join(format![
+        "libnotcurses-sys-render_to_file-{}.txt",
+        std::process::id()
+    ]);
+    unsafe {
+        let nc = notcurses_init_test();
+        nc.stdplane().putstr("hello").unwrap();
+        nc.render_to_file(&path).unwrap();
+        assert![nc.render_to_file("/nonexistent/dir/file").is_err()];
+        nc.stop().unwrap();
+    }
+    let contents = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert![!contents.is_empty()];
+    assert![contents.contains(&0x1b)];
+    assert![String::from_utf8_lossy(&contents).contains("hello")];
+}
+
+#[test]
+#[serial]
+fn palette() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let palette = nc.palette().unwrap();
+
+        if nc.can_change_color() {
+            let color = NcRgba::new(0x12, 0x34, 0x56, 0xFF);
+            nc.set_palette_entry(1, color).unwrap();
+            assert_eq![NcRgb(0x123456), nc.palette().unwrap().get(1)];
+
+            // restore the original entry
+            nc.set_palette_entry(1, NcRgba(palette.get(1).0)).unwrap();
+            assert_eq![palette.get(1), nc.palette().unwrap().get(1)];
+        } else {
+            assert![nc.set_palette_entry(1, NcRgba(0)).is_err()];
+        }
+        nc.stop().unwrap();
+    }
+}
+
+#[test]
+#[serial]
+fn plane_tree() {
+    use crate::{cstring, NcAlign, NcPlane};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let stdplane = nc.stdplane();
+        let set_name = |plane: &mut NcPlane, name: &str| {
+            let name = cstring![name];
+            crate::c_api::ncplane_set_name(plane, name.as_ptr());
+        };
+
+        let a = NcPlane::new_child_sized(stdplane, 1, 2, 3, 4).unwrap();
+        set_name(a, "a");
+        let b = NcPlane::new_child_sized(a, 0, 1, 1, 2).unwrap();
+        set_name(b, "b");
+        let c = NcPlane::new_child_sized(stdplane, 5, 5, 1, 1).unwrap();
+        set_name(c, "c");
+        c.set_user_data(42_u32);
+
+        // new planes are placed on top, so `c` is listed first
+        let tree = nc.plane_tree();
+        let lines: Vec<&str> = tree.lines().collect();
+        assert![lines[0].starts_with("std ")];
+        assert_eq![
+            lines[1..],
+            ["  c 1x1 at 5,5", "  a 3x4 at 1,2", "    b 1x2 at 0,1"]
+        ];
+
+        let diagnostics = nc.diagnostics();
+        let lines: Vec<&str> = diagnostics.lines().collect();
+        assert_eq![lines.len(), 6];
+        assert![lines[0].ends_with("user data")];
+        assert![lines[2].contains(" c ") && lines[2].ends_with(" data")];
+        assert![lines[4].contains("    b ") && lines[4].ends_with(" -")];
+        assert![lines[5].starts_with("renders: ")];
+
+        // the table is written as a block, one line per row
+        let out = NcPlane::new_pile_sized(nc, 0, 0, 8, 120).unwrap();
+        out.cursor_move_yx(1, 0).unwrap();
+        nc.print_diagnostics(out, NcAlign::Right).unwrap();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap();
+        assert_eq![out.row_as_string(0).unwrap(), ""];
+        let row = out.row_as_string(1).unwrap();
+        assert_eq![row.trim_start(), lines[0].trim_end()];
+        assert_eq![row.len() - row.trim_start().len(), 120 - width];
+        assert![out.row_as_string(6).unwrap().contains("renders: ")];
+        let short = NcPlane::new_pile_sized(nc, 0, 0, 3, 120).unwrap();
+        assert![nc.print_diagnostics(short, NcAlign::Left).is_err()];
+
+        nc.stop().unwrap();
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "clipboard")]
+fn clipboard_set() {
+    use crate::notcurses::clipboard::osc52_set;
+
+    assert_eq![osc52_set(""), "\x1b]52;c;\x07"];
+    assert_eq![osc52_set("hello"), "\x1b]52;c;aGVsbG8=\x07"];
+    assert_eq![osc52_set("hello!"), "\x1b]52;c;aGVsbG8h\x07"];
+    assert_eq![osc52_set("ñ"), "\x1b]52;c;w7E=\x07"];
+}
+
+#[test]
+#[cfg(feature = "clipboard")]
+fn clipboard_base64() {
+    use crate::notcurses::base64::{decode, encode};
+
+    for text in ["", "h", "he", "hel", "hello", "ñandú 🦩"] {
+        assert_eq![decode(&encode(text.as_bytes())).unwrap(), text.as_bytes()];
+    }
+    assert_eq![encode(b"hello"), "aGVsbG8="];
+    // the padding is optional
+    assert_eq![decode("aGVsbG8").unwrap(), b"hello"];
+    assert![decode("aGVsb").is_none()];
+    assert![decode("aGV*bG8=").is_none()];
+}
+
+#[test]
+#[cfg(feature = "clipboard")]
+fn clipboard_reply() {
+    use crate::notcurses::clipboard::{osc52_text, reply_body};
+
+    let prefix = "\x1b]52;";
+    // the reply can be preceded by other input, and end with BEL or ST
+    let body = reply_body("ab\x1b]52;c;aGVsbG8=\x07", prefix).unwrap();
+    assert_eq![osc52_text(body).unwrap(), "hello"];
+    let body = reply_body("\x1b]52;p;w7E=\x1b\\", prefix).unwrap();
+    assert_eq![osc52_text(body).unwrap(), "ñ"];
+    // incomplete, or not a reply
+    assert![reply_body("\x1b]52;c;aGVs", prefix).is_none()];
+    assert![reply_body("\x1bP1+r4d73\x1b\\", prefix).is_none()];
+    assert![osc52_text("c;*").is_none()];
+
+    let body = reply_body("\x1bP1+r4d73=5c45\x1b\\", "\x1bP1+r4d73").unwrap();
+    assert_eq![body, "=5c45"];
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn set_title() {
+    use crate::notcurses::title::osc_title;
+
+    assert_eq![osc_title(2, "title"), "\x1b]2;title\x07"];
+    // control characters can't end the sequence early
+    assert_eq![
+        osc_title(0, "a\x07b\x1b\\c\u{9c}d\ne"),
+        "\x1b]0;ab\\cde\x07"
+    ];
+    assert_eq![osc_title(1, "ñandú 🦩"), "\x1b]1;ñandú 🦩\x07"];
+    assert_eq![osc_title(2, "\x1b]0;nested\x07"), "\x1b]2;]0;nested\x07"];
+}
+
+#[test]
+#[serial]
+fn stdplane_ref() {
+    let nc = unsafe { notcurses_init_test() };
+
+    let plane = nc.stdplane_mut();
+    plane.putstr_yx(Some(0), Some(0), "hello").unwrap();
+    assert_eq![plane.strdup_egc_at(0, 4).unwrap(), "o"];
+    nc.render().unwrap();
+
+    let (rows, cols) = nc.stdplane_ref().dim_yx();
+    assert_eq![(rows, cols), nc.term_dim_yx()];
+    assert_eq![nc.stdplane_ref().cursor_yx(), (0, 5)];
+
+    unsafe { nc.stop().unwrap() };
+}
+
+#[test]
+#[serial]
+fn linesigs() {
+    // the line discipline can only be changed with a controlling terminal
+    if unsafe { libc::isatty(libc::STDIN_FILENO) } == 0 {
+        return;
+    }
+    unsafe {
+        let nc = notcurses_init_test();
+        nc.linesigs_disable().unwrap();
+        nc.linesigs_disable().unwrap();
+        nc.linesigs_enable().unwrap();
+        nc.linesigs_enable().unwrap();
+        nc.stop().unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+//! `Nc` window and icon titles, with OSC escape sequences.
+
+use super::helpers::write_escape;
+use crate::{Nc, NcError, NcResult};
+
+/// # `Nc` methods: window title
+///
+/// The titles are set with OSC escape sequences written to the output stream
+/// of notcurses, between renders. Terminals that don't support them ignore
+/// them, and there's no way to know whether they were applied.
+impl Nc {
+    /// Sets the title of the terminal window.
+    ///
+    /// See [`set_title_and_icon`][Nc#method.set_title_and_icon].
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn set_title(&mut self, title: &str) -> NcResult<()> {
+        self.write_title(2, title, "set_title")
+    }
+
+    /// Sets the icon title of the terminal window, used by some terminals
+    /// for the minimized window or the tab.
+    ///
+    /// See [`set_title_and_icon`][Nc#method.set_title_and_icon].
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn set_icon_title(&mut self, title: &str) -> NcResult<()> {
+        self.write_title(1, title, "set_icon_title")
+    }
+
+    /// Sets both the title and the icon title of the terminal window.
+    ///
+    /// Control characters are removed from the `title`, since they could
+    /// end the escape sequence early.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "std")))]
+    pub fn set_title_and_icon(&mut self, title: &str) -> NcResult<()> {
+        self.write_title(0, title, "set_title_and_icon")
+    }
+
+    /// Writes the OSC `kind` sequence with the `title`.
+    fn write_title(&mut self, kind: u8, title: &str, method: &str) -> NcResult<()> {
+        write_escape(&osc_title(kind, title))
+            .map_err(|e| NcError::new_msg(&format!["Nc.{}({:?}): {}", method, title, e.msg]))
+    }
+}
+
+/// Returns the OSC `kind` escape sequence that sets the `title`, without its
+/// control characters.
+pub(crate) fn osc_title(kind: u8, title: &str) -> String {
+    let title: String = title.chars().filter(|c| !c.is_control()).collect();
+    format!["\x1b]{};{}\x07", kind, title]
+}
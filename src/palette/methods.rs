@@ -1,6 +1,6 @@
 //! `NcPalette` methods and associated functions.
 
-use crate::{c_api, error, Nc, NcPalette, NcPaletteIndex, NcResult, NcRgb};
+use crate::{c_api, error, Nc, NcPalette, NcPaletteIndex, NcResult, NcRgb, NcRgba};
 
 impl NcPalette {
     /// Returns a new `NcPalette`.
@@ -41,4 +41,28 @@ impl NcPalette {
     pub fn set(&mut self, index: impl Into<NcPaletteIndex>, rgb: impl Into<NcRgb>) {
         c_api::ncpalette_set(self, index.into(), rgb.into().into())
     }
+
+    /// Returns the index of the entry closest to `rgb`, by the squared
+    /// euclidean distance between their RGB components.
+    ///
+    /// The alpha component is ignored, and ties resolve to the lowest index.
+    ///
+    /// See also [`NcRgba::quantize`].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn nearest(&self, rgb: impl Into<NcRgba>) -> NcPaletteIndex {
+        let rgb = rgb.into().0;
+        let distance = |entry: u32| {
+            [16, 8, 0]
+                .iter()
+                .map(|shift| {
+                    let d = ((rgb >> shift) & 0xFF) as i32 - ((entry >> shift) & 0xFF) as i32;
+                    (d * d) as u32
+                })
+                .sum::<u32>()
+        };
+        (0..=NcPaletteIndex::MAX)
+            .min_by_key(|&index| distance(self.get(index).0))
+            .unwrap_or_default()
+    }
 }
@@ -0,0 +1,138 @@
+//! `NcAnimation`, and `NcPlane` frame-based animations.
+
+use std::time::{Duration, Instant};
+
+use crate::{Nc, NcError, NcPlane, NcResult, NcScale, NcVisual, NcVisualOptions};
+
+/// A looping animation of [`NcVisual`] frames, blitted to an [`NcPlane`].
+///
+/// Created with [`NcPlane::bind_animation`].
+///
+/// The animation doesn't run on its own: [`update`] must be called from the
+/// event loop of the application, and it blits the next frame when it's due.
+/// The planes and visuals of notcurses can't be shared with other threads,
+/// and rendering is left to the application, which usually has other planes
+/// to render in the same pile.
+///
+/// [`update`]: NcAnimation#method.update
+#[derive(Debug)]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "animation")))]
+pub struct NcAnimation<'a> {
+    plane: &'a mut NcPlane,
+    frames: Vec<&'a mut NcVisual>,
+    frame_duration: Duration,
+    /// The index of the next frame to blit.
+    next_frame: usize,
+    /// When the next frame is due, if playing.
+    next_at: Option<Instant>,
+}
+
+/// # NcPlane methods: animations
+impl NcPlane {
+    /// Binds an animation of `frames`, at `fps` frames per second, to this plane.
+    ///
+    /// The animation starts paused. Each frame is scaled to the size of the
+    /// plane, preserving its aspect ratio, and blitted with the default
+    /// blitter.
+    ///
+    /// It is an error if there are no `frames`, or if `fps` is less than one
+    /// frame per hour or not finite.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "animation")))]
+    pub fn bind_animation<'a>(
+        &'a mut self,
+        frames: Vec<&'a mut NcVisual>,
+        fps: f32,
+    ) -> NcResult<NcAnimation<'a>> {
+        if frames.is_empty() {
+            return Err(NcError::new_msg("NcPlane.bind_animation() no frames"));
+        }
+        let frame_duration = frame_duration(fps).ok_or_else(|| {
+            NcError::new_msg(&format![
+                "NcPlane.bind_animation(frames, {}) invalid fps",
+                fps
+            ])
+        })?;
+        Ok(NcAnimation { plane: self, frames, frame_duration, next_frame: 0, next_at: None })
+    }
+}
+
+impl<'a> NcAnimation<'a> {
+    /// Starts or resumes looping the frames.
+    ///
+    /// The next call to [`update`][NcAnimation#method.update] blits a frame.
+    pub fn play(&mut self) {
+        if self.next_at.is_none() {
+            self.next_at = Some(Instant::now());
+        }
+    }
+
+    /// Suspends the animation, leaving the current frame in the plane.
+    pub fn pause(&mut self) {
+        self.next_at = None;
+    }
+
+    /// Returns `true` if the animation is playing.
+    pub fn is_playing(&self) -> bool {
+        self.next_at.is_some()
+    }
+
+    /// Ends the animation, erasing the plane, and returns the plane and the
+    /// frames, so that they can be reused or destroyed.
+    pub fn stop(self) -> (&'a mut NcPlane, Vec<&'a mut NcVisual>) {
+        self.plane.erase();
+        (self.plane, self.frames)
+    }
+
+    /// Returns the number of frames per second.
+    pub fn fps(&self) -> f32 {
+        1.0 / self.frame_duration.as_secs_f32()
+    }
+
+    /// Sets the number of frames per second, from the next frame on.
+    ///
+    /// It is an error if `fps` is less than one frame per hour or not finite.
+    pub fn set_fps(&mut self, fps: f32) -> NcResult<()> {
+        self.frame_duration = frame_duration(fps).ok_or_else(|| {
+            NcError::new_msg(&format!["NcAnimation.set_fps({}) invalid fps", fps])
+        })?;
+        Ok(())
+    }
+
+    /// Blits the next frame to the plane if it's playing and the frame is
+    /// due, and returns `true` in that case.
+    ///
+    /// The plane is not rendered, so a render is needed after a `true`.
+    /// If the animation falls behind, it goes on from the next frame, without
+    /// trying to catch up.
+    pub fn update(&mut self, nc: &mut Nc) -> NcResult<bool> {
+        let now = Instant::now();
+        let next_at = match self.next_at {
+            Some(next_at) if next_at <= now => next_at,
+            _ => return Ok(false),
+        };
+
+        self.plane.erase();
+        let vopts = NcVisualOptions::builder()
+            .plane(&mut *self.plane)
+            .scale(NcScale::Scale)
+            .build();
+        unsafe { self.frames[self.next_frame].blit(nc, Some(&vopts))? };
+
+        self.next_frame = (self.next_frame + 1) % self.frames.len();
+        let next_at = next_at + self.frame_duration;
+        self.next_at = Some(if next_at < now { now + self.frame_duration } else { next_at });
+        Ok(true)
+    }
+}
+
+/// Returns the duration of a frame at `fps` frames per second, or `None` if
+/// `fps` is less than one frame per hour or not finite.
+fn frame_duration(fps: f32) -> Option<Duration> {
+    if fps.is_finite() && fps >= 1.0 / 3600.0 {
+        Some(Duration::from_secs_f32(1.0 / fps))
+    } else {
+        None
+    }
+}
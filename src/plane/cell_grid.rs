@@ -0,0 +1,128 @@
+//! `NcPlane` conversions from and to grids of `NcCell`s.
+
+use crate::{Nc, NcCell, NcError, NcPlane, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// Returns `true` if the `EGC` of the `cell` is stored in the pool of a plane,
+/// instead of inline, because it's longer than 4 bytes.
+fn cell_egc_in_pool(cell: &NcCell) -> bool {
+    u32::from_le(cell.gcluster) & 0xFF00_0000 == 0x0100_0000
+}
+
+/// Writes a `cell` whose `EGC` is in the pool of the `source` plane at
+/// `y`×`x` of the `plane`, storing its `EGC` in the pool of the `plane`.
+fn put_pooled_cell(
+    plane: &mut NcPlane,
+    y: u32,
+    x: u32,
+    cell: &NcCell,
+    source: &NcPlane,
+) -> NcResult<()> {
+    let mut copy = NcCell::new();
+    NcCell::prime(
+        plane,
+        &mut copy,
+        cell.egc(source),
+        cell.styles(),
+        cell.channels(),
+    )?;
+    let res = plane.putc_yx(y, x, &copy);
+    copy.release(plane);
+    res.map(|_| ())
+}
+
+/// # NcPlane methods: cell grids
+impl NcPlane {
+    /// Creates a new pile with a plane at `y`×`x` that has the size and the
+    /// content of a grid of `cells`, indexed by row and column.
+    ///
+    /// The right halves of wide glyphs are written along with their left
+    /// halves, and wide glyphs that don't fit in the last column are skipped.
+    ///
+    /// The `EGC`s longer than 4 bytes are stored in the pool of the plane the
+    /// cells come from, like the ones returned by
+    /// [`to_cell_grid`][NcPlane#method.to_cell_grid], so that `source` plane
+    /// is needed to read them. The cells created from a single `char` don't
+    /// need it.
+    ///
+    /// It is an error if the grid is empty, if the rows are not all of the
+    /// same length, or if any `EGC` is stored in a pool without a `source`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_cell_grid<'a>(
+        nc: &mut Nc,
+        cells: &[&[NcCell]],
+        source: Option<&NcPlane>,
+        y: i32,
+        x: i32,
+    ) -> NcResult<&'a mut NcPlane> {
+        let cols = cells.first().map_or(0, |row| row.len());
+        if cols == 0 {
+            return Err(NcError::new_msg("NcPlane::from_cell_grid() empty grid"));
+        }
+        for (row, row_cells) in cells.iter().enumerate() {
+            if row_cells.len() != cols {
+                return Err(NcError::new_msg(&format![
+                    "NcPlane::from_cell_grid() row {} has {} cells instead of {}",
+                    row,
+                    row_cells.len(),
+                    cols
+                ]));
+            }
+            if source.is_none() {
+                if let Some(col) = row_cells.iter().position(cell_egc_in_pool) {
+                    return Err(NcError::new_msg(&format![
+                        "NcPlane::from_cell_grid() the EGC at {}×{} is in the pool of a plane",
+                        row, col
+                    ]));
+                }
+            }
+        }
+
+        let (rows, cols) = (cells.len() as u32, cols as u32);
+        let plane = NcPlane::new_pile_sized(nc, y, x, rows, cols)?;
+        for (row, row_cells) in (0..rows).zip(cells) {
+            for (col, cell) in (0..cols).zip(row_cells.iter()) {
+                if cell.is_double_wide_right() || col + cell.width() > cols {
+                    continue;
+                }
+                let res = match source {
+                    Some(source) if cell_egc_in_pool(cell) => {
+                        put_pooled_cell(plane, row, col, cell, source)
+                    }
+                    _ => plane.putc_yx(row, col, cell).map(|_| ()),
+                };
+                if let Err(e) = res {
+                    let _ = plane.destroy();
+                    return Err(e);
+                }
+            }
+        }
+        Ok(plane)
+    }
+
+    /// Returns a copy of the cells of the plane, indexed by row and column.
+    ///
+    /// The `EGC`s longer than 4 bytes are stored in the pool of this plane, so
+    /// those cells can only be used with it, must be passed along with it to
+    /// [`from_cell_grid`][NcPlane#method.from_cell_grid], and must be
+    /// [released][NcCell#method.release] with it.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_cell_grid(&mut self) -> NcResult<Vec<Vec<NcCell>>> {
+        let (dim_y, dim_x) = self.dim_yx();
+        let mut grid = Vec::with_capacity(dim_y as usize);
+        for y in 0..dim_y {
+            let mut row = Vec::with_capacity(dim_x as usize);
+            for x in 0..dim_x {
+                let mut cell = NcCell::new();
+                self.at_yx_cell(y, x, &mut cell)?;
+                row.push(cell);
+            }
+            grid.push(row);
+        }
+        Ok(grid)
+    }
+}
@@ -0,0 +1,129 @@
+//! `NcPlaneSnapshot`, `NcCellDiff`, and `NcPlane` incremental updates.
+
+use crate::{NcCell, NcChannels, NcPlane, NcResult, NcStyle};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// A snapshot of the content of an [`NcPlane`], that owns its glyphs.
+///
+/// Created with [`NcPlane::snapshot`], and compared with the current content
+/// of a plane with [`NcPlane::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcPlaneSnapshot {
+    /// The dimensions of the plane.
+    dim_yx: (u32, u32),
+    /// The cells in row-major order, with `None` for the right halves of
+    /// wide glyphs.
+    cells: Vec<Option<(String, NcStyle, NcChannels)>>,
+}
+
+impl NcPlaneSnapshot {
+    /// Returns the dimensions `(rows, cols)` of the plane when the snapshot
+    /// was taken.
+    pub fn dim_yx(&self) -> (u32, u32) {
+        self.dim_yx
+    }
+}
+
+/// A cell of an [`NcPlane`] that changed, that owns its glyph.
+///
+/// See [`NcPlane::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcCellDiff {
+    /// The row of the cell.
+    pub row: u32,
+
+    /// The column of the cell.
+    pub col: u32,
+
+    /// The `EGC`, empty for an erased cell.
+    pub egc: String,
+
+    /// The styles.
+    pub styles: NcStyle,
+
+    /// The channels.
+    pub channels: NcChannels,
+}
+
+/// # NcPlane methods: snapshots and diffs
+impl NcPlane {
+    /// Returns a snapshot of the content of the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn snapshot(&mut self) -> NcResult<NcPlaneSnapshot> {
+        let (dim_y, dim_x) = self.dim_yx();
+        let mut cells = Vec::with_capacity((dim_y * dim_x) as usize);
+        let mut cell = NcCell::new();
+        for y in 0..dim_y {
+            for x in 0..dim_x {
+                self.at_yx_cell(y, x, &mut cell)?;
+                if cell.is_double_wide_right() {
+                    cells.push(None);
+                } else {
+                    cells.push(Some((
+                        String::from(cell.egc(self)),
+                        cell.styles(),
+                        cell.channels(),
+                    )));
+                }
+                cell.release(self);
+            }
+        }
+        Ok(NcPlaneSnapshot { dim_yx: (dim_y, dim_x), cells })
+    }
+
+    /// Returns the cells that changed since the `previous` snapshot, in
+    /// row-major order.
+    ///
+    /// The right halves of wide glyphs are never returned, since they're
+    /// written along with their left halves. If the plane was resized since
+    /// the snapshot, every cell is returned.
+    ///
+    /// Applying the result with [`apply_diff`] to a plane that has the
+    /// content of the `previous` snapshot makes it equal to this one.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`apply_diff`]: NcPlane#method.apply_diff
+    pub fn diff(&mut self, previous: &NcPlaneSnapshot) -> NcResult<Vec<NcCellDiff>> {
+        let current = self.snapshot()?;
+        let same_dims = current.dim_yx == previous.dim_yx;
+        let dim_x = current.dim_yx.1;
+
+        let mut diff = Vec::new();
+        for (i, cell) in current.cells.into_iter().enumerate() {
+            let (egc, styles, channels) = match cell {
+                Some(cell) => cell,
+                None => continue,
+            };
+            if same_dims {
+                if let Some(Some((p_egc, p_styles, p_channels))) = previous.cells.get(i) {
+                    if *p_egc == egc && *p_styles == styles && *p_channels == channels {
+                        continue;
+                    }
+                }
+            }
+            let (row, col) = (i as u32 / dim_x, i as u32 % dim_x);
+            diff.push(NcCellDiff { row, col, egc, styles, channels });
+        }
+        Ok(diff)
+    }
+
+    /// Writes the cells of a `diff` returned by [`diff`][NcPlane#method.diff].
+    ///
+    /// It is an error if any cell is outside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn apply_diff(&mut self, diff: &[NcCellDiff]) -> NcResult<()> {
+        let mut cell = NcCell::new();
+        for d in diff {
+            NcCell::prime(self, &mut cell, &d.egc, d.styles, d.channels)?;
+            let res = self.putc_yx(d.row, d.col, &cell);
+            cell.release(self);
+            res?;
+        }
+        Ok(())
+    }
+}
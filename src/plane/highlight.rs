@@ -0,0 +1,102 @@
+//! `NcPlane` highlighting of regular expressions with the [`regex`] crate.
+
+use regex::Regex;
+
+use super::userdata::NcPlaneUserData;
+use crate::{
+    c_api::{self, NcChannels_u64, NcStyle_u16},
+    egc::str_width,
+    NcChannels, NcError, NcPlane, NcResult, NcRgba, NcStyle,
+};
+
+use std::collections::BTreeMap;
+
+/// The original styles and channels of the highlighted cells, by (y, x).
+pub(crate) type NcPlaneHighlights = BTreeMap<(u32, u32), (NcStyle_u16, NcChannels_u64)>;
+
+/// # NcPlane methods: highlighting
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "regex")))]
+impl NcPlane {
+    /// Highlights all the matches of the regular expression `pattern` in the
+    /// text content of the plane, using the `fg` and `bg` colors.
+    ///
+    /// Each row is searched separately, as returned by
+    /// [`row_as_string`][NcPlane#method.row_as_string], and every match
+    /// is formatted with [`format_region`][NcPlane#method.format_region].
+    ///
+    /// The original styles and channels of the highlighted cells are kept,
+    /// to be restored by [`clear_highlights`][NcPlane#method.clear_highlights].
+    ///
+    /// Returns the number of highlighted matches.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn highlight_pattern(&mut self, pattern: &str, fg: NcRgba, bg: NcRgba) -> NcResult<usize> {
+        let regex = Regex::new(pattern).map_err(|e| {
+            NcError::new_msg(&format!["NcPlane.highlight_pattern({:?}) {}", pattern, e])
+        })?;
+
+        let mut count = 0;
+        for row in 0..self.dim_y() {
+            let text = self.row_as_string(row)?;
+            for m in regex.find_iter(&text).filter(|m| !m.as_str().is_empty()) {
+                let col = str_width(&text[..m.start()]);
+                let len = str_width(m.as_str());
+                self.save_highlighted(row, col, len);
+                self.format_region(row, col, 1, len, Some(fg), Some(bg), NcStyle::None)?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Restores the original styles and channels of the cells highlighted
+    /// since the last call, removing any highlighting.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn clear_highlights(&mut self) -> NcResult<()> {
+        let highlights = match unsafe { NcPlaneUserData::get(self) } {
+            Some(userdata) => userdata.highlights.take(),
+            None => None,
+        };
+        unsafe { NcPlaneUserData::drop_if_empty(self) };
+
+        for ((y, x), (styles, channels)) in highlights.unwrap_or_default() {
+            let channels = NcChannels::from(channels);
+            let (y, x) = (Some(y), Some(x));
+            self.stain(
+                y,
+                x,
+                Some(1),
+                Some(1),
+                channels,
+                channels,
+                channels,
+                channels,
+            )?;
+            self.format(y, x, Some(1), Some(1), NcStyle::from(styles))?;
+        }
+        Ok(())
+    }
+
+    // Saves the styles and channels of the `len` cells starting at `y`, `x`,
+    // unless they are already highlighted.
+    fn save_highlighted(&mut self, y: u32, x: u32, len: u32) {
+        let mut saved = Vec::with_capacity(len as usize);
+        for x in x..x + len {
+            let (mut styles, mut channels) = (0, 0);
+            let egc = unsafe {
+                c_api::ncplane_at_yx(self, y as i32, x as i32, &mut styles, &mut channels)
+            };
+            if !egc.is_null() {
+                unsafe { c_api::ffi::free(egc as *mut core::ffi::c_void) };
+                saved.push(((y, x), (styles, channels)));
+            }
+        }
+        let highlights = unsafe { NcPlaneUserData::get_or_insert(self) }
+            .highlights
+            .get_or_insert_with(Default::default);
+        for (yx, original) in saved {
+            highlights.entry(yx).or_insert(original);
+        }
+    }
+}
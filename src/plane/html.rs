@@ -0,0 +1,125 @@
+//! `NcPlane` export to HTML.
+
+use crate::{NcCell, NcPlane, NcRgba, NcStyle};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+/// # NcPlane methods: HTML
+impl NcPlane {
+    /// Returns the content of the plane as an HTML `<pre>` element, with the
+    /// rows separated by `\n`.
+    ///
+    /// Each run of consecutive cells with the same colors and styles becomes
+    /// a `<span>` with an inline `style`, or plain text if they have none.
+    /// The RGB colors are exported as `color` and `background-color`, while
+    /// the default, palette-indexed and transparent colors are omitted.
+    /// Bold, italic, underline, undercurl and struck map to the equivalent
+    /// `font-weight`, `font-style` and `text-decoration` properties.
+    ///
+    /// Empty cells are represented by spaces, and the trailing empty cells
+    /// of each row without colors or styles are omitted.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_html(&mut self) -> String {
+        let (dim_y, dim_x) = self.dim_yx();
+
+        let mut html = String::from("<pre>");
+        let mut cell = NcCell::new();
+        for y in 0..dim_y {
+            if y > 0 {
+                html.push('\n');
+            }
+            // the runs of text with the same style attribute
+            let mut runs: Vec<(String, String)> = Vec::new();
+            for x in 0..dim_x {
+                if self.at_yx_cell(y, x, &mut cell).is_err() {
+                    continue;
+                }
+                if !cell.wide_right_p() {
+                    let style = css_style(&cell);
+                    let egc = match cell.egc(self) {
+                        "" => " ",
+                        egc => egc,
+                    };
+                    if runs
+                        .last()
+                        .map_or(false, |(run_style, _)| *run_style == style)
+                    {
+                        runs.last_mut().unwrap().1.push_str(egc);
+                    } else {
+                        runs.push((style, String::from(egc)));
+                    }
+                }
+                cell.release(self);
+            }
+            if let Some((style, text)) = runs.last_mut() {
+                if style.is_empty() {
+                    text.truncate(text.trim_end_matches(' ').len());
+                }
+            }
+
+            for (style, text) in runs {
+                if style.is_empty() {
+                    html_escape(&mut html, &text);
+                } else {
+                    html.push_str(&format!["<span style=\"{}\">", style]);
+                    html_escape(&mut html, &text);
+                    html.push_str("</span>");
+                }
+            }
+        }
+        html.push_str("</pre>");
+        html
+    }
+}
+
+/// Returns the CSS declarations for the colors and styles of the `cell`.
+fn css_style(cell: &NcCell) -> String {
+    let mut decls = Vec::new();
+    let css_color = |rgba: Option<NcRgba>| match rgba {
+        Some(rgba) if rgba.0 >> 24 != 0 => Some(format!["#{:06x}", rgba.0 & 0xFF_FF_FF]),
+        _ => None,
+    };
+    if let Some(color) = css_color(cell.fg_rgba()) {
+        decls.push(format!["color: {}", color]);
+    }
+    if let Some(color) = css_color(cell.bg_rgba()) {
+        decls.push(format!["background-color: {}", color]);
+    }
+
+    let styles = cell.styles();
+    if styles.has(NcStyle::Bold) {
+        decls.push(String::from("font-weight: bold"));
+    }
+    if styles.has(NcStyle::Italic) {
+        decls.push(String::from("font-style: italic"));
+    }
+    let mut decorations = Vec::new();
+    if styles.has(NcStyle::Underline) || styles.has(NcStyle::Undercurl) {
+        decorations.push("underline");
+    }
+    if styles.has(NcStyle::Struck) {
+        decorations.push("line-through");
+    }
+    if styles.has(NcStyle::Undercurl) {
+        decorations.push("wavy");
+    }
+    if !decorations.is_empty() {
+        decls.push(format!["text-decoration: {}", decorations.join(" ")]);
+    }
+    decls.join("; ")
+}
+
+/// Appends the `text` to `html`, escaping the HTML special characters.
+fn html_escape(html: &mut String, text: &str) {
+    for ch in text.chars() {
+        match ch {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            _ => html.push(ch),
+        }
+    }
+}
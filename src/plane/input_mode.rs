@@ -0,0 +1,105 @@
+//! `NcTextInputMode`, `NcCursorShape`, and `NcPlane` input mode tracking.
+
+use super::userdata::NcPlaneUserData;
+use crate::{notcurses::helpers::write_escape, NcError, NcPlane, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+
+/// The shape of the terminal cursor.
+///
+/// It's set with the DECSCUSR escape sequence, which some terminals ignore.
+///
+/// # Default
+/// *[`NcCursorShape::Block`]*
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcCursorShape {
+    /// A steady block.
+    #[default]
+    Block,
+    /// A blinking block.
+    BlinkBlock,
+    /// A steady underline.
+    Underline,
+    /// A blinking underline.
+    BlinkUnderline,
+    /// A steady vertical bar.
+    Bar,
+    /// A blinking vertical bar.
+    BlinkBar,
+}
+
+impl NcCursorShape {
+    /// Returns the DECSCUSR escape sequence that sets this shape.
+    pub const fn escape(&self) -> &'static str {
+        use NcCursorShape::*;
+        match self {
+            BlinkBlock => "\x1b[1 q",
+            Block => "\x1b[2 q",
+            BlinkUnderline => "\x1b[3 q",
+            Underline => "\x1b[4 q",
+            BlinkBar => "\x1b[5 q",
+            Bar => "\x1b[6 q",
+        }
+    }
+}
+
+/// The mode of a modal text input, like in vim.
+///
+/// # Default
+/// *[`NcTextInputMode::Normal`]*
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NcTextInputMode {
+    /// The keys are commands.
+    #[default]
+    Normal,
+    /// The keys insert text.
+    Insert,
+    /// The keys extend a selection.
+    Visual,
+}
+
+impl NcTextInputMode {
+    /// Returns the cursor shape of this mode: a block for `Normal` and
+    /// `Visual`, and a bar for `Insert`.
+    pub const fn cursor_shape(&self) -> NcCursorShape {
+        match self {
+            NcTextInputMode::Insert => NcCursorShape::Bar,
+            _ => NcCursorShape::Block,
+        }
+    }
+}
+
+/// # NcPlane methods: text input mode
+impl NcPlane {
+    /// Returns the text input mode of this plane.
+    ///
+    /// It's [`NcTextInputMode::Normal`] until it's changed with
+    /// [`set_input_mode`][NcPlane#method.set_input_mode].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn input_mode(&self) -> NcTextInputMode {
+        NcPlaneUserData::get_ref(self)
+            .and_then(|userdata| userdata.input_mode)
+            .unwrap_or_default()
+    }
+
+    /// Sets the text input mode of this plane, and changes the shape of the
+    /// terminal cursor to the [one of the mode][NcTextInputMode#method.cursor_shape].
+    ///
+    /// The escape sequence is written to the output stream of notcurses,
+    /// like with [`Nc.set_title`][crate::Nc#method.set_title].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_input_mode(&mut self, mode: NcTextInputMode) -> NcResult<()> {
+        self.store_input_mode(mode);
+        write_escape(mode.cursor_shape().escape()).map_err(|e| {
+            NcError::new_msg(&format!["NcPlane.set_input_mode({:?}): {}", mode, e.msg])
+        })
+    }
+
+    /// Sets the text input mode of this plane, without changing the cursor.
+    pub(crate) fn store_input_mode(&mut self, mode: NcTextInputMode) {
+        unsafe { NcPlaneUserData::get_or_insert(self) }.input_mode = Some(mode);
+    }
+}
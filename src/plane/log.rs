@@ -0,0 +1,78 @@
+//! `NcLogPlane`
+
+use crate::{NcChannels, NcPlane, NcResult, NcStyle};
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, string::String};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// A scrolling log pane, that writes each line below the previous one,
+/// and keeps the most recent lines in memory.
+#[derive(Debug)]
+pub struct NcLogPlane<'a> {
+    plane: &'a mut NcPlane,
+    history: VecDeque<String>,
+    capacity: usize,
+    /// Whether the next line must start in a new row.
+    started: bool,
+}
+
+impl<'a> NcLogPlane<'a> {
+    /// New `NcLogPlane` writing to the `plane`, which is made scrolling,
+    /// and keeping up to `history` lines in memory.
+    ///
+    /// The first line is written at the cursor position if it's at the start
+    /// of a row, or in the next row otherwise.
+    pub fn new(plane: &'a mut NcPlane, history: usize) -> Self {
+        plane.set_scrolling(true);
+        let started = plane.cursor_x() > 0;
+        Self { plane, history: VecDeque::with_capacity(history), capacity: history, started }
+    }
+
+    /// Returns the plane being written to.
+    pub fn plane(&mut self) -> &mut NcPlane {
+        self.plane
+    }
+
+    /// Writes the `text` in a new line with the `styles` and `channels`,
+    /// scrolling the plane if needed.
+    ///
+    /// Lines wider than the plane continue in the next row. The current
+    /// colors and styles of the plane are restored afterwards.
+    pub fn log_line(&mut self, styles: NcStyle, channels: NcChannels, text: &str) -> NcResult<()> {
+        let (old_styles, old_channels) = (self.plane.styles(), self.plane.channels());
+        self.plane.set_styles(styles);
+        self.plane.set_channels(channels);
+
+        let mut res = Ok(0);
+        if self.started {
+            res = self.plane.putstr("\n");
+        }
+        if res.is_ok() {
+            res = self.plane.putstr(text);
+        }
+        self.plane.set_styles(old_styles);
+        self.plane.set_channels(old_channels);
+        res?;
+        self.started = true;
+
+        if self.capacity > 0 {
+            if self.history.len() == self.capacity {
+                self.history.pop_front();
+            }
+            self.history.push_back(text.into());
+        }
+        Ok(())
+    }
+
+    /// Returns the most recent lines, from the oldest to the newest.
+    pub fn history(&self) -> &VecDeque<String> {
+        &self.history
+    }
+
+    /// Forgets the lines kept in memory.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+}
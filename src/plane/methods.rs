@@ -1,22 +1,32 @@
 //! `NcPlane*` methods and associated functions.
 
 use core::{
+    any::Any,
+    cell::RefCell,
     ffi::{c_char, c_void},
     ptr::null_mut,
     slice::from_raw_parts_mut,
 };
 
 use crate::{
-    c_api, cstring, error, error_ref, error_ref_mut, rstring_free, Nc, NcAlign, NcAlpha, NcBlitter,
-    NcBoxMask, NcCell, NcChannel, NcChannels, NcError, NcFadeCb, NcPaletteIndex, NcPixelGeometry,
-    NcPlane, NcPlaneOptions, NcResizeCb, NcResult, NcRgb, NcRgba, NcStyle, NcTime,
+    c_api, cstring,
+    egc::{egc_len, egc_prefix, str_width},
+    error, error_ref, error_ref_mut,
+    plane::userdata::{ncresizefn_trampoline, NcPlaneUserData, NcResizeFnShared},
+    rstring_free,
+    visual::sixel::rgba_to_sixel,
+    Nc, NcAlign, NcAlpha, NcBlitter, NcBoxMask, NcCell, NcChannel, NcChannels, NcError, NcFadeCb,
+    NcPaletteIndex, NcPixelGeometry, NcPlane, NcPlaneOptions, NcResizeCb, NcResult, NcRgb, NcRgba,
+    NcStyle, NcTime, NcVisual,
 };
 
 #[cfg(feature = "std")]
 use crate::NcFile;
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{boxed::Box, format, rc::Rc, string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::rc::Rc;
 
 /// # NcPlane constructors & destructors
 impl NcPlane {
@@ -37,8 +47,12 @@ impl NcPlane {
         parent: &'parent mut NcPlane,
         options: &'opts NcPlaneOptions,
     ) -> NcResult<&'plane mut NcPlane> {
+        let plane = unsafe { c_api::ncplane_create(parent, options) };
+        if !plane.is_null() {
+            unsafe { NcPlaneUserData::adopt_options_userptr(plane) };
+        }
         error_ref_mut![
-            unsafe { c_api::ncplane_create(parent, options) },
+            plane,
             &format!["NcPlane::new_plane(NcPlane, {:?})", options] // TODO: show name
         ]
     }
@@ -54,10 +68,11 @@ impl NcPlane {
         nc: &'nc mut Nc,
         options: &'opts NcPlaneOptions,
     ) -> NcResult<&'plane mut NcPlane> {
-        error_ref_mut![
-            unsafe { c_api::ncpile_create(nc, options) },
-            &format!["NcPlane::with_options(Nc, {:?})", options]
-        ]
+        let plane = unsafe { c_api::ncpile_create(nc, options) };
+        if !plane.is_null() {
+            unsafe { NcPlaneUserData::adopt_options_userptr(plane) };
+        }
+        error_ref_mut![plane, &format!["NcPlane::with_options(Nc, {:?})", options]]
     }
 
     /// Creates a new `NcPlane` child of `parent` plane.
@@ -102,9 +117,20 @@ impl NcPlane {
     ///
     /// It is an error to attempt to destroy the standard plane.
     ///
+    /// Any Rust data stored in the plane's user pointer by the safe API, like
+    /// a [`resize_fn`] closure, is dropped. A user pointer set with the C API
+    /// is left to its owner.
+    ///
     /// *C style function: [ncplane_destroy()][c_api::ncplane_destroy].*
+    ///
+    /// [`resize_fn`]: crate::NcPlaneOptionsBuilder#method.resize_fn
     pub fn destroy(&mut self) -> NcResult<()> {
-        error![unsafe { c_api::ncplane_destroy(self) }, "NcPlane.destroy()"]
+        let userptr = unsafe { c_api::ncplane_userptr(self) };
+        let res = unsafe { c_api::ncplane_destroy(self) };
+        if res >= c_api::NCRESULT_OK {
+            unsafe { NcPlaneUserData::drop_userptr(userptr) };
+        }
+        error![res, "NcPlane.destroy()"]
     }
 }
 
@@ -268,6 +294,26 @@ impl NcPlane {
         c_api::ncchannels_bg_rgb(c_api::ncplane_channels(self)).into()
     }
 
+    /// Gets the foreground RGB components from this `NcPlane`.
+    ///
+    /// *C style function: [ncplane_fg_rgb8()][c_api::ncplane_fg_rgb8].*
+    #[inline]
+    pub fn fg_rgb8(&self) -> (u8, u8, u8) {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+        c_api::ncplane_fg_rgb8(self, &mut r, &mut g, &mut b);
+        (r, g, b)
+    }
+
+    /// Gets the background RGB components from this `NcPlane`.
+    ///
+    /// *C style function: [ncplane_bg_rgb8()][c_api::ncplane_bg_rgb8].*
+    #[inline]
+    pub fn bg_rgb8(&self) -> (u8, u8, u8) {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+        c_api::ncplane_bg_rgb8(self, &mut r, &mut g, &mut b);
+        (r, g, b)
+    }
+
     /// Sets the foreground [`NcRgb`] for this `NcPlane`.
     ///
     /// *C style function: [ncplane_set_fg_rgb()][c_api::ncplane_set_fg_rgb].*
@@ -288,6 +334,62 @@ impl NcPlane {
         }
     }
 
+    /// Sets the foreground RGB components for this `NcPlane`.
+    ///
+    /// *C style function: [ncplane_set_fg_rgb8()][c_api::ncplane_set_fg_rgb8].*
+    #[inline]
+    pub fn set_fg_rgb8(&mut self, r: u8, g: u8, b: u8) {
+        unsafe {
+            c_api::ncplane_set_fg_rgb8(self, r as u32, g as u32, b as u32);
+        }
+    }
+
+    /// Sets the background RGB components for this `NcPlane`.
+    ///
+    /// *C style function: [ncplane_set_bg_rgb8()][c_api::ncplane_set_bg_rgb8].*
+    #[inline]
+    pub fn set_bg_rgb8(&mut self, r: u8, g: u8, b: u8) {
+        unsafe {
+            c_api::ncplane_set_bg_rgb8(self, r as u32, g as u32, b as u32);
+        }
+    }
+
+    /// Sets the foreground RGB components for this `NcPlane`, saturating
+    /// each of them to the `0..=255` range.
+    ///
+    /// *C style function: [ncplane_set_fg_rgb8_clipped()][c_api::ncplane_set_fg_rgb8_clipped].*
+    #[inline]
+    pub fn set_fg_rgb8_clipped(&mut self, r: i32, g: i32, b: i32) {
+        unsafe {
+            c_api::ncplane_set_fg_rgb8_clipped(self, r, g, b);
+        }
+    }
+
+    /// Sets the background RGB components for this `NcPlane`, saturating
+    /// each of them to the `0..=255` range.
+    ///
+    /// *C style function: [ncplane_set_bg_rgb8_clipped()][c_api::ncplane_set_bg_rgb8_clipped].*
+    #[inline]
+    pub fn set_bg_rgb8_clipped(&mut self, r: i32, g: i32, b: i32) {
+        unsafe {
+            c_api::ncplane_set_bg_rgb8_clipped(self, r, g, b);
+        }
+    }
+
+    /// Sets the background color for this `NcPlane`, and the foreground to
+    /// black or white, whichever is more readable over it.
+    ///
+    /// The alpha component of `bg` is ignored.
+    ///
+    /// See also [`NcRgba::contrasting`][NcRgba#method.contrasting].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_bg_rgb_with_contrast(&mut self, bg: impl Into<NcRgba>) {
+        let bg = bg.into();
+        self.set_bg_rgb(bg.0 & NcChannel::RGB_MASK);
+        self.set_fg_rgb(bg.contrasting().0 & NcChannel::RGB_MASK);
+    }
+
     /// Is this `NcPlane`'s foreground using the "default foreground color"?
     ///
     /// *C style function: [ncplane_fg_default_p()][c_api::ncplane_fg_default_p].*
@@ -419,6 +521,176 @@ impl NcPlane {
         ]
     }
 
+    /// Sets the given style, and optionally the foreground and background
+    /// colors, throughout the specified region, keeping the content unchanged.
+    ///
+    /// The upper left corner is at `y`, `x`, and the area is `len_y` × `len_x`.
+    /// A `None` color keeps the existing one on each cell. The alpha
+    /// component of the colors is ignored.
+    ///
+    /// A wide glyph partially inside the region is formatted in its entirety.
+    ///
+    /// It is an error for any coordinate to be outside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn format_region(
+        &mut self,
+        y: u32,
+        x: u32,
+        len_y: u32,
+        len_x: u32,
+        fg: Option<NcRgba>,
+        bg: Option<NcRgba>,
+        style: impl Into<NcStyle>,
+    ) -> NcResult<()> {
+        let style = style.into();
+        let (dim_y, dim_x) = self.dim_yx();
+        if len_y == 0
+            || len_x == 0
+            || y.checked_add(len_y).map_or(true, |end| end > dim_y)
+            || x.checked_add(len_x).map_or(true, |end| end > dim_x)
+        {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.format_region({}, {}, {}, {}, {:?}, {:?}, {:0X}) out of bounds",
+                y, x, len_y, len_x, fg, bg, style
+            ]));
+        }
+
+        let mut cell = NcCell::new();
+        for row in y..y + len_y {
+            let (mut beg_x, mut end_x) = (x, x + len_x - 1);
+
+            // extend the row to cover both halves of wide glyphs on the edges
+            self.at_yx_cell(row, beg_x, &mut cell)?;
+            if cell.wide_right_p() && beg_x > 0 {
+                beg_x -= 1;
+            }
+            cell.release(self);
+            self.at_yx_cell(row, end_x, &mut cell)?;
+            if cell.double_wide_p() && !cell.wide_right_p() && end_x + 1 < dim_x {
+                end_x += 1;
+            }
+            cell.release(self);
+
+            for col in beg_x..=end_x {
+                self.at_yx_cell(row, col, &mut cell)?;
+                let mut channels = cell.channels();
+                cell.release(self);
+
+                if let Some(fg) = fg {
+                    channels.set_fg_rgb(fg.0 & NcChannel::RGB_MASK);
+                }
+                if let Some(bg) = bg {
+                    channels.set_bg_rgb(bg.0 & NcChannel::RGB_MASK);
+                }
+                let (row, col) = (Some(row), Some(col));
+                self.stain(
+                    row,
+                    col,
+                    Some(1),
+                    Some(1),
+                    channels,
+                    channels,
+                    channels,
+                    channels,
+                )?;
+                self.format(row, col, Some(1), Some(1), style)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the foreground and background colors of the region of
+    /// `len_y`×`len_x` cells starting at `y`×`x` to greys of the same
+    /// [`luminance`][NcRgba#method.luminance], keeping the content.
+    ///
+    /// The alpha components are kept, and the default and palette-indexed
+    /// colors are left unchanged.
+    ///
+    /// Returns the number of cells processed.
+    ///
+    /// It is an error for any coordinate to be outside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn greyscale_region(&mut self, y: u32, x: u32, len_y: u32, len_x: u32) -> NcResult<u32> {
+        let (dim_y, dim_x) = self.dim_yx();
+        if len_y == 0
+            || len_x == 0
+            || y.checked_add(len_y).map_or(true, |end| end > dim_y)
+            || x.checked_add(len_x).map_or(true, |end| end > dim_x)
+        {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.greyscale_region({}, {}, {}, {}) out of bounds",
+                y, x, len_y, len_x
+            ]));
+        }
+        let grey = |rgb: NcRgb| {
+            let level = (NcRgba(rgb.0).luminance() * 255.0 + 0.5) as u8;
+            NcRgb::new(level, level, level)
+        };
+
+        let mut cell = NcCell::new();
+        for row in y..y + len_y {
+            for col in x..x + len_x {
+                self.at_yx_cell(row, col, &mut cell)?;
+                let mut channels = cell.channels();
+                cell.release(self);
+
+                if channels.fg_rgb_p() {
+                    channels.set_fg_rgb(grey(channels.fg_rgb()));
+                }
+                if channels.bg_rgb_p() {
+                    channels.set_bg_rgb(grey(channels.bg_rgb()));
+                }
+                let (row, col) = (Some(row), Some(col));
+                self.stain(
+                    row,
+                    col,
+                    Some(1),
+                    Some(1),
+                    channels,
+                    channels,
+                    channels,
+                    channels,
+                )?;
+            }
+        }
+        Ok(len_y * len_x)
+    }
+
+    /// Copies the content of this plane onto `dst`, starting at
+    /// `dst_y`×`dst_x`, with the channels and styles of every cell.
+    ///
+    /// The cells that would fall outside `dst` are clipped, including wide
+    /// glyphs that don't fit entirely.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn copy_to(&self, dst: &mut NcPlane, dst_y: u32, dst_x: u32) -> NcResult<()> {
+        let (dim_y, dim_x) = self.dim_yx();
+        let (dst_dim_y, dst_dim_x) = dst.dim_yx();
+        let len_y = dim_y.min(dst_dim_y.saturating_sub(dst_y));
+        let len_x = dim_x.min(dst_dim_x.saturating_sub(dst_x));
+
+        let mut cell = NcCell::new();
+        for row in 0..len_y {
+            for col in 0..len_x {
+                self.copy_cell(row, col, &mut cell)?;
+                // the right halves are written along with the left ones
+                if cell.is_double_wide_right() || col + cell.width() > len_x {
+                    self.release_cell(&mut cell);
+                    continue;
+                }
+                let dup = cell.duplicate_into(self, dst);
+                self.release_cell(&mut cell);
+                let mut dup = dup?;
+                let res = dst.putc_yx(dst_y + row, dst_x + col, &dup);
+                dup.release(dst);
+                res?;
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the current styles for this `NcPlane`.
     ///
     /// *C style function: [ncplane_styles()][c_api::ncplane_styles].*
@@ -453,6 +725,42 @@ impl NcPlane {
         }
     }
 
+    /// Returns the default styles applied to the text written to this `NcPlane`.
+    ///
+    /// This is the same as [`styles`][NcPlane#method.styles].
+    ///
+    /// *C style function: [ncplane_styles()][c_api::ncplane_styles].*
+    pub fn default_style(&self) -> NcStyle {
+        self.styles()
+    }
+
+    /// Sets the default styles applied to the text written to this `NcPlane`.
+    ///
+    /// This is the same as [`set_styles`][NcPlane#method.set_styles].
+    ///
+    /// *C style function: [ncplane_set_styles()][c_api::ncplane_set_styles].*
+    pub fn set_default_style(&mut self, styles: impl Into<NcStyle>) {
+        self.set_styles(styles)
+    }
+
+    /// Adds the specified `styles` to the default styles of this `NcPlane`.
+    ///
+    /// This is the same as [`on_styles`][NcPlane#method.on_styles].
+    ///
+    /// *C style function: [ncplane_on_styles()][c_api::ncplane_on_styles].*
+    pub fn add_default_style(&mut self, styles: impl Into<NcStyle>) {
+        self.on_styles(styles)
+    }
+
+    /// Removes the specified `styles` from the default styles of this `NcPlane`.
+    ///
+    /// This is the same as [`off_styles`][NcPlane#method.off_styles].
+    ///
+    /// *C style function: [ncplane_off_styles()][c_api::ncplane_off_styles].*
+    pub fn remove_default_style(&mut self, styles: impl Into<NcStyle>) {
+        self.off_styles(styles)
+    }
+
     /// Sets this `NcPlane`'s foreground [`NcPaletteIndex`].
     ///
     /// Also sets the foreground palette index bit, sets it foreground-opaque,
@@ -574,6 +882,80 @@ impl NcPlane {
         ]
     }
 
+    /// Returns an owned copy of the `EGC` of the cell at `row`×`col`.
+    ///
+    /// On the right half of a wide glyph it returns the `EGC` of the left
+    /// half, and on an empty cell an empty string. Unlike [`at_yx`], the
+    /// base cell is not taken into account.
+    ///
+    /// It is an error for the coordinates to be outside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`at_yx`]: NcPlane#method.at_yx
+    pub fn strdup_egc_at(&self, row: u32, col: u32) -> NcResult<String> {
+        let (dim_y, dim_x) = self.dim_yx();
+        if row >= dim_y || col >= dim_x {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.strdup_egc_at({}, {}) out of bounds",
+                row, col
+            ]));
+        }
+        let mut cell = NcCell::new();
+        self.copy_cell(row, col, &mut cell)?;
+        if cell.wide_right_p() && col > 0 {
+            self.release_cell(&mut cell);
+            self.copy_cell(row, col - 1, &mut cell)?;
+        }
+        let egc = String::from(cell.egc(self));
+        self.release_cell(&mut cell);
+        Ok(egc)
+    }
+
+    /// Returns an owned copy of the `EGC` of the cell under the cursor,
+    /// like [`strdup_egc_at`][NcPlane#method.strdup_egc_at].
+    ///
+    /// The cursor is not moved. It is an error if the cursor is past the
+    /// last column.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn char_at_cursor(&self) -> NcResult<String> {
+        let (y, x) = self.cursor_yx();
+        self.strdup_egc_at(y, x)
+    }
+
+    /// Returns a copy of the cell under the cursor, with its styles and channels.
+    ///
+    /// The cursor is not moved. An `EGC` longer than 4 bytes is stored in the
+    /// pool of this plane, so the cell can only be used with it, and must be
+    /// [released][NcCell#method.release] with it.
+    ///
+    /// It is an error if the cursor is past the last column.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn cell_at_cursor(&self) -> NcResult<NcCell> {
+        let (y, x) = self.cursor_yx();
+        let mut cell = NcCell::new();
+        self.copy_cell(y, x, &mut cell)?;
+        Ok(cell)
+    }
+
+    // Copies the cell at `y`×`x` into `cell`, through a shared reference.
+    //
+    // `ncplane_at_yx_cell` doesn't change the visible contents of the plane,
+    // it only duplicates a long `EGC` into its pool.
+    pub(crate) fn copy_cell(&self, y: u32, x: u32, cell: &mut NcCell) -> NcResult<()> {
+        let plane = self as *const NcPlane as *mut NcPlane;
+        let res = unsafe { c_api::ncplane_at_yx_cell(plane, y as i32, x as i32, cell) };
+        error![res, &format!("NcPlane.copy_cell({}, {})", y, x)]
+    }
+
+    // Releases a `cell` copied with `copy_cell`.
+    pub(crate) fn release_cell(&self, cell: &mut NcCell) {
+        let plane = self as *const NcPlane as *mut NcPlane;
+        unsafe { c_api::nccell_release(plane, cell) };
+    }
+
     /// Extracts this `NcPlane`'s base [`NcCell`].
     ///
     /// The reference is invalidated if this `NcPlane` is destroyed.
@@ -633,6 +1015,33 @@ impl NcPlane {
         ]
     }
 
+    /// Sets this `NcPlane`'s base [`NcCell`] to a copy of the base cell of
+    /// the `source` plane, including its `EGC`, styles and channels.
+    ///
+    /// Useful to give a popup the same background as its parent.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn set_base_from_plane(&mut self, source: &mut NcPlane) -> NcResult<()> {
+        let mut cell = NcCell::new();
+        let res = unsafe { c_api::ncplane_base(source, &mut cell) };
+        if res < c_api::NCRESULT_OK {
+            return Err(NcError::with_msg(res, "NcPlane.set_base_from_plane()"));
+        }
+        let egc = String::from(cell.egc(source));
+        cell.release(source);
+        self.set_base(&egc, cell.styles(), cell.channels())
+            .map(|_| ())
+    }
+
+    /// Sets the current [`NcChannels`] of this `NcPlane` to the current
+    /// channels of the `source` plane, so that the cells written from now on
+    /// have the same colors.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn inherit_channels_from(&mut self, source: &NcPlane) {
+        self.set_channels(source.channels());
+    }
+
     /// Creates a flat string from the `EGC`'s of the selected region of the
     /// `NcPlane`.
     ///
@@ -675,6 +1084,188 @@ impl NcPlane {
         }
     }
 
+    /// Returns the text content of the plane, joining the rows with `\n`.
+    ///
+    /// Wide glyphs are included once, empty cells are represented by the EGC
+    /// of the base cell, or by spaces if it has none, in which case the
+    /// trailing empty cells of each row are omitted.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn content_as_string(&self) -> String {
+        let mut content = String::new();
+        for row in 0..self.dim_y() {
+            if row > 0 {
+                content.push('\n');
+            }
+            if let Ok(text) = self.row_as_string(row) {
+                content.push_str(&text);
+            }
+        }
+        content
+    }
+
+    /// Returns the text content of the `row` of the plane.
+    ///
+    /// Wide glyphs are included once, empty cells are represented by the EGC
+    /// of the base cell, or by spaces if it has none, in which case the
+    /// trailing empty cells are omitted.
+    ///
+    /// It is an error if the row is outside the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn row_as_string(&self, row: u32) -> NcResult<String> {
+        if row >= self.dim_y() {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.row_as_string({}) out of bounds",
+                row
+            ]));
+        }
+        let mut text = String::new();
+        let mut len = 0;
+        for (_, egc) in self.row_egcs(row) {
+            match egc.as_str() {
+                "" => text.push(' '),
+                egc => {
+                    text.push_str(egc);
+                    len = text.len();
+                }
+            }
+        }
+        text.truncate(len);
+        Ok(text)
+    }
+
+    /// Searches the content of the plane for `pattern`, scanning forward in
+    /// row-major order from the `start` (y, x) coordinates, inclusive.
+    ///
+    /// Rows are concatenated without separators, so a pattern can span the
+    /// end of a row and the start of the next one. Empty cells match the EGC
+    /// of the base cell, or a space if it has none.
+    ///
+    /// If `wrap` is true and the end of the plane is reached, the search
+    /// continues from the beginning of the plane.
+    ///
+    /// Returns the (y, x) coordinates of the first cell of the match.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn search_forward(
+        &self,
+        start: (u32, u32),
+        pattern: &str,
+        wrap: bool,
+    ) -> Option<(u32, u32)> {
+        if pattern.is_empty() {
+            return None;
+        }
+        let (text, cells) = self.search_text();
+        let from = Self::search_offset(&cells, start, text.len());
+
+        let found = text[from..].find(pattern).map(|i| from + i).or_else(|| {
+            if wrap {
+                text.find(pattern)
+            } else {
+                None
+            }
+        })?;
+        Some(Self::search_cell(&cells, found))
+    }
+
+    /// Searches the content of the plane for `pattern`, scanning backward in
+    /// row-major order from the `start` (y, x) coordinates, inclusive.
+    ///
+    /// Rows are concatenated without separators, so a pattern can span the
+    /// end of a row and the start of the next one. Empty cells match the EGC
+    /// of the base cell, or a space if it has none.
+    ///
+    /// If `wrap` is true and the beginning of the plane is reached, the search
+    /// continues from the end of the plane.
+    ///
+    /// Returns the (y, x) coordinates of the first cell of the match.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn search_backward(
+        &self,
+        start: (u32, u32),
+        pattern: &str,
+        wrap: bool,
+    ) -> Option<(u32, u32)> {
+        if pattern.is_empty() {
+            return None;
+        }
+        let (text, cells) = self.search_text();
+        let from = Self::search_offset(&cells, start, text.len());
+        // the end of a match starting at `from`, rounded down to a char boundary
+        let mut until = (from + pattern.len()).min(text.len());
+        while !text.is_char_boundary(until) {
+            until -= 1;
+        }
+
+        let found =
+            text[..until].rfind(pattern).or_else(
+                || {
+                    if wrap {
+                        text.rfind(pattern)
+                    } else {
+                        None
+                    }
+                },
+            )?;
+        Some(Self::search_cell(&cells, found))
+    }
+
+    // Returns the content of the plane as a single string, and the byte offset
+    // and (y, x) coordinates of each glyph in it, for the search methods.
+    fn search_text(&self) -> (String, Vec<(usize, u32, u32)>) {
+        let mut text = String::new();
+        let mut cells = Vec::new();
+        for y in 0..self.dim_y() {
+            for (x, egc) in self.row_egcs(y) {
+                cells.push((text.len(), y, x));
+                match egc.as_str() {
+                    "" => text.push(' '),
+                    egc => text.push_str(egc),
+                }
+            }
+        }
+        (text, cells)
+    }
+
+    // Returns the column and the EGC of each glyph in the row `y`, skipping
+    // the secondary columns of the wide glyphs.
+    //
+    // Empty cells return the EGC of the base cell, as with `at_yx`.
+    pub(crate) fn row_egcs(&self, y: u32) -> Vec<(u32, String)> {
+        let mut egcs = Vec::new();
+        let (mut styles, mut channels) = (0, 0);
+        let mut x = 0;
+        while x < self.dim_x() {
+            let egc = unsafe {
+                c_api::ncplane_at_yx(self, y as i32, x as i32, &mut styles, &mut channels)
+            };
+            if egc.is_null() {
+                x += 1;
+                continue;
+            }
+            let egc = rstring_free![egc];
+            let width = str_width(&egc).max(1);
+            egcs.push((x, egc));
+            x += width;
+        }
+        egcs
+    }
+
+    // Returns the byte offset of the first glyph at or after `yx`.
+    fn search_offset(cells: &[(usize, u32, u32)], yx: (u32, u32), len: usize) -> usize {
+        let i = cells.partition_point(|&(_, y, x)| (y, x) < yx);
+        cells.get(i).map_or(len, |c| c.0)
+    }
+
+    // Returns the coordinates of the glyph containing the byte `offset`.
+    fn search_cell(cells: &[(usize, u32, u32)], offset: usize) -> (u32, u32) {
+        let (_, y, x) = cells[cells.partition_point(|c| c.0 <= offset) - 1];
+        (y, x)
+    }
+
     /// Erases every [`NcCell`] in this `NcPlane`, resetting all attributes to
     /// normal, all colors to the default color, and all cells to undrawn.
     ///
@@ -688,6 +1279,22 @@ impl NcPlane {
         }
     }
 
+    /// Erases this `NcPlane`, including its bitmap graphics, if any.
+    ///
+    /// This is the same as [`erase`][NcPlane#method.erase]. Blitting with
+    /// [`NcBlitter::Pixel`] draws bitmaps which are not made of cells, and
+    /// erasing their plane is what hides and releases them.
+    ///
+    /// This only affects the bitmaps drawn through notcurses. The bitmaps
+    /// already on the screen when notcurses started are cleared on startup,
+    /// unless [`NcFlag::NoClearBitmaps`][crate::NcFlag#associatedconstant.NoClearBitmaps]
+    /// was set.
+    ///
+    /// *C style function: [ncplane_erase()][c_api::ncplane_erase].*
+    pub fn destroy_bitmaps(&mut self) {
+        self.erase();
+    }
+
     /// Erases every cell in the region beginning at (`beg_y`, `beg_x`) and
     /// having a size (`len_y` × `len_x`) for non-zero lengths.
     ///
@@ -747,11 +1354,64 @@ impl NcPlane {
         ]
     }
 
+    /// Fills the region of `len_y`×`len_x` cells starting at `y`×`x` with
+    /// copies of the `cell`, drawing a horizontal line per row.
+    ///
+    /// The `cell` must already be associated with this `NcPlane`. A wide
+    /// `cell` is written as many times as it fits in `len_x` columns.
+    /// The cursor position is restored afterwards.
+    ///
+    /// Returns the number of cells written.
+    ///
+    /// It is an error for the region to be empty or to exceed the plane, or
+    /// for the `cell` to be wider than the region.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fill_region(
+        &mut self,
+        y: u32,
+        x: u32,
+        len_y: u32,
+        len_x: u32,
+        cell: &NcCell,
+    ) -> NcResult<u32> {
+        let (dim_y, dim_x) = self.dim_yx();
+        let width = (cell.width as u32).max(1);
+        if len_y == 0
+            || len_x < width
+            || y.checked_add(len_y).map_or(true, |end| end > dim_y)
+            || x.checked_add(len_x).map_or(true, |end| end > dim_x)
+        {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.fill_region({}, {}, {}, {}, {:?}) out of bounds",
+                y, x, len_y, len_x, cell
+            ]));
+        }
+
+        let (cursor_y, cursor_x) = self.cursor_yx();
+        let (mut written, mut res) = (0, Ok(()));
+        for row in y..y + len_y {
+            match self
+                .cursor_move_yx(row, x)
+                .and_then(|_| self.hline(cell, len_x / width))
+            {
+                Ok(cells) => written += cells,
+                Err(e) => {
+                    res = Err(e);
+                    break;
+                }
+            }
+        }
+        let _ = self.cursor_move_yx(cursor_y, cursor_x);
+        res.map(|_| written)
+    }
+
     /// Replaces the `NcCell` at the **specified** coordinates with the provided
     /// `NcCell`, advancing the cursor by its width (but not past the end of
     /// the plane).
     ///
     /// The new `NcCell` must already be associated with this `NcPlane`.
+    /// It's copied, so the same cell can be placed at many coordinates.
     ///
     /// On success, returns the number of columns the cursor was advanced.
     ///
@@ -760,12 +1420,16 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_putc_yx()][c_api::ncplane_putc_yx].*
     pub fn putc_yx(&mut self, y: u32, x: u32, cell: &NcCell) -> NcResult<u32> {
-        let res = unsafe { c_api::ncplane_putc_yx(self, y as i32, x as i32, cell) };
-        error![
-            res,
-            &format!("NcPlane.putc_yx({}, {}, {:?})", y, x, cell),
-            res as u32
-        ]
+        let egc = String::from(cell.egc(self));
+        self.scrollback_put(Some(y), Some(x), &egc, |plane, y, x, _| {
+            let (y, x) = (y.map_or(-1, |y| y as i32), x.map_or(-1, |x| x as i32));
+            let res = unsafe { c_api::ncplane_putc_yx(plane, y, x, cell) };
+            error![
+                res,
+                &format!("NcPlane.putc_yx({}, {}, {:?})", y, x, cell),
+                res as u32
+            ]
+        })
     }
 
     /// Replaces the [`NcCell`] at the **current** coordinates with the provided
@@ -773,6 +1437,7 @@ impl NcPlane {
     /// the plane).
     ///
     /// The new `NcCell` must already be associated with the `NcPlane`.
+    /// It's copied, so the same cell can be written many times.
     ///
     /// On success, returns the number of columns the cursor was advanced.
     ///
@@ -781,8 +1446,11 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_putc()][c_api::ncplane_putc].*
     pub fn putc(&mut self, cell: &NcCell) -> NcResult<u32> {
-        let res = c_api::ncplane_putc(self, cell);
-        error![res, &format!("NcPlane.putc({:?})", cell), res as u32]
+        let egc = String::from(cell.egc(self));
+        self.scrollback_put(None, None, &egc, |plane, _, _, _| {
+            let res = c_api::ncplane_putc(plane, cell);
+            error![res, &format!("NcPlane.putc({:?})", cell), res as u32]
+        })
     }
 
     /// Calls [`putchar_yx`][NcPlane#method.putchar_yx] at the current cursor
@@ -797,8 +1465,11 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_putchar()][c_api::ncplane_putchar].*
     pub fn putchar(&mut self, ch: char) -> NcResult<u32> {
-        let res = c_api::ncplane_putchar(self, ch);
-        error![res, &format!("NcPlane.putchar({:?})", ch), res as u32]
+        let mut buf = [0u8; 4];
+        self.scrollback_put(None, None, ch.encode_utf8(&mut buf), |plane, _, _, _| {
+            let res = c_api::ncplane_putchar(plane, ch);
+            error![res, &format!("NcPlane.putchar({:?})", ch), res as u32]
+        })
     }
 
     /// Replaces the [`NcCell`] at the current location with the provided `char`,
@@ -814,12 +1485,15 @@ impl NcPlane {
     /// *C style function: [ncplane_putchar_stained()][c_api::ncplane_putchar_stained].*
     // WIP
     pub fn putchar_stained(&mut self, ch: char) -> NcResult<u32> {
-        let res = c_api::ncplane_putchar_stained(self, ch);
-        error![
-            res,
-            &format!("NcPlane.putchar_stained({:?})", ch),
-            res as u32
-        ]
+        let mut buf = [0u8; 4];
+        self.scrollback_put(None, None, ch.encode_utf8(&mut buf), |plane, _, _, _| {
+            let res = c_api::ncplane_putchar_stained(plane, ch);
+            error![
+                res,
+                &format!("NcPlane.putchar_stained({:?})", ch),
+                res as u32
+            ]
+        })
     }
 
     /// Replaces the [`NcCell`] at the specified coordinates with the provided
@@ -834,12 +1508,16 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_putchar_yx()][c_api::ncplane_putchar_yx].*
     pub fn putchar_yx(&mut self, y: u32, x: u32, ch: char) -> NcResult<u32> {
-        let res = c_api::ncplane_putchar_yx(self, y, x, ch);
-        error![
-            res,
-            &format!("NcPlane.putchar_yx({}, {}, {:?})", y, x, ch),
-            res as u32
-        ]
+        let mut buf = [0u8; 4];
+        let egc = ch.encode_utf8(&mut buf);
+        self.scrollback_put(Some(y), Some(x), egc, |plane, y, x, egc| {
+            let res = c_api::ncplane_putegc_yx(plane, y, x, egc, None);
+            error![
+                res,
+                &format!("NcPlane.putchar_yx({:?}, {:?}, {:?})", y, x, ch),
+                res as u32
+            ]
+        })
     }
 
     /// Replaces the [`NcCell`] at the current location with the provided `egc`,
@@ -857,9 +1535,11 @@ impl NcPlane {
     /// NOTE: Unlike the original C function, this one accepts any 4-byte `char`.
     ///
     /// *C style function: [ncplane_putegc()][c_api::ncplane_putegc].*
-    pub fn putegc(&mut self, egc: &str, sbytes: Option<&mut usize>) -> NcResult<u32> {
-        let res = c_api::ncplane_putegc(self, egc, sbytes);
-        error![res, &format!("NcPlane.putegc({:?}, …)", egc), res as u32]
+    pub fn putegc(&mut self, egc: &str, mut sbytes: Option<&mut usize>) -> NcResult<u32> {
+        self.scrollback_put(None, None, egc, |plane, _, _, egc| {
+            let res = c_api::ncplane_putegc(plane, egc, sbytes.as_deref_mut());
+            error![res, &format!("NcPlane.putegc({:?}, …)", egc), res as u32]
+        })
     }
 
     /// Replaces the [`NcCell`] at the specified coordinates with the provided
@@ -882,14 +1562,38 @@ impl NcPlane {
         y: Option<u32>,
         x: Option<u32>,
         egc: &str,
-        sbytes: Option<&mut usize>,
+        mut sbytes: Option<&mut usize>,
     ) -> NcResult<u32> {
-        let res = c_api::ncplane_putegc_yx(self, y, x, egc, sbytes);
-        error![
-            res,
-            &format!("NcPlane.putegc_yx({:?}, {:?}, {:?}, …)", y, x, egc),
-            res as u32
-        ]
+        self.scrollback_put(y, x, egc, |plane, y, x, egc| {
+            let res = c_api::ncplane_putegc_yx(plane, y, x, egc, sbytes.as_deref_mut());
+            error![
+                res,
+                &format!("NcPlane.putegc_yx({:?}, {:?}, {:?}, …)", y, x, egc),
+                res as u32
+            ]
+        })
+    }
+
+    /// Replaces the [`NcCell`] at the `y`,`x` coordinates with the provided
+    /// `egc`, using the current style.
+    ///
+    /// Unlike [`putegc_yx`], both coordinates are mandatory, and the cursor is
+    /// moved and the cluster written in a single C call.
+    ///
+    /// Returns the number of columns the cursor advanced (`0`, `1` or `2`).
+    ///
+    /// *C style function: [ncplane_putegc_yx()][c_api::ncplane_putegc_yx].*
+    ///
+    /// [`putegc_yx`]: NcPlane#method.putegc_yx
+    pub fn putegc_at(&mut self, y: u32, x: u32, egc: &str) -> NcResult<u32> {
+        self.scrollback_put(Some(y), Some(x), egc, |plane, y, x, egc| {
+            let res = c_api::ncplane_putegc_yx(plane, y, x, egc, None);
+            error![
+                res,
+                &format!("NcPlane.putegc_at({:?}, {:?}, {:?})", y, x, egc),
+                res as u32
+            ]
+        })
     }
 
     /// Replaces the [`NcCell`] at the current location with the provided `egc`,
@@ -907,13 +1611,15 @@ impl NcPlane {
     /// NOTE: Unlike the original C function, this one accepts any 4-byte `char`.
     ///
     /// *C style function: [ncplane_putegc_stained()][c_api::ncplane_putegc_stained].*
-    pub fn putegc_stained(&mut self, egc: &str, sbytes: Option<&mut usize>) -> NcResult<u32> {
-        let res = c_api::ncplane_putegc_stained(self, egc, sbytes);
-        error![
-            res,
-            &format!("NcPlane.putegc_stained({:?}, …)", egc),
-            res as u32
-        ]
+    pub fn putegc_stained(&mut self, egc: &str, mut sbytes: Option<&mut usize>) -> NcResult<u32> {
+        self.scrollback_put(None, None, egc, |plane, _, _, egc| {
+            let res = c_api::ncplane_putegc_stained(plane, egc, sbytes.as_deref_mut());
+            error![
+                res,
+                &format!("NcPlane.putegc_stained({:?}, …)", egc),
+                res as u32
+            ]
+        })
     }
 
     /// Write the specified text to the plane, breaking lines sensibly,
@@ -941,11 +1647,14 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_puttext()][c_api::ncplane_puttext].*
     pub fn puttext(&mut self, y: u32, align: impl Into<NcAlign>, string: &str) -> NcResult<u32> {
+        let align = align.into();
         let cs = cstring![string];
-        let res = unsafe {
-            c_api::ncplane_puttext(self, y as i32, align.into().into(), cs.as_ptr(), null_mut())
-        };
-        error![res, &format!("NcPlane.puttext({:?})", string), res as u32]
+        self.scrollback_put_laid_out(|plane| {
+            let res = unsafe {
+                c_api::ncplane_puttext(plane, y as i32, align.into(), cs.as_ptr(), null_mut())
+            };
+            error![res, &format!("NcPlane.puttext({:?})", string), res as u32]
+        })
     }
 
     /// Writes a string to the current location, using the current style.
@@ -964,8 +1673,10 @@ impl NcPlane {
     /// *C style function: [ncplane_putstr()][c_api::ncplane_putstr].*
     #[inline]
     pub fn putstr(&mut self, string: &str) -> NcResult<u32> {
-        let res = c_api::ncplane_putstr(self, string);
-        error![res, &format!("NcPlane.putstr({:?})", string), res as u32]
+        self.scrollback_put(None, None, string, |plane, _, _, string| {
+            let res = c_api::ncplane_putstr(plane, string);
+            error![res, &format!("NcPlane.putstr({:?})", string), res as u32]
+        })
     }
 
     /// Same as [`putstr`][NcPlane#method.putstr], but it also puts a newline
@@ -980,6 +1691,188 @@ impl NcPlane {
         Ok(cols)
     }
 
+    /// Writes a string to the current location, using the current style,
+    /// wrapping it at word boundaries to fit the width of the plane.
+    ///
+    /// Words longer than the width of the plane are broken at the last column
+    /// that fits, never splitting a grapheme. Newlines in `string` start a
+    /// new line, and runs of whitespace are collapsed into a single space.
+    ///
+    /// When the bottom of the plane is reached, it will scroll if scrolling is
+    /// enabled (or grow, if autogrow is also enabled). Otherwise it is an error.
+    ///
+    /// Returns the final cursor position `(y, x)`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_wrapped(&mut self, string: &str) -> NcResult<(u32, u32)> {
+        let cols = self.dim_x();
+
+        for (i, line) in string.split('\n').enumerate() {
+            if i > 0 {
+                self.putstr_wrapped_newline(string)?;
+            }
+            for word in line.split_whitespace() {
+                let word_width = str_width(word);
+                let x = self.cursor_x();
+                if x > 0 {
+                    if x + 1 + word_width <= cols {
+                        self.putstr(" ")?;
+                    } else {
+                        self.putstr_wrapped_newline(string)?;
+                    }
+                }
+                if word_width <= cols - self.cursor_x() {
+                    self.putstr(word)?;
+                    continue;
+                }
+                // the word doesn't fit in a single line, so it's split
+                // between graphemes, writing as many as fit in each line
+                let mut rest = word;
+                while !rest.is_empty() {
+                    let room = cols - self.cursor_x();
+                    let (mut end, mut width) = (0, 0);
+                    while end < rest.len() {
+                        let len = egc_len(&rest[end..]);
+                        let egc_width = str_width(&rest[end..end + len]);
+                        if width + egc_width > room {
+                            break;
+                        }
+                        width += egc_width;
+                        end += len;
+                    }
+                    if end == 0 {
+                        if self.cursor_x() > 0 {
+                            self.putstr_wrapped_newline(string)?;
+                            continue;
+                        }
+                        // a grapheme wider than the plane
+                        end = egc_len(rest);
+                    }
+                    self.putstr(&rest[..end])?;
+                    rest = &rest[end..];
+                }
+            }
+        }
+        Ok(self.cursor_yx())
+    }
+
+    // Moves the cursor to the start of the next line, for `putstr_wrapped`.
+    fn putstr_wrapped_newline(&mut self, string: &str) -> NcResult<()> {
+        if self.scrolling_p() {
+            // scrolls, or grows the plane if autogrow is also enabled
+            self.putstr("\n")?;
+            Ok(())
+        } else if self.cursor_y() + 1 < self.dim_y() {
+            self.cursor_move_yx(self.cursor_y() + 1, 0)
+        } else {
+            Err(NcError::new_msg(&format![
+                "NcPlane.putstr_wrapped({:?}) out of rows",
+                string
+            ]))
+        }
+    }
+
+    /// Writes a string to the current location, using the current style,
+    /// truncating it with an ellipsis (`…`) if it's wider than `max_cols`.
+    ///
+    /// When truncated, the string is cut at the last grapheme that fits in
+    /// `max_cols - 1` columns, never splitting a wide glyph or a cluster,
+    /// and the ellipsis is written after it.
+    ///
+    /// Returns the number of columns written.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_ellipsized(&mut self, max_cols: u32, string: &str) -> NcResult<u32> {
+        if max_cols == 0 {
+            return Ok(0);
+        }
+        if str_width(string) <= max_cols {
+            return self.putstr(string);
+        }
+
+        let (mut end, mut width) = (0, 0);
+        while end < string.len() {
+            let len = egc_len(&string[end..]);
+            let egc_width = str_width(&string[end..end + len]);
+            if width + egc_width > max_cols - 1 {
+                break;
+            }
+            width += egc_width;
+            end += len;
+        }
+        let mut cols = 0;
+        if end > 0 {
+            cols += self.putstr(&string[..end])?;
+        }
+        cols += self.putstr("…")?;
+        Ok(cols)
+    }
+
+    /// Writes a string to the current location, using the given `styles` and
+    /// `channels` only for this call.
+    ///
+    /// The current styles and channels of the plane are restored afterwards,
+    /// even on error.
+    ///
+    /// Returns the number of columns the cursor was advanced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_styled(
+        &mut self,
+        styles: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        string: &str,
+    ) -> NcResult<u32> {
+        let (prev_styles, prev_channels) = (self.styles(), self.channels());
+        self.set_styles(styles);
+        self.set_channels(channels);
+        let res = self.putstr(string);
+        self.set_styles(prev_styles);
+        self.set_channels(prev_channels);
+        res
+    }
+
+    /// Like [`putchar`][NcPlane#method.putchar], but returns `None` instead of
+    /// an error when there's no room for the glyph in the current line.
+    ///
+    /// On success, returns the number of columns the cursor was advanced.
+    ///
+    /// It's useful in rendering loops where truncating the output is fine.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putchar_safe(&mut self, ch: char) -> Option<u32> {
+        let mut buf = [0u8; 4];
+        let width = str_width(ch.encode_utf8(&mut buf));
+        if !self.scrolling_p() && self.cursor_x() + width > self.dim_x() {
+            return None;
+        }
+        self.putchar(ch).ok()
+    }
+
+    /// Like [`putstr`][NcPlane#method.putstr], but instead of returning an
+    /// error when a glyph doesn't fit, stops writing there.
+    ///
+    /// Returns the number of columns written, or `None` if nothing could be
+    /// written at all. An empty `string` returns `Some(0)`.
+    ///
+    /// It's useful in rendering loops where truncating the output is fine.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn putstr_safe(&mut self, string: &str) -> Option<usize> {
+        if string.is_empty() {
+            return Some(0);
+        }
+        if self.scrollback_recording() {
+            return self.putstr(string).ok().map(|cols| cols as usize);
+        }
+        self.scrollback_restore().ok()?;
+        // on error, returns the negated number of columns written before it
+        match c_api::ncplane_putstr(self, string) {
+            0 => None,
+            res => Some(res.unsigned_abs() as usize),
+        }
+    }
+
     /// Prints a new line character.
     ///
     /// This will only work if scrolling is enabled in the plane.
@@ -1003,12 +1896,14 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_putstr_stained()][c_api::ncplane_putstr_stained].*
     pub fn putstr_stained(&mut self, string: &str) -> NcResult<u32> {
-        let res = c_api::ncplane_putstr_stained(self, string);
-        error![
-            res,
-            &format!("NcPlane.putstr_stained({:?})", string),
-            res as u32
-        ]
+        self.scrollback_put(None, None, string, |plane, _, _, string| {
+            let res = c_api::ncplane_putstr_stained(plane, string);
+            error![
+                res,
+                &format!("NcPlane.putstr_stained({:?})", string),
+                res as u32
+            ]
+        })
     }
 
     /// Writes a string to the provided location, using the current style
@@ -1031,12 +1926,15 @@ impl NcPlane {
         string: &str,
     ) -> NcResult<u32> {
         let align = align.into();
-        let res = c_api::ncplane_putstr_aligned(self, y, align, string);
-        error![
-            res,
-            &format!("NcPlane.putstr_aligned({:?}, {}, {:?})", y, align, string),
-            res as u32
-        ]
+        let xpos = self.halign(align, str_width(string))?;
+        self.scrollback_put(y, Some(xpos), string, |plane, y, x, string| {
+            let res = c_api::ncplane_putstr_yx(plane, y, x, string);
+            error![
+                res,
+                &format!("NcPlane.putstr_aligned({:?}, {}, {:?})", y, align, string),
+                res as u32
+            ]
+        })
     }
 
     /// Writes a string to the provided location, using the current style.
@@ -1052,12 +1950,14 @@ impl NcPlane {
     ///
     /// *C style function: [ncplane_putstr_yx()][c_api::ncplane_putstr_yx].*
     pub fn putstr_yx(&mut self, y: Option<u32>, x: Option<u32>, string: &str) -> NcResult<u32> {
-        let res = c_api::ncplane_putstr_yx(self, y, x, string);
-        error![
-            res,
-            &format!("NcPlane.putstr_yx({:?}, {:?}, {:?})", y, x, string),
-            res as u32
-        ]
+        self.scrollback_put(y, x, string, |plane, y, x, string| {
+            let res = c_api::ncplane_putstr_yx(plane, y, x, string);
+            error![
+                res,
+                &format!("NcPlane.putstr_yx({:?}, {:?}, {:?})", y, x, string),
+                res as u32
+            ]
+        })
     }
 
     /// Writes a string to the provided location, [`NcAlign`]ed on *x*
@@ -1084,15 +1984,17 @@ impl NcPlane {
         let xpos = self.halign(align, width)?;
         let new_y = if let Some(y) = y { y } else { self.cursor_y() };
         self.cursor_move_yx(new_y, xpos)?;
-        let res = c_api::ncplane_putstr_stained(self, string);
-        error![
-            res,
-            &format!(
-                "NcPlane.putstr_aligned_stained({}, {}, {:?})",
-                new_y, align, string
-            ),
-            res as u32
-        ]
+        self.scrollback_put(None, None, string, |plane, _, _, string| {
+            let res = c_api::ncplane_putstr_stained(plane, string);
+            error![
+                res,
+                &format!(
+                    "NcPlane.putstr_aligned_stained({}, {}, {:?})",
+                    new_y, align, string
+                ),
+                res as u32
+            ]
+        })
     }
 
     /// Writes a string to the provided location, while retaining the previous
@@ -1110,12 +2012,14 @@ impl NcPlane {
     /// *(No equivalent C style function)*
     pub fn putstr_yx_stained(&mut self, y: u32, x: u32, string: &str) -> NcResult<u32> {
         self.cursor_move_yx(y, x)?;
-        let res = c_api::ncplane_putstr_stained(self, string);
-        error![
-            res,
-            &format!("NcPlane.putstr_yx_stained({}, {}, {:?})", y, x, string),
-            res as u32
-        ]
+        self.scrollback_put(None, None, string, |plane, _, _, string| {
+            let res = c_api::ncplane_putstr_stained(plane, string);
+            error![
+                res,
+                &format!("NcPlane.putstr_yx_stained({}, {}, {:?})", y, x, string),
+                res as u32
+            ]
+        })
     }
 
     /// Writes a string to the current location, using the current style,
@@ -1133,12 +2037,15 @@ impl NcPlane {
     /// *C style function: [ncplane_putnstr()][c_api::ncplane_putnstr].*
     #[inline]
     pub fn putnstr(&mut self, num_bytes: usize, string: &str) -> NcResult<u32> {
-        let res = c_api::ncplane_putnstr(self, num_bytes, string);
-        error![
-            res,
-            &format!("NcPlane.puntstr({}, {:?})", num_bytes, string),
-            res as u32
-        ]
+        let string = egc_prefix(string, num_bytes);
+        self.scrollback_put(None, None, string, |plane, _, _, string| {
+            let res = c_api::ncplane_putnstr(plane, string.len(), string);
+            error![
+                res,
+                &format!("NcPlane.puntstr({}, {:?})", num_bytes, string),
+                res as u32
+            ]
+        })
     }
 
     /// Writes a string to the provided location, using the current style,
@@ -1162,19 +2069,19 @@ impl NcPlane {
         string: &str,
     ) -> NcResult<u32> {
         let align = align.into();
-        let cs = cstring![string];
-        let new_y = if let Some(y) = y { y as i32 } else { self.cursor_y() as i32 };
-        let res = unsafe {
-            c_api::ncplane_putnstr_aligned(self, new_y, align.into(), num_bytes, cs.as_ptr())
-        };
-        error![
-            res,
-            &format!(
-                "NcPlane.putnstr_aligned({}, {}, {}, {:?})",
-                new_y, align, num_bytes, string
-            ),
-            res as u32
-        ]
+        let string = egc_prefix(string, num_bytes);
+        let xpos = self.halign(align, str_width(string))?;
+        self.scrollback_put(y, Some(xpos), string, |plane, y, x, string| {
+            let res = c_api::ncplane_putnstr_yx(plane, y, x, string.len(), string);
+            error![
+                res,
+                &format!(
+                    "NcPlane.putnstr_aligned({:?}, {}, {}, {:?})",
+                    y, align, num_bytes, string
+                ),
+                res as u32
+            ]
+        })
     }
 
     /// Writes a string to the provided location, using the current style,
@@ -1197,15 +2104,18 @@ impl NcPlane {
         num_bytes: usize,
         string: &str,
     ) -> NcResult<u32> {
-        let res = c_api::ncplane_putnstr_yx(self, y, x, num_bytes, string);
-        error![
-            res,
-            &format!(
-                "NcPlane.putnstr_yx({:?}, {:?}, {}, {:?})",
-                y, x, num_bytes, string
-            ),
-            res as u32
-        ]
+        let string = egc_prefix(string, num_bytes);
+        self.scrollback_put(y, x, string, |plane, y, x, string| {
+            let res = c_api::ncplane_putnstr_yx(plane, y, x, string.len(), string);
+            error![
+                res,
+                &format!(
+                    "NcPlane.putnstr_yx({:?}, {:?}, {}, {:?})",
+                    y, x, num_bytes, string
+                ),
+                res as u32
+            ]
+        })
     }
 
     /// Considers the glyph at `y`,`x` coordinates as the fill target,
@@ -1312,6 +2222,66 @@ impl NcPlane {
         ]
     }
 
+    /// Moves this `NcPlane` and its bound planes relative to the standard
+    /// plane, or the plane to which it is bound.
+    ///
+    /// This is the same as [`move_yx`], since the position of the bound planes
+    /// is relative to their parent, and they always move along with it.
+    ///
+    /// It is an error to attempt to move the standard plane.
+    ///
+    /// *C style function: [ncplane_move_yx()][c_api::ncplane_move_yx].*
+    ///
+    /// [`move_yx`]: NcPlane#method.move_yx
+    pub fn move_family_yx(&mut self, y: i32, x: i32) -> NcResult<()> {
+        self.move_yx(y, x)
+    }
+
+    /// Moves this `NcPlane` and its bound planes relative to its current
+    /// location.
+    ///
+    /// This is the same as [`move_rel`], since the position of the bound
+    /// planes is relative to their parent, and they always move along with it.
+    ///
+    /// It is an error to attempt to move the standard plane.
+    ///
+    /// *C style function: [ncplane_moverel()][c_api::ncplane_moverel].*
+    ///
+    /// [`move_rel`]: NcPlane#method.move_rel
+    pub fn move_family_rel(&mut self, rows: i32, cols: i32) -> NcResult<()> {
+        self.move_rel(rows, cols)
+    }
+
+    /// Moves this `NcPlane` like [`move_yx`], but clamps the position so that
+    /// at least one cell of the plane remains within the bounds of the
+    /// standard plane.
+    ///
+    /// Returns the position actually used, relative to the standard plane,
+    /// or the plane to which it is bound.
+    ///
+    /// It is an error to attempt to move the standard plane.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`move_yx`]: NcPlane#method.move_yx
+    pub fn move_yx_clamped(&mut self, y: i32, x: i32) -> NcResult<(i32, i32)> {
+        let (term_y, term_x) = unsafe { self.notcurses_const()? }.term_dim_yx();
+        let (dim_y, dim_x) = self.dim_yx();
+        let ((abs_y, abs_x), (rel_y, rel_x)) = (self.abs_yx(), self.yx());
+
+        // the origin of the parent, relative to the standard plane
+        let (parent_y, parent_x) = (abs_y - rel_y, abs_x - rel_x);
+
+        let clamp = |pos: i32, parent: i32, dim: u32, term: u32| {
+            let abs = (parent as i64 + pos as i64).clamp(1 - dim as i64, term as i64 - 1);
+            (abs - parent as i64) as i32
+        };
+        let y = clamp(y, parent_y, dim_y, term_y);
+        let x = clamp(x, parent_x, dim_x, term_x);
+        self.move_yx(y, x)?;
+        Ok((y, x))
+    }
+
     /// Returns the `NcPlane` above this one, or None if already at the top.
     ///
     /// *C style function: [ncplane_above()][c_api::ncplane_above].*
@@ -1497,6 +2467,127 @@ impl NcPlane {
         ]
     }
 
+    /// Splits this `NcPlane` vertically into two new planes, side by side,
+    /// and destroys it, returning the `(left, right)` planes.
+    ///
+    /// The left plane gets the columns `0..at_col`, and the right plane the
+    /// columns `at_col..`, with their content merged down from this plane.
+    /// Both are bound to the same parent, or are new piles if this plane
+    /// is the root of its pile.
+    ///
+    /// It is an error if `at_col` is `0` or not less than the number of
+    /// columns, or if this is the standard plane.
+    ///
+    /// This `NcPlane` must not be used after a successful call.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn vsplit<'a>(&mut self, at_col: u32) -> NcResult<(&'a mut NcPlane, &'a mut NcPlane)> {
+        let (rows, cols) = self.dim_yx();
+        if at_col == 0 || at_col >= cols {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.vsplit({}) invalid column",
+                at_col
+            ]));
+        }
+        self.split((rows, at_col), (0, at_col), (rows, cols - at_col), "vsplit")
+    }
+
+    /// Splits this `NcPlane` horizontally into two new planes, one above the
+    /// other, and destroys it, returning the `(top, bottom)` planes.
+    ///
+    /// The top plane gets the rows `0..at_row`, and the bottom plane the
+    /// rows `at_row..`, with their content merged down from this plane.
+    /// Both are bound to the same parent, or are new piles if this plane
+    /// is the root of its pile.
+    ///
+    /// It is an error if `at_row` is `0` or not less than the number of rows,
+    /// or if this is the standard plane.
+    ///
+    /// This `NcPlane` must not be used after a successful call.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn hsplit<'a>(&mut self, at_row: u32) -> NcResult<(&'a mut NcPlane, &'a mut NcPlane)> {
+        let (rows, cols) = self.dim_yx();
+        if at_row == 0 || at_row >= rows {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.hsplit({}) invalid row",
+                at_row
+            ]));
+        }
+        self.split((at_row, cols), (at_row, 0), (rows - at_row, cols), "hsplit")
+    }
+
+    // Creates the two planes of `vsplit` & `hsplit`, the first one at the origin
+    // of this plane and the second one at the `offset`, and destroys this plane.
+    fn split<'a>(
+        &mut self,
+        first_len: (u32, u32),
+        offset: (u32, u32),
+        second_len: (u32, u32),
+        method: &str,
+    ) -> NcResult<(&'a mut NcPlane, &'a mut NcPlane)> {
+        let (y, x) = self.yx();
+        let this = self as *mut NcPlane;
+        let parent = unsafe { c_api::ncplane_parent(self) };
+        let nc = unsafe { self.notcurses()? };
+        if this == unsafe { c_api::notcurses_stdplane(nc) } {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.{}() on the standard plane",
+                method
+            ]));
+        }
+
+        let mut create = |(off_y, off_x): (u32, u32), (rows, cols)| {
+            let options = NcPlaneOptions::new(y + off_y as i32, x + off_x as i32, rows, cols);
+            if parent == this {
+                NcPlane::new_pile(nc, &options)
+            } else {
+                NcPlane::new_child(unsafe { &mut *parent }, &options)
+            }
+        };
+        let first = create((0, 0), first_len)?;
+        let second = match create(offset, second_len) {
+            Ok(plane) => plane,
+            Err(e) => {
+                let _ = first.destroy();
+                return Err(e);
+            }
+        };
+
+        let (first_rows, first_cols) = first_len;
+        let (second_rows, second_cols) = second_len;
+        let (off_y, off_x) = offset;
+        let merged = first
+            .mergedown(
+                self,
+                Some(0),
+                Some(0),
+                Some(first_rows),
+                Some(first_cols),
+                Some(0),
+                Some(0),
+            )
+            .and_then(|_| {
+                second.mergedown(
+                    self,
+                    Some(off_y),
+                    Some(off_x),
+                    Some(second_rows),
+                    Some(second_cols),
+                    Some(0),
+                    Some(0),
+                )
+            });
+        // this plane is left untouched if the new planes can't be filled
+        if let Err(e) = merged {
+            let _ = first.destroy();
+            let _ = second.destroy();
+            return Err(e);
+        }
+        self.destroy()?;
+        Ok((first, second))
+    }
+
     /// Gets the parent to which this `NcPlane` is bound, if any.
     ///
     /// # Safety
@@ -1750,6 +2841,69 @@ impl NcPlane {
         self.cursor_move_cols(cols)?;
         Ok(())
     }
+
+    /// Returns an iterator over the positions where every grapheme cluster of
+    /// `text` would be placed by [`putstr`][NcPlane#method.putstr] from the
+    /// current cursor position, as `(row, col, byte_offset)`.
+    ///
+    /// Wide glyphs advance the cursor two columns, tabs advance it to the next
+    /// multiple of 8 columns, and newlines to the start of the next line.
+    ///
+    /// A glyph that doesn't fit in the rest of the line is wrapped to the next
+    /// one, but only if scrolling is enabled, like newlines. On the last row
+    /// the plane scrolls, so the row doesn't increase, unless autogrow is
+    /// also enabled. The iterator ends at the first glyph that can't be placed.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn text_cursor_positions<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> impl Iterator<Item = (u32, u32, usize)> + 'a {
+        let (dim_y, dim_x) = self.dim_yx();
+        let (scrolling, autogrow) = (self.scrolling_p(), self.autogrow_p());
+        let (mut y, mut x) = self.cursor_yx();
+        let mut offset = 0;
+
+        // returns false if there's no next line
+        let newline = move |y: &mut u32, x: &mut u32| {
+            if !scrolling {
+                return false;
+            }
+            if *y + 1 < dim_y || autogrow {
+                *y += 1;
+            }
+            *x = 0;
+            true
+        };
+
+        core::iter::from_fn(move || {
+            let rest = &text[offset..];
+            let len = egc_len(rest);
+            if len == 0 {
+                return None;
+            }
+            let egc = &rest[..len];
+            let egc_offset = offset;
+            offset += len;
+
+            if egc == "\n" {
+                let pos = (y, x, egc_offset);
+                if !newline(&mut y, &mut x) {
+                    offset = text.len();
+                    return None;
+                }
+                return Some(pos);
+            }
+            let width = if egc == "\t" { 8 - x % 8 } else { str_width(egc) };
+            if x + width > dim_x && !newline(&mut y, &mut x) {
+                offset = text.len();
+                return None;
+            }
+            let pos = (y, x, egc_offset);
+            x += width;
+            Some(pos)
+        })
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -1895,6 +3049,59 @@ impl NcPlane {
         ]
     }
 
+    /// Creates an RGBA vector from the selected region of the plane,
+    /// returning it along with its dimensions in pixels: `(pixels, y, x)`.
+    ///
+    /// Unlike [`as_rgba`][NcPlane#method.as_rgba], the pixels are copied
+    /// and the C allocation is freed.
+    ///
+    /// Each pixel is a `u32` with its components in `0xRRGGBBAA` order,
+    /// independently of the endianness, like the bytes returned by
+    /// [`NcVisual.to_rgba_vec`][NcVisual#method.to_rgba_vec].
+    ///
+    /// See [`as_rgba`][NcPlane#method.as_rgba] for the meaning of the arguments.
+    ///
+    /// *C style function: [ncplane_as_rgba()][c_api::ncplane_as_rgba].*
+    pub fn as_rgba_vec(
+        &mut self,
+        blitter: impl Into<NcBlitter>,
+        beg_y: Option<u32>,
+        beg_x: Option<u32>,
+        len_y: Option<u32>,
+        len_x: Option<u32>,
+    ) -> NcResult<(Vec<u32>, u32, u32)> {
+        let blitter = blitter.into();
+        let (mut pxdim_y, mut pxdim_x) = (0, 0);
+
+        let res_array = unsafe {
+            c_api::ncplane_as_rgba(
+                self,
+                blitter.into(),
+                beg_y.unwrap_or(u32::MAX) as i32, // -1_i32
+                beg_x.unwrap_or(u32::MAX) as i32, // "
+                len_y.unwrap_or(0),
+                len_x.unwrap_or(0),
+                &mut pxdim_y,
+                &mut pxdim_x,
+            )
+        };
+        if res_array.is_null() {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.as_rgba_vec({}, {:?}, {:?}, {:?}, {:?})",
+                blitter, beg_y, beg_x, len_y, len_x
+            ]));
+        }
+
+        // the C buffer has the bytes of each pixel in R, G, B, A order
+        let len = (pxdim_y * pxdim_x) as usize * 4;
+        let pixels = unsafe { core::slice::from_raw_parts(res_array as *const u8, len) }
+            .chunks_exact(4)
+            .map(|rgba| u32::from_be_bytes([rgba[0], rgba[1], rgba[2], rgba[3]]))
+            .collect();
+        unsafe { c_api::ffi::free(res_array as *mut c_void) };
+        Ok((pixels, pxdim_y, pxdim_x))
+    }
+
     /// Returns an [`NcPixelGeometry`] structure filled with pixel geometry for
     /// the display region, each cell, and the maximum displayable bitmap.
     ///
@@ -2034,10 +3241,15 @@ impl NcPlane {
     /// Resizes this `NcPlane`, retaining what data we can (everything, unless we're
     /// shrinking in some dimension). Keeps the origin where it is.
     ///
+    /// The retained content stays at the top-left corner.
+    ///
     /// *C style function: [ncplane_resize_simple()][c_api::ncplane_resize_simple].*
     #[inline]
     pub fn resize_simple(&mut self, len_y: u32, len_x: u32) -> NcResult<()> {
-        error![c_api::ncplane_resize_simple(self, len_y, len_x)]
+        error![
+            c_api::ncplane_resize_simple(self, len_y, len_x),
+            &format!("NcPlane.resize_simple({}, {})", len_y, len_x)
+        ]
     }
 
     /// Returns this `NcPlane`'s current resize callback, or `None` if not set.
@@ -2056,6 +3268,51 @@ impl NcPlane {
         unsafe { c_api::ncplane_set_resizecb(self, c_api::ncresizecb_to_c(resizecb)) }
     }
 
+    /// Removes this `NcPlane`'s resize callback, and drops its [`resize_fn`]
+    /// closure, if any.
+    ///
+    /// The standard plane's resize callback may not be changed.
+    ///
+    /// *C style function: [ncplane_set_resizecb()][c_api::ncplane_set_resizecb].*
+    ///
+    /// [`resize_fn`]: crate::NcPlaneOptionsBuilder#method.resize_fn
+    pub fn clear_resizecb(&mut self) {
+        self.set_resizecb(None);
+        if let Some(userdata) = unsafe { NcPlaneUserData::get(self) } {
+            userdata.resize_fn = None;
+        }
+        unsafe { NcPlaneUserData::drop_if_empty(self) };
+    }
+
+    /// Sets a closure to be called when the parent of this `NcPlane` is
+    /// resized, replacing any previous resize callback or closure.
+    ///
+    /// The closure is stored in the plane's user pointer, and it's dropped
+    /// when replaced, [cleared], or when the plane is [`destroy`]ed.
+    ///
+    /// The closure is called during the rendering, so it must not call
+    /// [`Nc.render`][Nc#method.render] itself.
+    ///
+    /// The standard plane's resize callback may not be changed.
+    ///
+    /// *C style function: [ncplane_set_resizecb()][c_api::ncplane_set_resizecb].*
+    ///
+    /// [cleared]: NcPlane#method.clear_resizecb
+    /// [`destroy`]: NcPlane#method.destroy
+    pub fn set_resize_callback<F>(&mut self, mut cb: F)
+    where
+        F: FnMut(&mut NcPlane) + 'static,
+    {
+        let resize_fn: NcResizeFnShared = Rc::new(RefCell::new(move |plane: &mut NcPlane| {
+            cb(plane);
+            Ok(())
+        }));
+        unsafe {
+            NcPlaneUserData::get_or_insert(self).resize_fn = Some(resize_fn);
+            c_api::ncplane_set_resizecb(self, Some(ncresizefn_trampoline));
+        }
+    }
+
     /// Rotate the plane π/2 radians (90°) clockwise.
     ///
     /// This cannot be performed on arbitrary planes, because glyphs cannot be
@@ -2155,6 +3412,36 @@ impl NcPlane {
         unsafe { c_api::ncplane_abs_x(self) }
     }
 
+    /// Moves this plane so that it's centered within `container`.
+    ///
+    /// When the remaining space is odd, the plane leans to the top and left.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn center_within(&mut self, container: &NcPlane) -> NcResult<()> {
+        let (rows, cols) = self.dim_yx();
+        let (c_rows, c_cols) = container.dim_yx();
+        let (c_y, c_x) = container.abs_yx();
+
+        // the new origin is relative to the parent, or to the pile if it's a root plane
+        let parent = unsafe { c_api::ncplane_parent_const(self) };
+        let (p_y, p_x) = if core::ptr::eq(parent, self) || parent.is_null() {
+            (0, 0)
+        } else {
+            unsafe { (*parent).abs_yx() }
+        };
+
+        let y = c_y - p_y + (c_rows as i32 - rows as i32) / 2;
+        let x = c_x - p_x + (c_cols as i32 - cols as i32) / 2;
+        self.move_yx(y, x)
+    }
+
+    /// Moves this plane so that it's centered within the standard plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn center_on_stdplane(&mut self, nc: &Nc) -> NcResult<()> {
+        self.center_within(nc.stdplane_ref())
+    }
+
     /// Returns `true` if this `NcPlane` has scrolling enabled, or `false` otherwise.
     ///
     /// *C style function: [ncplane_scrolling_p()][c_api::ncplane_scrolling_p].*
@@ -2181,15 +3468,39 @@ impl NcPlane {
         unsafe { c_api::ncplane_set_scrolling(self, scroll.into()) }
     }
 
+    /// Calls `f` with the scrolling of the plane set to `enabled`, and then
+    /// restores the previous scrolling state, returning the result of `f`.
+    ///
+    /// The previous state is restored even if `f` panics.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn with_scrolling<R>(&mut self, enabled: bool, f: impl FnOnce(&mut NcPlane) -> R) -> R {
+        struct Restore<'a>(&'a mut NcPlane, bool);
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                self.0.set_scrolling(self.1);
+            }
+        }
+
+        let previous = self.set_scrolling(enabled);
+        let restore = Restore(self, previous);
+        f(&mut *restore.0)
+    }
+
     /// Sends `n` scroll events to the current plane.
     ///
     /// Returns an error if the current plane is not a scrolling plane,
     /// and otherwise returns the number of lines scrolled.
     ///
+    /// The lines scrolled off are recorded in the
+    /// [scrollback buffer][NcPlane#method.set_scrollback_size], if any.
+    ///
     /// *C style function: [ncplane_scrollup()][c_api::ncplane_scrollup].*
     pub fn scrollup(&mut self, n: u32) -> NcResult<u32> {
-        let res = unsafe { c_api::ncplane_scrollup(self, n as i32) };
-        error![res, "", res as u32]
+        self.scrollback_scroll(n, |plane| {
+            let res = unsafe { c_api::ncplane_scrollup(plane, n as i32) };
+            error![res, "", res as u32]
+        })
     }
 
     /// Scrolls the current plane until `child` is no longer hidden beneath it.
@@ -2237,6 +3548,135 @@ impl NcPlane {
     }
 }
 
+// -----------------------------------------------------------------------------
+/// ## NcPlane methods: lines
+impl NcPlane {
+    /// Draws a horizontal line of `len` copies of `cell`, starting at the
+    /// current cursor position.
+    ///
+    /// The cursor will end at the cell following the last cell output.
+    ///
+    /// Returns the number of cells drawn.
+    ///
+    /// *C style function: [ncplane_hline()][c_api::ncplane_hline].*
+    pub fn hline(&mut self, cell: &NcCell, len: u32) -> NcResult<u32> {
+        let res = c_api::ncplane_hline(self, cell, len);
+        error![
+            res,
+            &format!("NcPlane.hline({:?}, {})", cell, len),
+            res as u32
+        ]
+    }
+
+    /// Draws a vertical line of `len` copies of `cell`, starting at the
+    /// current cursor position.
+    ///
+    /// The cursor will end at the cell following the last cell output.
+    ///
+    /// Returns the number of cells drawn.
+    ///
+    /// *C style function: [ncplane_vline()][c_api::ncplane_vline].*
+    pub fn vline(&mut self, cell: &NcCell, len: u32) -> NcResult<u32> {
+        let res = c_api::ncplane_vline(self, cell, len);
+        error![
+            res,
+            &format!("NcPlane.vline({:?}, {})", cell, len),
+            res as u32
+        ]
+    }
+
+    /// Draws a horizontal line of `glyph` on row `y`, from column `beg_x`
+    /// to column `end_x`, both inclusive, in any order.
+    ///
+    /// The line uses the given `style` and the current channels of the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn line_horizontal(
+        &mut self,
+        y: u32,
+        beg_x: u32,
+        end_x: u32,
+        glyph: &str,
+        style: impl Into<NcStyle>,
+    ) -> NcResult<()> {
+        let (beg_x, end_x) = if beg_x <= end_x { (beg_x, end_x) } else { (end_x, beg_x) };
+        let channels = self.channels();
+        let mut cell = NcCell::new();
+        NcCell::prime(self, &mut cell, glyph, style, channels)?;
+        let res = self
+            .cursor_move_yx(y, beg_x)
+            .and_then(|_| self.hline(&cell, end_x - beg_x + 1));
+        cell.release(self);
+        res.map(|_| ())
+    }
+
+    /// Draws a vertical line of `glyph` on column `x`, from row `beg_y`
+    /// to row `end_y`, both inclusive, in any order.
+    ///
+    /// The line uses the given `style` and the current channels of the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn line_vertical(
+        &mut self,
+        x: u32,
+        beg_y: u32,
+        end_y: u32,
+        glyph: &str,
+        style: impl Into<NcStyle>,
+    ) -> NcResult<()> {
+        let (beg_y, end_y) = if beg_y <= end_y { (beg_y, end_y) } else { (end_y, beg_y) };
+        let channels = self.channels();
+        let mut cell = NcCell::new();
+        NcCell::prime(self, &mut cell, glyph, style, channels)?;
+        let res = self
+            .cursor_move_yx(beg_y, x)
+            .and_then(|_| self.vline(&cell, end_y - beg_y + 1));
+        cell.release(self);
+        res.map(|_| ())
+    }
+
+    /// Draws a diagonal line of `glyph` at 45°, from `beg_y`,`beg_x`
+    /// to `end_y`,`end_x`, both inclusive.
+    ///
+    /// Each step advances one row and one column, so the vertical and
+    /// horizontal distances between both ends must be the same.
+    ///
+    /// The line uses the given `style` and the current channels of the plane.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn line_diagonal(
+        &mut self,
+        beg_y: u32,
+        beg_x: u32,
+        end_y: u32,
+        end_x: u32,
+        glyph: &str,
+        style: impl Into<NcStyle>,
+    ) -> NcResult<()> {
+        let len = beg_y.abs_diff(end_y);
+        if len != beg_x.abs_diff(end_x) {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.line_diagonal({}, {}, {}, {}, {:?}) not at 45°",
+                beg_y, beg_x, end_y, end_x, glyph
+            ]));
+        }
+        let channels = self.channels();
+        let mut cell = NcCell::new();
+        NcCell::prime(self, &mut cell, glyph, style, channels)?;
+        let mut res = Ok(0);
+        for i in 0..=len {
+            let y = if beg_y <= end_y { beg_y + i } else { beg_y - i };
+            let x = if beg_x <= end_x { beg_x + i } else { beg_x - i };
+            res = self.putc_yx(y, x, &cell);
+            if res.is_err() {
+                break;
+            }
+        }
+        cell.release(self);
+        res.map(|_| ())
+    }
+}
+
 // -----------------------------------------------------------------------------
 /// ## NcPlane methods: boxes & perimeters
 impl NcPlane {
@@ -2375,7 +3815,52 @@ impl NcPlane {
         len_x: u32,
         boxmask: impl Into<NcBoxMask>,
     ) -> NcResult<()> {
-        error![c_api::ncplane_double_box(
+        error![c_api::ncplane_double_box_sized(
+            self,
+            stylemask.into().0,
+            channels.into().0,
+            len_y,
+            len_x,
+            boxmask.into()
+        )]
+    }
+
+    /// NcPlane.[`box`][NcPlane#method.box] with the rounded box-drawing characters.
+    ///
+    /// *C style function: [ncplane_rounded_box()][c_api::ncplane_rounded_box].*
+    #[inline]
+    pub fn rounded_box(
+        &mut self,
+        stylemask: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        stop_y: u32,
+        stop_x: u32,
+        boxmask: impl Into<NcBoxMask>,
+    ) -> NcResult<()> {
+        error![c_api::ncplane_rounded_box(
+            self,
+            stylemask.into().0,
+            channels.into().0,
+            stop_y,
+            stop_x,
+            boxmask.into()
+        )]
+    }
+
+    /// NcPlane.[`box_sized`][NcPlane#method.box_sized] with the rounded
+    /// box-drawing characters.
+    ///
+    /// *C style function: [ncplane_rounded_box_sized()][c_api::ncplane_rounded_box_sized].*
+    #[inline]
+    pub fn rounded_box_sized(
+        &mut self,
+        stylemask: impl Into<NcStyle>,
+        channels: impl Into<NcChannels>,
+        len_y: u32,
+        len_x: u32,
+        boxmask: impl Into<NcBoxMask>,
+    ) -> NcResult<()> {
+        error![c_api::ncplane_rounded_box_sized(
             self,
             stylemask.into().0,
             channels.into().0,
@@ -2620,26 +4105,190 @@ impl NcPlane {
 // -----------------------------------------------------------------------------
 /// ## NcPlane methods: other
 impl NcPlane {
-    /// Draws a QR code at the current position on the plane.
-    ///
-    /// A tuple of 3 elements will be returned: `(version, max_y, max_x)`.
+    /// Draws a QR code encoding `data` at the current position on the plane.
     ///
-    /// - The QR code size is (`version` * 4 + 17) columns wide, and
-    /// ⌈`version` * 4 + 17⌉ rows tall.
-    /// - The properly-scaled values are returned as `max_y` and `max_x`.
+    /// Returns the size of the QR code in cells, as `(rows, cols)`.
     ///
-    /// It is an error not to have sufficient room to draw the qrcode.
+    /// It is an error not to have sufficient room to draw the QR code from the
+    /// cursor position, and it's always an error if notcurses was built without
+    /// QR code support (libqrcodegen).
     ///
     /// *C style function: [ncplane_qrcode()][c_api::ncplane_qrcode].*
-    pub fn qrcode(&mut self, data: &mut [u8]) -> NcResult<(u32, u32, u32)> {
-        let (mut max_y, mut max_x) = (0, 0);
-        let len = data.len();
+    pub fn qrcode(&mut self, data: &[u8]) -> NcResult<(u32, u32)> {
+        // the available room, overwritten with the size of the QR code
+        let (mut max_y, mut max_x) = (
+            self.dim_y().saturating_sub(self.cursor_y()),
+            self.dim_x().saturating_sub(self.cursor_x()),
+        );
         let data_ptr = data.as_ptr() as *const c_void;
-        let res = unsafe { c_api::ncplane_qrcode(self, &mut max_y, &mut max_x, data_ptr, len) };
+        let res =
+            unsafe { c_api::ncplane_qrcode(self, &mut max_y, &mut max_x, data_ptr, data.len()) };
         error![
             res,
-            &format!("NcPlane.qrcode(data:{:?})", data),
-            (res as u32, max_y, max_x)
+            &format!(
+                "NcPlane.qrcode({:?}) not enough room, or no QR code support in notcurses",
+                data
+            ),
+            (max_y, max_x)
         ]
     }
+
+    /// Captures the current contents of this plane into a new [`NcVisual`].
+    ///
+    /// Each cell becomes 2×1 pixels, the [cell size][NcBlitter#method.cell_size]
+    /// of the [`NcBlitter::Half`] blitter, so the visual is `2 * dim_y` pixels
+    /// tall and `dim_x` pixels wide:
+    /// - Upper `▀` and lower `▄` half blocks put their foreground color in the
+    ///   corresponding pixel, and their background color in the other one.
+    /// - Empty cells and spaces use their background color for both pixels.
+    /// - Any other glyph uses its foreground color for both pixels.
+    ///
+    /// Default colors are captured as black for the background and white for
+    /// the foreground, and transparent colors as transparent pixels.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn screenshot<'a>(&mut self) -> NcResult<&'a mut NcVisual> {
+        let (rgba, rows, cols) = self.screenshot_rgba()?;
+        NcVisual::from_rgba(&rgba, rows, cols * 4, cols)
+    }
+
+    /// Encodes the current contents of this plane as a Sixel escape sequence,
+    /// from `ESC P` to the string terminator `ESC \\`.
+    ///
+    /// The image is captured like in [`screenshot`][NcPlane#method.screenshot],
+    /// and the transparent pixels are left undrawn. If there are more than
+    /// 256 different colors, they are reduced to a 6×6×6 color cube.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_sixel_string(&mut self) -> NcResult<String> {
+        let (rgba, rows, cols) = self.screenshot_rgba()?;
+        Ok(rgba_to_sixel(&rgba, rows, cols))
+    }
+
+    /// Returns the RGBA pixels of a [`screenshot`][NcPlane#method.screenshot],
+    /// with its height and width.
+    fn screenshot_rgba(&mut self) -> NcResult<(Vec<u8>, u32, u32)> {
+        let pixel = |rgb: NcRgb, alpha: NcAlpha| {
+            let [r, g, b]: [u8; 3] = rgb.into();
+            [
+                r,
+                g,
+                b,
+                if alpha == NcAlpha::Transparent { 0 } else { 0xFF },
+            ]
+        };
+
+        let (rows, cols) = self.dim_yx();
+        let stride = cols as usize * 4;
+        let mut rgba = vec![0_u8; rows as usize * 2 * stride];
+        let mut cell = NcCell::new();
+
+        for y in 0..rows {
+            for x in 0..cols {
+                self.at_yx_cell(y, x, &mut cell)?;
+                let fg_rgb = if cell.fg_default_p() { NcRgb(0xFFFFFF) } else { cell.fg_rgb() };
+                let bg_rgb = if cell.bg_default_p() { NcRgb(0x000000) } else { cell.bg_rgb() };
+                let fg = pixel(fg_rgb, cell.fg_alpha());
+                let bg = pixel(bg_rgb, cell.bg_alpha());
+                let (top, bottom) = match cell.egc(self) {
+                    "▀" => (fg, bg),
+                    "▄" => (bg, fg),
+                    "" | " " => (bg, bg),
+                    _ => (fg, fg),
+                };
+                cell.release(self);
+
+                let i = y as usize * 2 * stride + x as usize * 4;
+                rgba[i..i + 4].copy_from_slice(&top);
+                rgba[i + stride..i + stride + 4].copy_from_slice(&bottom);
+            }
+        }
+        Ok((rgba, rows * 2, cols))
+    }
+}
+
+// -----------------------------------------------------------------------------
+/// ## NcPlane methods: user data
+///
+/// The user pointer of the plane is managed by these methods, and shared with
+/// the [`resize_fn`] closure. A user pointer set with the C API function
+/// [`ncplane_set_userptr`] is ignored by the getters, and replaced without
+/// being freed by the setters. Such a user pointer must be null or point to
+/// at least `size_of::<usize>()` readable bytes, which are read to tell it
+/// apart. [`ncplane_userptr`] must not be used to modify the data stored by
+/// these methods.
+///
+/// [`resize_fn`]: crate::NcPlaneOptionsBuilder#method.resize_fn
+/// [`ncplane_set_userptr`]: c_api::ncplane_set_userptr
+/// [`ncplane_userptr`]: c_api::ncplane_userptr
+impl NcPlane {
+    /// Stores `data` in this plane, replacing and dropping any previous data.
+    ///
+    /// The data is dropped when the plane is [`destroy`]ed.
+    ///
+    /// It's recommended to always store the same type `T` in a given plane,
+    /// since retrieving it as a different type will return `None`.
+    ///
+    /// *C style function: [ncplane_set_userptr()][c_api::ncplane_set_userptr].*
+    ///
+    /// [`destroy`]: NcPlane#method.destroy
+    pub fn set_userptr<T: 'static>(&mut self, data: Box<T>) {
+        let data: Box<dyn Any> = data;
+        unsafe { NcPlaneUserData::get_or_insert(self) }.data = Some(data);
+    }
+
+    /// Returns a reference to the data stored in this plane, or `None` if
+    /// there's no data, or if it's not of type `T`.
+    ///
+    /// *C style function: [ncplane_userptr()][c_api::ncplane_userptr].*
+    pub fn userptr<T: 'static>(&self) -> Option<&T> {
+        NcPlaneUserData::get_ref(self)?
+            .data
+            .as_ref()?
+            .downcast_ref::<T>()
+    }
+
+    /// Returns a mutable reference to the data stored in this plane, or `None`
+    /// if there's no data, or if it's not of type `T`.
+    ///
+    /// *C style function: [ncplane_userptr()][c_api::ncplane_userptr].*
+    pub fn userptr_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        unsafe { NcPlaneUserData::get(self) }?
+            .data
+            .as_mut()?
+            .downcast_mut::<T>()
+    }
+
+    /// Stores `data` in this plane, replacing and dropping any previous data.
+    ///
+    /// This is the same as [`set_userptr`], but boxes the data itself.
+    ///
+    /// *C style function: [ncplane_set_userptr()][c_api::ncplane_set_userptr].*
+    ///
+    /// [`set_userptr`]: NcPlane#method.set_userptr
+    pub fn set_user_data<T: 'static>(&mut self, data: T) {
+        self.set_userptr(Box::new(data));
+    }
+
+    /// Returns a reference to the data stored in this plane, or `None` if
+    /// there's no data, or if it's not of type `T`.
+    ///
+    /// This is the same as [`userptr`].
+    ///
+    /// *C style function: [ncplane_userptr()][c_api::ncplane_userptr].*
+    ///
+    /// [`userptr`]: NcPlane#method.userptr
+    pub fn user_data<T: 'static>(&self) -> Option<&T> {
+        self.userptr()
+    }
+
+    /// Drops the data stored in this plane, if any.
+    ///
+    /// *C style function: [ncplane_set_userptr()][c_api::ncplane_set_userptr].*
+    pub fn drop_user_data(&mut self) {
+        if let Some(userdata) = unsafe { NcPlaneUserData::get(self) } {
+            userdata.data = None;
+        }
+        unsafe { NcPlaneUserData::drop_if_empty(self) };
+    }
 }
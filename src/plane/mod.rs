@@ -1,9 +1,9 @@
 //! `NcPlane`
 
-// functions already exported by bindgen : 100
+// functions already exported by bindgen : 102
 // -------------------------------------------
-// (#) test: 9
-// (W) wrap: 91
+// (#) test: 15
+// (W) wrap: 97
 // -------------------------------------------
 //W  ncpile_bottom
 //W# ncpile_create
@@ -21,7 +21,7 @@
 //W  ncplane_at_cursor_cell
 //W  ncplane_at_yx
 //W  ncplane_at_yx_cell
-//W  ncplane_autogrow_p
+//W# ncplane_autogrow_p
 //W  ncplane_base
 //W  ncplane_below
 //W  ncplane_box
@@ -79,28 +79,30 @@
 //W  ncplane_scrolling_p
 //W  ncplane_scrollup,
 //W  ncplane_scrollup_child,
-//W  ncplane_set_autogrow
+//W# ncplane_set_autogrow
 //W  ncplane_set_base
 //W  ncplane_set_base_cell
 //W  ncplane_set_bg_alpha
 //W  ncplane_set_bg_default
 //W  ncplane_set_bg_palindex
 //W  ncplane_set_bg_rgb
-//   ncplane_set_bg_rgb8           // unneeded method
+//W# ncplane_set_bg_rgb8
+//W# ncplane_set_bg_rgb8_clipped
 //W  ncplane_set_fg_alpha
 //W  ncplane_set_fg_default
 //W  ncplane_set_fg_palindex
 //W  ncplane_set_fg_rgb
-//   ncplane_set_fg_rgb8           // unneeded method
+//W# ncplane_set_fg_rgb8
+//W# ncplane_set_fg_rgb8_clipped
 //W  ncplane_set_resizecb
 //W  ncplane_set_scrolling
 //W  ncplane_set_styles
-//   ncplane_set_userptr
+//W  ncplane_set_userptr
 //W  ncplane_stain
 //W  ncplane_styles
 //W  ncplane_translate
 //W  ncplane_translate_abs
-//   ncplane_userptr
+//W  ncplane_userptr
 //   ncplane_vline_interp
 //W  ncplane_x
 //W  ncplane_y
@@ -109,10 +111,10 @@
 // functions manually reimplemented: 49
 // ------------------------------------------
 // (+) done: 49
-// (W) wrap: 45
-// (#) test:  9
+// (W) wrap: 49
+// (#) test: 12
 // ------------------------------------------
-//W+ ncplane_ascii_box WIP
+//W# ncplane_ascii_box
 //W+ ncplane_bchannel
 //W+ ncplane_bg_alpha
 //W# ncplane_bg_default_p
@@ -122,7 +124,7 @@
 //W# ncplane_channels
 //W# ncplane_dim_x
 //W# ncplane_dim_y
-//W+ ncplane_double_box
+//W# ncplane_double_box
 //W+ ncplane_double_box_sized
 //W+ ncplane_fchannel
 //W+ ncplane_fg_alpha
@@ -132,7 +134,7 @@
 //W+ ncplane_gradient
 //W+ ncplane_gradient_sized
 //W+ ncplane_halign
-// + ncplane_hline
+//W+ ncplane_hline
 //W+ ncplane_moverel
 //W+ ncplane_move_bottom
 //W+ ncplane_move_family_bottom
@@ -154,13 +156,13 @@
 //W+ ncplane_putstr_stained
 //W+ ncplane_putstr_yx
 //W# ncplane_resize_simple
-// + ncplane_rounded_box
-// + ncplane_rounded_box_sized
+//W# ncplane_rounded_box
+//W+ ncplane_rounded_box_sized
 //W# ncplane_set_bchannel
 //W# ncplane_set_channels
 //W# ncplane_set_fchannel
 //W+ ncplane_valign
-// + ncplane_vline
+//W+ ncplane_vline
 
 // wont implement:
 // ----------------
@@ -168,8 +170,6 @@
 //   ncplane_putegc_yx
 //   ncplane_putwegc_stained
 //   ncplane_putwstr_stained
-//   ncplane_set_bg_rgb8_clipped
-//   ncplane_set_fg_rgb8_clipped
 //   ncplane_vprintf_aligned
 //   ncplane_vprintf_stained
 //   ncplane_vprintf_yx
@@ -185,14 +185,34 @@
 //   ncplane_putwstr_yx
 //   ncplane_vprintf
 
+#[cfg(feature = "animation")]
+mod animation;
+mod cell_grid;
+mod diff;
 pub(crate) mod helpers;
+#[cfg(feature = "regex")]
+mod highlight;
+mod html;
+mod input_mode;
+mod log;
 mod methods;
 pub(crate) mod options;
 pub(crate) mod reimplemented;
+mod replace;
+mod scrollback;
+mod span;
 #[cfg(test)]
 pub(crate) mod test;
+pub(crate) mod userdata;
 
+#[cfg(feature = "animation")]
+pub use animation::NcAnimation;
+pub use diff::{NcCellDiff, NcPlaneSnapshot};
+pub use input_mode::{NcCursorShape, NcTextInputMode};
+pub use log::NcLogPlane;
 pub use options::{NcPlaneFlag, NcPlaneOptions, NcPlaneOptionsBuilder};
+pub use scrollback::NcPlaneRow;
+pub use span::NcSpan;
 
 // NcPlane
 //
@@ -1,8 +1,20 @@
 //!
 
-use core::ptr::{null, null_mut};
-
-use crate::{c_api, NcAlign, NcPlaneFlag, NcPlaneOptions, NcResizeCb};
+use core::{
+    cell::RefCell,
+    ptr::{null, null_mut},
+};
+
+use crate::{
+    c_api,
+    plane::userdata::{ncresizefn_trampoline, NcPlaneUserData, NcResizeFnShared},
+    NcAlign, NcPlane, NcPlaneFlag, NcPlaneOptions, NcResizeCb, NcResult,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc;
+#[cfg(feature = "std")]
+use std::rc::Rc;
 
 /// Builder object for [`NcPlaneOptions`].
 ///
@@ -27,6 +39,7 @@ pub struct NcPlaneOptionsBuilder {
     // A ‘name’ can be set, used in debugging.
     // pub(crate) name: String,
     pub(crate) resizecb: Option<NcResizeCb>,
+    pub(crate) resize_fn: Option<NcResizeFnShared>,
     pub(crate) flags: u64,
     pub(crate) margin_b: u32,
     pub(crate) margin_r: u32,
@@ -84,6 +97,7 @@ mod core_impls {
                 rows: 0,
                 cols: 0,
                 resizecb: None,
+                resize_fn: None,
                 flags: NcPlaneFlag::Marginalized.into(),
                 margin_b: 0,
                 margin_r: 0,
@@ -95,12 +109,15 @@ mod core_impls {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             let resizecb_str =
                 if self.resizecb.is_some() { String::from("Some") } else { String::from("None") };
+            let resize_fn_str =
+                if self.resize_fn.is_some() { String::from("Some") } else { String::from("None") };
             f.debug_struct("NcPlaneOptionsBuilder")
                 .field("y", &self.y)
                 .field("x", &self.x)
                 .field("rows", &self.rows)
                 .field("cols", &self.cols)
                 .field("resizecb", &resizecb_str)
+                .field("resize_fn", &resize_fn_str)
                 .field("flags", &self.flags)
                 .field("margin_b", &self.margin_b)
                 .field("margin_r", &self.margin_r)
@@ -132,8 +149,13 @@ impl NcPlaneOptionsBuilder {
             builder = builder.fixed(true);
         }
 
-        if options.resizecb.is_some() {
-            builder = builder.resizecb(c_api::ncresizecb_to_rust(options.resizecb));
+        if let Some(cb) = options.resizecb {
+            if cb as usize == ncresizefn_trampoline as c_api::NcResizeCbUnsafe as usize {
+                // the user pointer was set by a builder with a resize closure
+                builder.resize_fn = unsafe { NcPlaneUserData::options_resize_fn(options.userptr) };
+            } else {
+                builder = builder.resizecb(c_api::ncresizecb_to_rust(options.resizecb));
+            }
         }
 
         if options.is_autogrow() {
@@ -160,15 +182,31 @@ impl NcPlaneOptionsBuilder {
     }
 
     /// Finishes the building and returns [`NcPlaneOptions`].
+    ///
+    /// If a [`resize_fn`] closure was set, the returned options own a handle
+    /// to it through their `userptr`, which is released when they are dropped.
+    /// Every plane created from them gets its own handle, released when the
+    /// plane is [`destroy`]ed.
+    ///
+    /// [`resize_fn`]: NcPlaneOptionsBuilder#method.resize_fn
+    /// [`destroy`]: crate::NcPlane#method.destroy
     pub fn build(self) -> NcPlaneOptions {
+        let (userptr, resizecb) = match self.resize_fn {
+            Some(resize_fn) => (
+                NcPlaneUserData { resize_fn: Some(resize_fn), ..Default::default() }
+                    .into_options_userptr(),
+                Some(ncresizefn_trampoline as c_api::NcResizeCbUnsafe),
+            ),
+            None => (null_mut(), c_api::ncresizecb_to_c(self.resizecb)),
+        };
         NcPlaneOptions {
             y: self.y,
             x: self.x,
             rows: self.rows,
             cols: self.cols,
-            userptr: null_mut(), // TODO
-            name: null(),        // TODO
-            resizecb: c_api::ncresizecb_to_c(self.resizecb),
+            userptr,
+            name: null(), // TODO
+            resizecb,
             flags: self.flags,
             margin_b: self.margin_b,
             margin_r: self.margin_r,
@@ -388,8 +426,32 @@ impl NcPlaneOptionsBuilder {
     /// (Un)Sets the resize callback.
     ///
     /// Default: *None*.
+    ///
+    /// Effect: unsets the [`resize_fn`] closure.
+    ///
+    /// [`resize_fn`]: NcPlaneOptionsBuilder#method.resize_fn
     pub fn resizecb(mut self, callback: Option<NcResizeCb>) -> Self {
         self.resizecb = callback;
+        self.resize_fn = None;
+        self
+    }
+
+    /// Sets a closure to be called when the parent plane is resized.
+    ///
+    /// The closure is stored in the plane's user pointer, and it's dropped
+    /// when the plane is [`destroy`]ed.
+    ///
+    /// Default: *None*.
+    ///
+    /// Effect: unsets the [`resizecb`] callback.
+    ///
+    /// [`destroy`]: crate::NcPlane#method.destroy
+    /// [`resizecb`]: NcPlaneOptionsBuilder#method.resizecb
+    pub fn resize_fn(
+        mut self,
+        closure: impl FnMut(&mut NcPlane) -> NcResult<()> + 'static,
+    ) -> Self {
+        self.set_resize_fn(closure);
         self
     }
 }
@@ -593,7 +655,29 @@ impl NcPlaneOptionsBuilder {
     /// (Un)Sets the resize callback.
     ///
     /// Default: *None*.
+    ///
+    /// Effect: unsets the [`resize_fn`] closure.
+    ///
+    /// [`resize_fn`]: NcPlaneOptionsBuilder#method.resize_fn
     pub fn set_resizecb(&mut self, callback: Option<NcResizeCb>) {
         self.resizecb = callback;
+        self.resize_fn = None;
+    }
+
+    /// Sets a closure to be called when the parent plane is resized.
+    ///
+    /// The closure is stored in the plane's user pointer, and it's dropped
+    /// when the plane is [`destroy`]ed.
+    ///
+    /// Default: *None*.
+    ///
+    /// Effect: unsets the [`resizecb`] callback.
+    ///
+    /// [`destroy`]: crate::NcPlane#method.destroy
+    /// [`resizecb`]: NcPlaneOptionsBuilder#method.resizecb
+    pub fn set_resize_fn(&mut self, closure: impl FnMut(&mut NcPlane) -> NcResult<()> + 'static) {
+        let closure: NcResizeFnShared = Rc::new(RefCell::new(closure));
+        self.resize_fn = Some(closure);
+        self.resizecb = None;
     }
 }
@@ -1,6 +1,6 @@
 //!
 
-use crate::{c_api::ffi, NcAlign, NcResizeCb};
+use crate::{c_api::ffi, plane::userdata::NcPlaneUserData, NcAlign, NcResizeCb};
 use core::ptr::{null, null_mut};
 
 mod builder;
@@ -115,6 +115,15 @@ impl NcPlaneOptions {
     }
 }
 
+impl Drop for NcPlaneOptions {
+    /// Releases the [`resize_fn`] closure set by the builder, if any.
+    ///
+    /// [`resize_fn`]: NcPlaneOptionsBuilder#method.resize_fn
+    fn drop(&mut self) {
+        unsafe { NcPlaneUserData::drop_options_userptr(self.userptr) };
+    }
+}
+
 /// # Methods
 impl NcPlaneOptions {
     /// Returns `true` if it has the [`VerAligned`] flag set.
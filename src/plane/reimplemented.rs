@@ -113,7 +113,7 @@ pub fn ncplane_set_channels(plane: &mut NcPlane, channels: impl Into<NcChannels_
 /// Gets the foreground RGB components from an [`NcPlane`].
 /// and returns the background [`NcChannel_u32`].
 ///
-/// *Method: NcPlane.[fg_rgb()][NcPlane#method.fg_rgb].*
+/// *Method: NcPlane.[fg_rgb8()][NcPlane#method.fg_rgb8].*
 #[inline]
 pub fn ncplane_fg_rgb8(
     plane: &NcPlane,
@@ -127,7 +127,7 @@ pub fn ncplane_fg_rgb8(
 /// Gets the background RGB componentss from an [`NcPlane`],
 /// and returns the background [`NcChannel_u32`].
 ///
-/// *Method: NcPlane.[bg_rgb()][NcPlane#method.bg_rgb].*
+/// *Method: NcPlane.[bg_rgb8()][NcPlane#method.bg_rgb8].*
 #[inline]
 pub fn ncplane_bg_rgb8(
     plane: &NcPlane,
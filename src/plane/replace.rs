@@ -0,0 +1,165 @@
+//! `NcPlane` search and replace of text.
+
+use super::scrollback::NcPlaneRow;
+use crate::{egc::egc_len, NcPlane, NcResult};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// # NcPlane methods: search and replace
+impl NcPlane {
+    /// Replaces all the occurrences of `pattern` in the text content of the
+    /// plane with `replacement`.
+    ///
+    /// Each row is searched separately, as returned by
+    /// [`row_as_string`][NcPlane#method.row_as_string], so a match can't span
+    /// two rows. The cells that follow a match in its row are shifted to the
+    /// left or to the right when the `replacement` is shorter or longer, and
+    /// the glyphs shifted past the last column are lost.
+    ///
+    /// The replacement glyphs take the styles and channels of the first cell
+    /// of the match. An empty `pattern` doesn't match anything.
+    ///
+    /// Returns the number of replaced matches.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn replace_all(&mut self, pattern: &str, replacement: &str) -> NcResult<usize> {
+        if pattern.is_empty() {
+            return Ok(0);
+        }
+        self.replace_matches(usize::MAX, |text| {
+            text.match_indices(pattern)
+                .map(|(i, m)| (i, i + m.len(), String::from(replacement)))
+                .collect()
+        })
+    }
+
+    /// Replaces the first occurrence of `pattern` in the text content of the
+    /// plane, in row-major order, with `replacement`.
+    ///
+    /// See [`replace_all`][NcPlane#method.replace_all].
+    ///
+    /// Returns `true` if a match was replaced.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn replace_first(&mut self, pattern: &str, replacement: &str) -> NcResult<bool> {
+        if pattern.is_empty() {
+            return Ok(false);
+        }
+        let count = self.replace_matches(1, |text| {
+            text.match_indices(pattern)
+                .map(|(i, m)| (i, i + m.len(), String::from(replacement)))
+                .collect()
+        })?;
+        Ok(count > 0)
+    }
+
+    /// Replaces all the matches of the regular expression `pattern` in the
+    /// text content of the plane with `replacement`, which can refer to the
+    /// capture groups of each match, like `$1` or `${name}`.
+    ///
+    /// See [`replace_all`][NcPlane#method.replace_all]. Empty matches are
+    /// ignored.
+    ///
+    /// Returns the number of replaced matches.
+    ///
+    /// *(No equivalent C style function)*
+    #[cfg(feature = "regex")]
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "regex")))]
+    pub fn search_and_replace_regex(
+        &mut self,
+        pattern: &str,
+        replacement: &str,
+    ) -> NcResult<usize> {
+        let regex = regex::Regex::new(pattern).map_err(|e| {
+            crate::NcError::new_msg(&format![
+                "NcPlane.search_and_replace_regex({:?}) {}",
+                pattern, e
+            ])
+        })?;
+        self.replace_matches(usize::MAX, |text| {
+            regex
+                .captures_iter(text)
+                .map(|caps| {
+                    let m = caps.get(0).unwrap();
+                    let mut expanded = String::new();
+                    caps.expand(replacement, &mut expanded);
+                    (m.start(), m.end(), expanded)
+                })
+                .collect()
+        })
+    }
+}
+
+/// # NcPlane search and replace internals
+impl NcPlane {
+    /// Replaces up to `max` matches, row by row.
+    ///
+    /// `find` returns the non-overlapping matches in the text of a row, as the
+    /// start and end byte offsets and the replacement. The empty matches, and
+    /// the ones that don't start and end at a glyph boundary, are skipped.
+    fn replace_matches(
+        &mut self,
+        max: usize,
+        mut find: impl FnMut(&str) -> Vec<(usize, usize, String)>,
+    ) -> NcResult<usize> {
+        let mut count = 0;
+        for y in 0..self.dim_y() {
+            if count == max {
+                break;
+            }
+            let row = self.row_snapshot(y)?;
+
+            // the text of the row, and the byte offset of each of its cells,
+            // without the trailing empty cells, like in row_as_string
+            let mut text = String::new();
+            let mut offsets = Vec::with_capacity(row.cells.len() + 1);
+            let mut used = 0;
+            for (i, (egc, _, _)) in row.cells.iter().enumerate() {
+                offsets.push(text.len());
+                text.push_str(if egc.is_empty() { " " } else { egc });
+                if !egc.is_empty() {
+                    used = i + 1;
+                }
+            }
+            offsets.push(text.len());
+            text.truncate(offsets[used]);
+            offsets.truncate(used + 1);
+
+            let mut cells = Vec::with_capacity(row.cells.len());
+            let mut next_cell = 0;
+            for (start, end, replacement) in find(&text) {
+                if count == max {
+                    break;
+                }
+                let first = match offsets.binary_search(&start) {
+                    Ok(first) if start < end && first >= next_cell => first,
+                    _ => continue,
+                };
+                let last = match offsets.binary_search(&end) {
+                    Ok(last) => last,
+                    Err(_) => continue,
+                };
+
+                cells.extend_from_slice(&row.cells[next_cell..first]);
+                let (_, styles, channels) = row.cells[first];
+                let mut rest = replacement.as_str();
+                while !rest.is_empty() {
+                    let len = egc_len(rest);
+                    cells.push((String::from(&rest[..len]), styles, channels));
+                    rest = &rest[len..];
+                }
+                next_cell = last;
+                count += 1;
+            }
+            if next_cell == 0 {
+                continue;
+            }
+            cells.extend_from_slice(&row.cells[next_cell..]);
+
+            self.erase_region(Some(y), Some(0), 1, 0)?;
+            self.put_row(y, &NcPlaneRow { cells })?;
+        }
+        Ok(count)
+    }
+}
@@ -0,0 +1,465 @@
+//! `NcPlane` scrollback buffer, and `NcPlaneRow`.
+
+use super::userdata::NcPlaneUserData;
+use crate::{
+    c_api,
+    egc::{egc_len, str_width},
+    NcCell, NcChannels, NcError, NcPlane, NcResult, NcStyle,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// A snapshot of a row of an [`NcPlane`], that owns its glyphs.
+///
+/// Created when a line scrolls off a plane with a
+/// [scrollback buffer][NcPlane#method.set_scrollback_size].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcPlaneRow {
+    /// The `EGC`s of the row, with their styles and channels.
+    ///
+    /// Wide glyphs are included once, and empty cells have an empty `EGC`.
+    pub cells: Vec<(String, NcStyle, NcChannels)>,
+}
+
+impl NcPlaneRow {
+    /// Returns the text of the row.
+    ///
+    /// Empty cells are represented by spaces, and the trailing ones are omitted,
+    /// like in [`row_as_string`][NcPlane#method.row_as_string].
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        let mut len = 0;
+        for (egc, _, _) in &self.cells {
+            if egc.is_empty() {
+                text.push(' ');
+            } else {
+                text.push_str(egc);
+                len = text.len();
+            }
+        }
+        text.truncate(len);
+        text
+    }
+}
+
+/// The scrollback buffer of an [`NcPlane`], stored in its user data.
+#[derive(Debug, Default)]
+pub(crate) struct NcPlaneScrollback {
+    /// The lines scrolled off the plane, from the oldest to the newest.
+    lines: VecDeque<NcPlaneRow>,
+    /// The maximum number of lines.
+    capacity: usize,
+    /// The number of lines the view is scrolled back.
+    offset: usize,
+    /// The live rows and cursor position, saved while scrolled back.
+    live: Option<(Vec<NcPlaneRow>, (u32, u32))>,
+}
+
+/// # NcPlane methods: scrollback
+impl NcPlane {
+    /// Sets the size of the scrollback buffer, which keeps up to `lines` of
+    /// the lines scrolled off the top of this plane.
+    ///
+    /// The lines are recorded when any of the `put*` methods, [`puttext`] or
+    /// [`scrollup`] make the plane scroll, and only if
+    /// [scrolling][NcPlane#method.set_scrolling] is enabled and
+    /// [autogrow][NcPlane#method.set_autogrow] is not. Writing with the C API
+    /// functions bypasses the buffer.
+    ///
+    /// Shrinking the buffer discards the oldest lines, and a size of 0
+    /// removes it, returning to the live view.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`puttext`]: NcPlane#method.puttext
+    /// [`scrollup`]: NcPlane#method.scrollup
+    pub fn set_scrollback_size(&mut self, lines: usize) {
+        if lines == 0 {
+            let _ = self.scroll_to_line(0);
+            if let Some(userdata) = unsafe { NcPlaneUserData::get(self) } {
+                userdata.scrollback = None;
+            }
+            unsafe { NcPlaneUserData::drop_if_empty(self) };
+            return;
+        }
+        let scrollback = unsafe { NcPlaneUserData::get_or_insert(self) }
+            .scrollback
+            .get_or_insert_with(Default::default);
+        scrollback.capacity = lines;
+        while scrollback.lines.len() > lines {
+            scrollback.lines.pop_front();
+        }
+        scrollback.offset = scrollback.offset.min(scrollback.lines.len());
+    }
+
+    /// Returns the number of lines in the scrollback buffer.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn scrollback_len(&self) -> usize {
+        self.scrollback().map_or(0, |sb| sb.lines.len())
+    }
+
+    /// Returns the line of the scrollback buffer `offset` lines back from the
+    /// most recent one, or `None` if there's no such line.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn scrollback_line(&self, offset: usize) -> Option<NcPlaneRow> {
+        let lines = &self.scrollback()?.lines;
+        lines
+            .len()
+            .checked_sub(offset + 1)
+            .and_then(|i| lines.get(i))
+            .cloned()
+    }
+
+    /// Shows the content of the plane scrolled back `offset` lines into the
+    /// scrollback buffer, or the live content if `offset` is 0.
+    ///
+    /// The `offset` is limited to the number of lines in the buffer.
+    /// The live content and the cursor position are restored when returning
+    /// to 0, which the `put*` methods, [`puttext`] and [`scrollup`] do
+    /// before writing.
+    ///
+    /// It is an error if the plane doesn't have a scrollback buffer.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`puttext`]: NcPlane#method.puttext
+    /// [`scrollup`]: NcPlane#method.scrollup
+    pub fn scroll_to_line(&mut self, offset: usize) -> NcResult<()> {
+        let (len, current) = match self.scrollback() {
+            Some(sb) => (sb.lines.len(), sb.offset),
+            None => {
+                return Err(NcError::new_msg(&format![
+                    "NcPlane.scroll_to_line({}): no scrollback buffer",
+                    offset
+                ]))
+            }
+        };
+        let offset = offset.min(len);
+        if offset == current {
+            return Ok(());
+        }
+
+        // save the live content the first time it's scrolled back
+        if current == 0 {
+            let live = (
+                (0..self.dim_y())
+                    .map(|y| self.row_snapshot(y))
+                    .collect::<NcResult<Vec<_>>>()?,
+                self.cursor_yx(),
+            );
+            self.scrollback_mut().unwrap().live = Some(live);
+        }
+
+        let sb = unsafe { NcPlaneUserData::get(self) }
+            .unwrap()
+            .scrollback
+            .as_mut()
+            .unwrap();
+        sb.offset = offset;
+        let rows: Vec<NcPlaneRow> = if offset == 0 {
+            let (rows, _) = sb.live.as_ref().unwrap();
+            rows.clone()
+        } else {
+            let (live, _) = sb.live.as_ref().unwrap();
+            sb.lines
+                .iter()
+                .skip(len - offset)
+                .chain(live.iter())
+                .take(self.dim_y() as usize)
+                .cloned()
+                .collect()
+        };
+        self.draw_rows(&rows)?;
+
+        if offset == 0 {
+            let (_, (y, x)) = self.scrollback_mut().unwrap().live.take().unwrap();
+            self.cursor_move_yx(y, x)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of lines the plane is scrolled back into the
+    /// scrollback buffer, or 0 if it shows the live content.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn scroll_offset(&self) -> usize {
+        self.scrollback().map_or(0, |sb| sb.offset)
+    }
+
+    /// Scrolls the view of the plane `delta` lines, back into the scrollback
+    /// buffer if it's negative, or towards the live content if it's positive.
+    ///
+    /// The view stops at the oldest line of the buffer and at the live
+    /// content. See [`scroll_to_line`][NcPlane#method.scroll_to_line].
+    ///
+    /// It is an error if the plane doesn't have a scrollback buffer.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn view_scroll(&mut self, delta: i32) -> NcResult<()> {
+        let current = match self.scrollback() {
+            Some(sb) => sb.offset,
+            None => {
+                return Err(NcError::new_msg(&format![
+                    "NcPlane.view_scroll({}): no scrollback buffer",
+                    delta
+                ]))
+            }
+        };
+        let offset = if delta < 0 {
+            current.saturating_add(delta.unsigned_abs() as usize)
+        } else {
+            current.saturating_sub(delta as usize)
+        };
+        self.scroll_to_line(offset)
+    }
+
+    /// Scrolls the view of the plane back to the oldest line of the
+    /// scrollback buffer.
+    ///
+    /// It is an error if the plane doesn't have a scrollback buffer.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn view_scroll_to_top(&mut self) -> NcResult<()> {
+        let len = self.scrollback_len();
+        self.scroll_to_line(len)
+    }
+
+    /// Scrolls the view of the plane to the live content.
+    ///
+    /// It is an error if the plane doesn't have a scrollback buffer.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn view_scroll_to_bottom(&mut self) -> NcResult<()> {
+        self.scroll_to_line(0)
+    }
+}
+
+/// # NcPlane scrollback internals
+impl NcPlane {
+    fn scrollback(&self) -> Option<&NcPlaneScrollback> {
+        NcPlaneUserData::get_ref(self)?.scrollback.as_ref()
+    }
+
+    fn scrollback_mut(&mut self) -> Option<&mut NcPlaneScrollback> {
+        unsafe { NcPlaneUserData::get(self) }?.scrollback.as_mut()
+    }
+
+    /// Returns true if the lines scrolled off this plane must be recorded.
+    pub(crate) fn scrollback_recording(&self) -> bool {
+        self.scrollback().is_some() && self.scrolling_p() && !self.autogrow_p()
+    }
+
+    /// Returns to the live view, if the plane is scrolled back.
+    pub(crate) fn scrollback_restore(&mut self) -> NcResult<()> {
+        if self.scroll_offset() > 0 {
+            self.scroll_to_line(0)?;
+        }
+        Ok(())
+    }
+
+    /// Records `rows` in the scrollback buffer, discarding the oldest lines.
+    fn scrollback_push(&mut self, rows: Vec<NcPlaneRow>) {
+        if let Some(sb) = self.scrollback_mut() {
+            sb.lines.extend(rows);
+            while sb.lines.len() > sb.capacity {
+                sb.lines.pop_front();
+            }
+        }
+    }
+
+    /// Records the first `rows` rows of the plane in the scrollback buffer,
+    /// before they scroll off.
+    fn scrollback_record(&mut self, rows: u32) -> NcResult<()> {
+        let rows = (0..rows.min(self.dim_y()))
+            .map(|y| self.row_snapshot(y))
+            .collect::<NcResult<Vec<_>>>()?;
+        self.scrollback_push(rows);
+        Ok(())
+    }
+
+    /// Scrolls up `n` lines with `scroll`, returning to the live view first,
+    /// and recording the lines scrolled off.
+    pub(crate) fn scrollback_scroll(
+        &mut self,
+        n: u32,
+        scroll: impl FnOnce(&mut NcPlane) -> NcResult<u32>,
+    ) -> NcResult<u32> {
+        self.scrollback_restore()?;
+        if self.scrollback_recording() {
+            self.scrollback_record(n)?;
+        }
+        scroll(self)
+    }
+
+    /// Writes `text` with `put`, at `y`×`x` if given, returning to the live
+    /// view first, and recording the lines scrolled off.
+    ///
+    /// All the `put*` methods write through here. When recording, the cursor
+    /// is moved to `y`×`x` first, and `put` is called at the cursor with
+    /// pieces of `text` that can only make the plane scroll before their
+    /// first glyph, so that the top row is recorded before. Otherwise `put`
+    /// is called once, with `y`, `x` and the whole `text`.
+    pub(crate) fn scrollback_put(
+        &mut self,
+        y: Option<u32>,
+        x: Option<u32>,
+        text: &str,
+        mut put: impl FnMut(&mut NcPlane, Option<u32>, Option<u32>, &str) -> NcResult<u32>,
+    ) -> NcResult<u32> {
+        self.scrollback_restore()?;
+        if !self.scrollback_recording() || text.is_empty() {
+            return put(self, y, x, text);
+        }
+        if y.is_some() || x.is_some() {
+            let (cursor_y, cursor_x) = self.cursor_yx();
+            self.cursor_move_yx(y.unwrap_or(cursor_y), x.unwrap_or(cursor_x))?;
+        }
+
+        let mut cols = 0;
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                if self.cursor_y() + 1 >= self.dim_y() {
+                    self.scrollback_record(1)?;
+                }
+                cols += put(self, None, None, "\n")?;
+            }
+            let mut rest = line;
+            while !rest.is_empty() {
+                let room = self.dim_x().saturating_sub(self.cursor_x());
+                let mut split = str_prefix_fitting(rest, room);
+                if split == 0 {
+                    // the next glyph wraps to the next line
+                    if self.cursor_y() + 1 >= self.dim_y() {
+                        self.scrollback_record(1)?;
+                    }
+                    // a glyph wider than the plane is written alone, and fails
+                    split = str_prefix_fitting(rest, self.dim_x()).max(egc_len(rest));
+                }
+                cols += put(self, None, None, &rest[..split])?;
+                rest = &rest[split..];
+            }
+        }
+        Ok(cols)
+    }
+
+    /// Writes with `put`, returning to the live view first, and recording the
+    /// lines scrolled off.
+    ///
+    /// It's for writes that lay out the text themselves, like `puttext`.
+    /// When recording, `put` is called first on a scratch copy of the plane
+    /// that grows instead of scrolling, to find out the lines scrolled off.
+    pub(crate) fn scrollback_put_laid_out(
+        &mut self,
+        mut put: impl FnMut(&mut NcPlane) -> NcResult<u32>,
+    ) -> NcResult<u32> {
+        self.scrollback_restore()?;
+        if self.scrollback_recording() {
+            let rows = self.scrolled_off_by(&mut put)?;
+            self.scrollback_push(rows);
+        }
+        put(self)
+    }
+
+    /// Returns the rows that writing with `put` would scroll off this plane,
+    /// without changing it.
+    fn scrolled_off_by(
+        &mut self,
+        put: impl FnOnce(&mut NcPlane) -> NcResult<u32>,
+    ) -> NcResult<Vec<NcPlaneRow>> {
+        let (rows, cols) = self.dim_yx();
+        let live = (0..rows)
+            .map(|y| self.row_snapshot(y))
+            .collect::<NcResult<Vec<_>>>()?;
+        let (styles, channels, cursor) = (self.styles(), self.channels(), self.cursor_yx());
+
+        let scratch = NcPlane::new_child_sized(self, 0, 0, rows, cols)?;
+        let scrolled_off = scratch.draw_rows(&live).and_then(|_| {
+            scratch.set_styles(styles);
+            scratch.set_channels(channels);
+            scratch.cursor_move_yx(cursor.0, cursor.1)?;
+            scratch.set_scrolling(true);
+            scratch.set_autogrow(true);
+            put(scratch)?;
+            (0..scratch.dim_y() - rows)
+                .map(|y| scratch.row_snapshot(y))
+                .collect()
+        });
+        scratch.destroy()?;
+        scrolled_off
+    }
+
+    /// Returns an owned snapshot of the row `y`.
+    pub(crate) fn row_snapshot(&self, y: u32) -> NcResult<NcPlaneRow> {
+        let mut cells = Vec::new();
+        let mut cell = NcCell::new();
+        for x in 0..self.dim_x() {
+            self.copy_cell(y, x, &mut cell)?;
+            if !cell.wide_right_p() {
+                cells.push((String::from(cell.egc(self)), cell.styles(), cell.channels()));
+            }
+            self.release_cell(&mut cell);
+        }
+        Ok(NcPlaneRow { cells })
+    }
+
+    /// Erases the plane and draws the `rows` from the top.
+    fn draw_rows(&mut self, rows: &[NcPlaneRow]) -> NcResult<()> {
+        self.erase();
+        for (y, row) in (0..self.dim_y()).zip(rows) {
+            self.put_row(y, row)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the cells of `row` to the row `y`, from the first column,
+    /// up to the first glyph that doesn't fit.
+    ///
+    /// The current styles and channels of the plane are preserved.
+    pub(crate) fn put_row(&mut self, y: u32, row: &NcPlaneRow) -> NcResult<()> {
+        let (styles, channels) = (self.styles(), self.channels());
+        let mut result = Ok(());
+        let mut x = 0;
+        for (egc, style, channels) in &row.cells {
+            let width = str_width(egc).max(1);
+            if x + width > self.dim_x() {
+                break;
+            }
+            if !egc.is_empty() {
+                self.set_styles(*style);
+                self.set_channels(*channels);
+                let res = c_api::ncplane_putstr_yx(self, Some(y), Some(x), egc);
+                if res < c_api::NCRESULT_OK {
+                    result = Err(NcError::with_msg(
+                        res,
+                        &format!("NcPlane.put_row() at {}x{}", y, x),
+                    ));
+                    break;
+                }
+            }
+            x += width;
+        }
+        self.set_styles(styles);
+        self.set_channels(channels);
+        result
+    }
+}
+
+/// Returns the length in bytes of the longest prefix of `string` that fits
+/// in `cols` columns, without splitting a grapheme.
+fn str_prefix_fitting(string: &str, cols: u32) -> usize {
+    let (mut fitting, mut width) = (0, 0);
+    while fitting < string.len() {
+        let len = egc_len(&string[fitting..]);
+        width += str_width(&string[fitting..fitting + len]);
+        if width > cols {
+            break;
+        }
+        fitting += len;
+    }
+    fitting
+}
@@ -0,0 +1,226 @@
+//! `NcSpan`, and `NcPlane` word wrapping of styled text.
+
+use crate::{
+    egc::{egc_len, str_width},
+    NcError, NcPlane, NcResult, NcRgb, NcRgba, NcStyle,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// A run of text with its colors and styles.
+///
+/// See [`NcPlane::word_wrap_spans`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcSpan {
+    /// The text.
+    pub text: String,
+
+    /// The foreground color. The alpha component is ignored.
+    pub fg: NcRgba,
+
+    /// The background color. The alpha component is ignored.
+    pub bg: NcRgba,
+
+    /// The styles.
+    pub style: NcStyle,
+}
+
+impl NcSpan {
+    /// New `NcSpan`.
+    pub fn new(text: &str, fg: NcRgba, bg: NcRgba, style: impl Into<NcStyle>) -> Self {
+        Self { text: text.into(), fg, bg, style: style.into() }
+    }
+}
+
+/// # NcPlane methods: styled text
+impl NcPlane {
+    /// Writes the `spans` starting at `row`, wrapping them at word boundaries
+    /// to fit in `max_width` columns, and keeping the colors and styles of
+    /// every span across the line breaks.
+    ///
+    /// Words are not broken at the boundaries between spans. Words wider than
+    /// `max_width` are broken at the last column that fits, never splitting a
+    /// wide glyph. Newlines start a new line, and runs of whitespace are
+    /// collapsed into a single space, drawn with the style of the span where
+    /// the run starts.
+    ///
+    /// The lines start at the first column, and `max_width` is limited to the
+    /// width of the plane. The current colors and styles of the plane are
+    /// restored afterwards.
+    ///
+    /// Returns the number of lines used. It is an error if they don't fit
+    /// in the plane, and then nothing is written.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn word_wrap_spans(
+        &mut self,
+        row: u32,
+        max_width: u32,
+        spans: &[NcSpan],
+    ) -> NcResult<usize> {
+        let max_width = max_width.min(self.dim_x());
+        if max_width == 0 {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.word_wrap_spans({}, {}) zero width",
+                row, max_width
+            ]));
+        }
+
+        let mut wrapper = SpanWrapper::new(max_width);
+        for (i, span) in spans.iter().enumerate() {
+            let mut rest = span.text.as_str();
+            while !rest.is_empty() {
+                let (egc, next) = rest.split_at(egc_len(rest));
+                rest = next;
+                if egc == "\n" {
+                    wrapper.end_word();
+                    wrapper.newline();
+                } else if egc.chars().all(char::is_whitespace) {
+                    wrapper.end_word();
+                    wrapper.space(i);
+                } else {
+                    wrapper.word_egc(i, egc);
+                }
+            }
+        }
+        wrapper.end_word();
+        let lines = wrapper.lines();
+
+        if row as usize + lines.len() > self.dim_y() as usize {
+            return Err(NcError::new_msg(&format![
+                "NcPlane.word_wrap_spans({}, {}) out of rows, {} lines needed",
+                row,
+                max_width,
+                lines.len()
+            ]));
+        }
+
+        let (styles, channels) = (self.styles(), self.channels());
+        let mut res = Ok(lines.len());
+        'lines: for (y, line) in (row..).zip(&lines) {
+            let mut x = 0;
+            for (i, text) in line {
+                let span = &spans[*i];
+                self.set_styles(span.style);
+                self.set_fg_rgb(NcRgb(span.fg.0 & 0xFF_FFFF));
+                self.set_bg_rgb(NcRgb(span.bg.0 & 0xFF_FFFF));
+                if let Err(e) = self.putstr_yx(Some(y), Some(x), text) {
+                    res = Err(e);
+                    break 'lines;
+                }
+                x += str_width(text);
+            }
+        }
+        self.set_styles(styles);
+        self.set_channels(channels);
+        res
+    }
+}
+
+/// Splits a sequence of spans into lines of pieces of text, each one
+/// referencing the index of its span.
+struct SpanWrapper {
+    max_width: u32,
+    lines: Vec<Vec<(usize, String)>>,
+    /// The width of the current line.
+    width: u32,
+    /// The span of the collapsed whitespace before the next word, if any.
+    space: Option<usize>,
+    /// The pieces of the current word.
+    word: Vec<(usize, String)>,
+    word_width: u32,
+}
+
+impl SpanWrapper {
+    fn new(max_width: u32) -> Self {
+        Self {
+            max_width,
+            lines: vec![Vec::new()],
+            width: 0,
+            space: None,
+            word: Vec::new(),
+            word_width: 0,
+        }
+    }
+
+    /// Returns the lines, or none if there's no text at all.
+    fn lines(self) -> Vec<Vec<(usize, String)>> {
+        if self.lines.len() == 1 && self.lines[0].is_empty() {
+            Vec::new()
+        } else {
+            self.lines
+        }
+    }
+
+    fn newline(&mut self) {
+        self.lines.push(Vec::new());
+        self.width = 0;
+        self.space = None;
+    }
+
+    fn space(&mut self, span: usize) {
+        if self.width > 0 && self.space.is_none() {
+            self.space = Some(span);
+        }
+    }
+
+    fn word_egc(&mut self, span: usize, egc: &str) {
+        push_piece(&mut self.word, span, egc);
+        self.word_width += str_width(egc);
+    }
+
+    /// Appends the current word to the lines.
+    fn end_word(&mut self) {
+        if self.word.is_empty() {
+            return;
+        }
+        let word = core::mem::take(&mut self.word);
+        let word_width = core::mem::replace(&mut self.word_width, 0);
+
+        if let Some(span) = self.space.take() {
+            if self.width + 1 + word_width <= self.max_width {
+                self.push(span, " ", 1);
+            } else {
+                self.newline();
+            }
+        }
+        if self.width + word_width <= self.max_width {
+            for (span, text) in &word {
+                self.push(*span, text, 0);
+            }
+            self.width += word_width;
+            return;
+        }
+
+        // the word doesn't fit in a single line, so it's split between graphemes
+        for (span, text) in &word {
+            let mut rest = text.as_str();
+            while !rest.is_empty() {
+                let (egc, next) = rest.split_at(egc_len(rest));
+                rest = next;
+                let egc_width = str_width(egc);
+                if self.width > 0 && self.width + egc_width > self.max_width {
+                    self.newline();
+                }
+                self.push(*span, egc, egc_width);
+            }
+        }
+    }
+
+    /// Appends the `text` of the `span` to the current line.
+    fn push(&mut self, span: usize, text: &str, width: u32) {
+        let line = self.lines.last_mut().unwrap();
+        push_piece(line, span, text);
+        self.width += width;
+    }
+}
+
+/// Appends the `text` of the `span` to the `pieces`, merging it with the
+/// last piece if it's from the same span.
+fn push_piece(pieces: &mut Vec<(usize, String)>, span: usize, text: &str) {
+    match pieces.last_mut() {
+        Some((last, piece)) if *last == span => piece.push_str(text),
+        _ => pieces.push((span, text.into())),
+    }
+}
@@ -1,4 +1,1905 @@
 //! Test `NcPlane` methods and associated functions.
 
-// use crate::NcPlane;
-// use serial_test::serial;
+use crate::{
+    c_api::{notcurses_init_test, notcurses_stop},
+    NcAlign, NcPlane, NcPlaneOptions,
+};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, rc::Rc, string::String, vec::Vec};
+use core::cell::Cell;
+use serial_test::serial;
+#[cfg(feature = "std")]
+use std::rc::Rc;
+
+#[test]
+#[serial]
+fn resize_fn() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let parent = NcPlane::new_pile_sized(nc, 0, 0, 20, 20).unwrap();
+
+        let calls = Rc::new(Cell::new(0));
+        let calls_fn = calls.clone();
+        let options = NcPlaneOptions::builder()
+            .rows_cols(5, 5)
+            .resize_fn(move |plane| {
+                calls_fn.set(calls_fn.get() + 1);
+                plane.resize_simple(6, 6)
+            })
+            .build();
+        let child = NcPlane::new_child(parent, &options).unwrap();
+
+        parent.resize_simple(10, 10).unwrap();
+        assert_eq![calls.get(), 1];
+        assert_eq![child.dim_yx(), (6, 6)];
+
+        // the options and each plane created from them own a handle
+        let sibling = NcPlane::new_child(parent, &options).unwrap();
+        assert_eq![Rc::strong_count(&calls), 4];
+        parent.resize_simple(12, 12).unwrap();
+        assert_eq![calls.get(), 3];
+        assert_eq![sibling.dim_yx(), (6, 6)];
+
+        // the closure is dropped with the plane, and the options
+        child.destroy().unwrap();
+        sibling.destroy().unwrap();
+        assert_eq![Rc::strong_count(&calls), 2];
+        drop(options);
+        assert_eq![Rc::strong_count(&calls), 1];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn clear_resizecb() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let parent = NcPlane::new_pile_sized(nc, 0, 0, 20, 20).unwrap();
+
+        let calls = Rc::new(Cell::new(0));
+        let calls_fn = calls.clone();
+        let options = NcPlaneOptions::builder()
+            .rows_cols(5, 5)
+            .resize_fn(move |plane| {
+                calls_fn.set(calls_fn.get() + 1);
+                plane.resize_simple(6, 6)
+            })
+            .build();
+        let child = NcPlane::new_child(parent, &options).unwrap();
+        child.set_user_data(7_u32);
+
+        drop(options);
+        child.clear_resizecb();
+        assert![child.resizecb().is_none()];
+        assert_eq![Rc::strong_count(&calls), 1];
+
+        parent.resize_simple(10, 10).unwrap();
+        assert_eq![calls.get(), 0];
+        assert_eq![child.dim_yx(), (5, 5)];
+        // the rest of the user data is kept
+        assert_eq![child.user_data::<u32>(), Some(&7)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn userptr() {
+    #[derive(Debug, PartialEq)]
+    struct State {
+        counter: u32,
+        name: &'static str,
+    }
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 20, 20).unwrap();
+        assert![plane.userptr::<State>().is_none()];
+
+        plane.set_userptr(Box::new(State { counter: 0, name: "plane" }));
+        assert_eq![
+            plane.userptr::<State>(),
+            Some(&State { counter: 0, name: "plane" })
+        ];
+        assert![plane.userptr::<u32>().is_none()];
+
+        plane.userptr_mut::<State>().unwrap().counter += 1;
+        assert_eq![plane.userptr::<State>().unwrap().counter, 1];
+
+        // the previous data is dropped
+        let shared = Rc::new(());
+        plane.set_userptr(Box::new(shared.clone()));
+        assert_eq![Rc::strong_count(&shared), 2];
+        plane.set_userptr(Box::new(0_u32));
+        assert_eq![Rc::strong_count(&shared), 1];
+        assert_eq![plane.userptr::<u32>(), Some(&0)];
+
+        plane.destroy().unwrap();
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn userptr_foreign() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 20, 20).unwrap();
+
+        // a user pointer set with the C API is neither read nor freed
+        let mut foreign = 7_usize;
+        let foreign_ptr = &mut foreign as *mut usize as *mut core::ffi::c_void;
+        crate::c_api::ncplane_set_userptr(plane, foreign_ptr);
+        assert![plane.userptr::<u32>().is_none()];
+        assert_eq![plane.scrollback_len(), 0];
+
+        // ...and it's replaced by the setters
+        plane.set_user_data(8_u32);
+        assert_eq![plane.user_data::<u32>(), Some(&8)];
+        assert![crate::c_api::ncplane_userptr(plane) != foreign_ptr];
+
+        plane.drop_user_data();
+        crate::c_api::ncplane_set_userptr(plane, foreign_ptr);
+        plane.destroy().unwrap();
+        assert_eq![foreign, 7];
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn userptr_dropped_on_stop() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_child_sized(nc.stdplane(), 0, 0, 2, 2).unwrap();
+        let data = Rc::new(5);
+        plane.set_user_data(data.clone());
+        assert_eq![Rc::strong_count(&data), 2];
+
+        nc.stop().unwrap();
+        assert_eq![Rc::strong_count(&data), 1];
+    }
+}
+
+#[test]
+#[serial]
+fn screenshot() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4).unwrap();
+
+        // a horizontal gradient of upper half blocks on the first row
+        for x in 0..4 {
+            plane.set_fg_rgb([x as u8 * 0x40, 0, 0]);
+            plane.set_bg_rgb([0, 0, x as u8 * 0x40]);
+            plane.putstr_yx(Some(0), Some(x), "▀").unwrap();
+        }
+        // a space on the second row
+        plane.set_bg_rgb([0x11, 0x22, 0x33]);
+        plane.putstr_yx(Some(1), Some(0), " ").unwrap();
+
+        let visual = plane.screenshot().unwrap();
+        assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((4, 4))];
+
+        for x in 0..4 {
+            let top = visual.at_yx(0, x).unwrap();
+            let bottom = visual.at_yx(1, x).unwrap();
+            assert_eq![(top.r(), top.g(), top.b()), (x as u8 * 0x40, 0, 0)];
+            assert_eq![(bottom.r(), bottom.g(), bottom.b()), (0, 0, x as u8 * 0x40)];
+        }
+        for y in 2..4 {
+            let pixel = visual.at_yx(y, 0).unwrap();
+            assert_eq![(pixel.r(), pixel.g(), pixel.b()), (0x11, 0x22, 0x33)];
+        }
+
+        visual.destroy();
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn putegc_at() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 4, 10).unwrap();
+
+        // single width
+        assert_eq![plane.putegc_at(0, 2, "a").unwrap(), 1];
+        assert_eq![plane.cursor_yx(), (0, 3)];
+
+        // double width
+        assert_eq![plane.putegc_at(1, 4, "字").unwrap(), 2];
+        assert_eq![plane.cursor_yx(), (1, 6)];
+
+        // base character plus combining acute accent
+        assert_eq![plane.putegc_at(2, 0, "e\u{301}").unwrap(), 1];
+        assert_eq![plane.cursor_yx(), (2, 1)];
+
+        // out of bounds
+        assert![plane.putegc_at(10, 0, "a").is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "libc")]
+fn lines() {
+    use crate::{NcChannels, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 10, 10).unwrap();
+        let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+
+        plane.line_horizontal(1, 7, 2, "─", NcStyle::Bold).unwrap();
+        for x in 2..=7 {
+            assert_eq![plane.at_yx(1, x, &mut style, &mut channels).unwrap(), "─"];
+        }
+        assert_eq![style, NcStyle::Bold];
+        assert_eq![plane.at_yx(1, 1, &mut style, &mut channels).unwrap(), ""];
+        assert_eq![plane.at_yx(1, 8, &mut style, &mut channels).unwrap(), ""];
+
+        plane.line_vertical(0, 3, 5, "│", NcStyle::None).unwrap();
+        for y in 3..=5 {
+            assert_eq![plane.at_yx(y, 0, &mut style, &mut channels).unwrap(), "│"];
+        }
+        assert_eq![plane.at_yx(6, 0, &mut style, &mut channels).unwrap(), ""];
+
+        plane.line_diagonal(9, 2, 6, 5, "╱", NcStyle::None).unwrap();
+        for i in 0..=3 {
+            assert_eq![
+                plane
+                    .at_yx(9 - i, 2 + i, &mut style, &mut channels)
+                    .unwrap(),
+                "╱"
+            ];
+        }
+        assert![plane.line_diagonal(0, 0, 2, 3, "╲", NcStyle::None).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn center_within() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let container = NcPlane::new_pile_sized(nc, 0, 0, 10, 20).unwrap();
+
+        let plane = NcPlane::new_child_sized(container, 0, 0, 3, 5).unwrap();
+        plane.center_within(container).unwrap();
+        assert_eq![plane.yx(), (3, 7)];
+
+        // the container doesn't need to be the parent
+        let inner = NcPlane::new_child_sized(container, 2, 4, 6, 8).unwrap();
+        plane.center_within(inner).unwrap();
+        assert_eq![plane.yx(), (2 + 1, 4 + 1)];
+
+        let stdplane = nc.stdplane();
+        let (rows, cols) = stdplane.dim_yx();
+        let child = NcPlane::new_child_sized(stdplane, 0, 0, 3, 5).unwrap();
+        child.center_on_stdplane(nc).unwrap();
+        assert_eq![child.yx(), ((rows as i32 - 3) / 2, (cols as i32 - 5) / 2)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn yx_abs_yx() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let root = NcPlane::new_pile_sized(nc, 1, 2, 20, 20).unwrap();
+        let child = NcPlane::new_child_sized(root, 3, 4, 5, 5).unwrap();
+
+        assert_eq![child.yx(), (3, 4)];
+        assert_eq![(child.y(), child.x()), (3, 4)];
+        assert_eq![child.abs_yx(), (1 + 3, 2 + 4)];
+        assert_eq![(child.abs_y(), child.abs_x()), (1 + 3, 2 + 4)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn user_data() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 20, 20).unwrap();
+
+        plane.set_user_data(String::from("hello"));
+        assert_eq![
+            plane.user_data::<String>().map(|s| s.as_str()),
+            Some("hello")
+        ];
+        assert![plane.user_data::<&str>().is_none()];
+
+        plane.drop_user_data();
+        assert![plane.user_data::<String>().is_none()];
+        assert![crate::c_api::ncplane_userptr(plane).is_null()];
+
+        // dropping it again does nothing
+        plane.drop_user_data();
+
+        plane.destroy().unwrap();
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn putstr_wrapped() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 6, 10).unwrap();
+
+        // "the quick" / "brown fox" / "jumps over" / "the lazy" / "dog"
+        let yx = plane
+            .putstr_wrapped("the quick brown fox jumps over the lazy dog")
+            .unwrap();
+        assert_eq![yx, (4, 3)];
+
+        // long words are hard-broken at the plane width
+        plane.erase();
+        plane.cursor_move_yx(0, 0).unwrap();
+        assert_eq![plane.putstr_wrapped("abcdefghijklmnop").unwrap(), (1, 6)];
+
+        // wide glyphs are never split
+        plane.erase();
+        plane.cursor_move_yx(0, 0).unwrap();
+        assert_eq![plane.putstr_wrapped("字字字字字字字").unwrap(), (1, 4)];
+
+        // nor are graphemes made of several characters
+        plane.erase();
+        plane.cursor_move_yx(0, 0).unwrap();
+        assert_eq![
+            plane.putstr_wrapped(&"e\u{301}".repeat(12)).unwrap(),
+            (1, 2)
+        ];
+        assert_eq![plane.row_as_string(1).unwrap(), "e\u{301}e\u{301}"];
+
+        // running out of rows is an error, unless scrolling
+        plane.erase();
+        plane.cursor_move_yx(0, 0).unwrap();
+        assert![plane.putstr_wrapped("a\nb\nc\nd\ne\nf\ng").is_err()];
+        plane.set_scrolling(true);
+        plane.cursor_move_yx(0, 0).unwrap();
+        assert_eq![plane.putstr_wrapped("a\nb\nc\nd\ne\nf\ng").unwrap(), (5, 1)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "libc")]
+fn default_style() {
+    use crate::{NcChannels, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 10).unwrap();
+        let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+
+        plane.set_default_style(NcStyle::Bold);
+        assert_eq![plane.default_style(), NcStyle::Bold];
+        plane.putstr_yx(Some(0), Some(0), "bold").unwrap();
+        assert_eq![plane.at_yx(0, 0, &mut style, &mut channels).unwrap(), "b"];
+        assert_eq![style, NcStyle::Bold];
+
+        plane.add_default_style(NcStyle::Italic);
+        assert_eq![plane.default_style(), NcStyle::Bold | NcStyle::Italic];
+        plane.remove_default_style(NcStyle::Bold);
+        assert_eq![plane.default_style(), NcStyle::Italic];
+        plane.putstr_yx(Some(1), Some(0), "italic").unwrap();
+        plane.at_yx(1, 0, &mut style, &mut channels).unwrap();
+        assert_eq![style, NcStyle::Italic];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn set_bg_rgb_with_contrast() {
+    use crate::{NcRgb, NcRgba};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 2).unwrap();
+
+        plane.set_bg_rgb_with_contrast(NcRgba::new(0x10, 0x10, 0x40, 0xFF));
+        assert_eq![plane.bg_rgb(), NcRgb(0x101040)];
+        assert_eq![plane.fg_rgb(), NcRgb(0xFFFFFF)];
+
+        plane.set_bg_rgb_with_contrast(NcRgba::new(0xF0, 0xF0, 0xC0, 0xFF));
+        assert_eq![plane.bg_rgb(), NcRgb(0xF0F0C0)];
+        assert_eq![plane.fg_rgb(), NcRgb(0x000000)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "libc")]
+fn format_region() {
+    use crate::{NcChannels, NcRgb, NcRgba, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 10).unwrap();
+        let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+
+        plane.set_bg_rgb(0x0000FF);
+        plane.putstr_yx(Some(0), Some(0), "plain text").unwrap();
+        plane.putstr_yx(Some(1), Some(0), "a字b").unwrap();
+
+        let red = NcRgba::new(0xFF, 0, 0, 0xFF);
+        plane
+            .format_region(0, 2, 1, 3, Some(red), None, NcStyle::Bold)
+            .unwrap();
+        for x in 0..10 {
+            let egc = plane.at_yx(0, x, &mut style, &mut channels).unwrap();
+            assert_eq![egc, &"plain text"[x as usize..x as usize + 1]];
+            assert_eq![channels.bg_rgb(), NcRgb(0x0000FF)];
+            if (2..5).contains(&x) {
+                assert_eq![channels.fg_rgb(), NcRgb(0xFF0000)];
+                assert_eq![style, NcStyle::Bold];
+            } else {
+                assert_ne![channels.fg_rgb(), NcRgb(0xFF0000)];
+                assert_eq![style, NcStyle::None];
+            }
+        }
+
+        // both halves of a wide glyph are formatted
+        plane
+            .format_region(1, 2, 1, 1, None, Some(red), NcStyle::None)
+            .unwrap();
+        for x in 1..=2 {
+            plane.at_yx(1, x, &mut style, &mut channels).unwrap();
+            assert_eq![channels.bg_rgb(), NcRgb(0xFF0000)];
+        }
+        plane.at_yx(1, 3, &mut style, &mut channels).unwrap();
+        assert_eq![channels.bg_rgb(), NcRgb(0x0000FF)];
+
+        assert![plane
+            .format_region(0, 8, 1, 3, Some(red), None, NcStyle::None)
+            .is_err()];
+        assert![plane
+            .format_region(1, 0, u32::MAX, 1, Some(red), None, NcStyle::None)
+            .is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn search() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 9).unwrap();
+        plane.putstr_yx(Some(0), Some(0), "foo bar").unwrap();
+        plane.putstr_yx(Some(1), Some(0), "añ字o foo").unwrap();
+        plane.putstr_yx(Some(2), Some(0), "barfoo").unwrap();
+
+        // ascii
+        assert_eq![plane.search_forward((0, 0), "foo", false), Some((0, 0))];
+        assert_eq![plane.search_forward((0, 1), "foo", false), Some((1, 6))];
+        assert_eq![plane.search_forward((2, 4), "foo", false), None];
+        assert_eq![plane.search_forward((2, 4), "foo", true), Some((0, 0))];
+
+        // multi-byte and wide glyphs
+        assert_eq![plane.search_forward((0, 0), "ñ字o", false), Some((1, 1))];
+        assert_eq![plane.search_forward((0, 0), "o", false), Some((0, 1))];
+        assert_eq![plane.search_forward((1, 0), "o", false), Some((1, 4))];
+
+        // spanning line boundaries
+        assert_eq![plane.search_forward((0, 0), "foobar", false), Some((1, 6))];
+
+        // backward
+        assert_eq![plane.search_backward((2, 7), "foo", false), Some((2, 3))];
+        assert_eq![plane.search_backward((2, 2), "foo", false), Some((1, 6))];
+        assert_eq![plane.search_backward((1, 5), "字", false), Some((1, 2))];
+        assert_eq![plane.search_backward((0, 0), "bar", false), None];
+        assert_eq![plane.search_backward((0, 0), "bar", true), Some((2, 0))];
+        // the end of the match would fall inside of a multi-byte glyph
+        assert_eq![plane.search_backward((1, 0), "ab", false), None];
+
+        assert_eq![plane.search_forward((0, 0), "", true), None];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn content_as_string() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10).unwrap();
+        plane.putstr_yx(Some(0), Some(0), "hello").unwrap();
+        plane.putstr_yx(Some(1), Some(2), "a 字 b").unwrap();
+
+        assert_eq![plane.row_as_string(0).unwrap(), "hello"];
+        assert_eq![plane.row_as_string(1).unwrap(), "  a 字 b"];
+        assert_eq![plane.row_as_string(2).unwrap(), ""];
+        assert![plane.row_as_string(3).is_err()];
+
+        assert_eq![plane.content_as_string(), "hello\n  a 字 b\n"];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn as_rgba_vec() {
+    use crate::NcBlitter;
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 3).unwrap();
+        plane.set_fg_rgb(0x102030);
+        for y in 0..2 {
+            plane.putstr_yx(Some(y), Some(0), "███").unwrap();
+        }
+
+        // the half blitter has 1×2 pixels per cell
+        let (pixels, pxdim_y, pxdim_x) = plane
+            .as_rgba_vec(NcBlitter::Half, Some(0), Some(0), None, None)
+            .unwrap();
+        assert_eq![(pxdim_y, pxdim_x), (4, 3)];
+        assert_eq![pixels.len(), 4 * 3];
+        for pixel in pixels {
+            assert_eq![pixel, 0x102030FF];
+        }
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "regex")]
+fn highlight_pattern() {
+    use crate::{NcChannels, NcRgb, NcRgba, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 20).unwrap();
+        plane.set_fg_rgb(0xAAAAAA);
+        plane
+            .putstr_yx(Some(0), Some(0), "one two three four")
+            .unwrap();
+        plane
+            .putstr_yx(Some(1), Some(0), "five six seven 字")
+            .unwrap();
+        let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+
+        let (fg, bg) = (NcRgba::new(0xFF, 0, 0, 0xFF), NcRgba::new(0, 0, 0xFF, 0xFF));
+        // every third word: "three", "six"
+        let count = plane.highlight_pattern(r"\b(three|six)\b", fg, bg).unwrap();
+        assert_eq![count, 2];
+
+        let highlighted = |plane: &mut NcPlane, y, x| {
+            let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+            plane.at_yx(y, x, &mut style, &mut channels).unwrap();
+            channels.fg_rgb() == NcRgb(0xFF0000) && channels.bg_rgb() == NcRgb(0x0000FF)
+        };
+        assert![(8..13).all(|x| highlighted(plane, 0, x))];
+        assert![!highlighted(plane, 0, 7) && !highlighted(plane, 0, 13)];
+        assert![(5..8).all(|x| highlighted(plane, 1, x))];
+
+        // matches after wide glyphs land on the right columns
+        assert_eq![plane.highlight_pattern("字", fg, bg).unwrap(), 1];
+        assert![highlighted(plane, 1, 15) && highlighted(plane, 1, 16)];
+
+        assert![plane.highlight_pattern("(", fg, bg).is_err()];
+
+        // the original channels are restored, not the current ones
+        plane.set_fg_rgb(0x555555);
+        plane.clear_highlights().unwrap();
+        assert![!highlighted(plane, 0, 8)];
+        plane.at_yx(0, 8, &mut style, &mut channels).unwrap();
+        assert_eq![channels.fg_rgb(), NcRgb(0xAAAAAA)];
+        plane.at_yx(1, 16, &mut style, &mut channels).unwrap();
+        assert_eq![channels.fg_rgb(), NcRgb(0xAAAAAA)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn split() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let root = NcPlane::new_pile_sized(nc, 0, 0, 10, 20).unwrap();
+
+        let plane = NcPlane::new_child_sized(root, 1, 2, 2, 6).unwrap();
+        plane.putstr_yx(Some(0), Some(0), "abcdef").unwrap();
+        plane.putstr_yx(Some(1), Some(0), "ghijkl").unwrap();
+        let (left, right) = plane.vsplit(2).unwrap();
+        assert_eq![(left.yx(), left.dim_yx()), ((1, 2), (2, 2))];
+        assert_eq![(right.yx(), right.dim_yx()), ((1, 4), (2, 4))];
+        assert_eq![left.content_as_string(), "ab\ngh"];
+        assert_eq![right.content_as_string(), "cdef\nijkl"];
+
+        let (top, bottom) = right.hsplit(1).unwrap();
+        assert_eq![(top.yx(), top.dim_yx()), ((1, 4), (1, 4))];
+        assert_eq![(bottom.yx(), bottom.dim_yx()), ((2, 4), (1, 4))];
+        assert_eq![top.content_as_string(), "cdef"];
+        assert_eq![bottom.content_as_string(), "ijkl"];
+
+        assert![left.vsplit(0).is_err()];
+        assert![left.vsplit(2).is_err()];
+        assert![left.hsplit(2).is_err()];
+        assert![nc.stdplane().vsplit(1).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn putstr_ellipsized() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 20).unwrap();
+        let mut put = |max_cols, string| {
+            plane.erase();
+            let cols = plane.putstr_ellipsized(max_cols, string).unwrap();
+            (cols, plane.row_as_string(0).unwrap())
+        };
+
+        // ascii
+        assert_eq![put(10, "status"), (6, "status".into())];
+        assert_eq![put(6, "status"), (6, "status".into())];
+        assert_eq![put(5, "status"), (5, "stat…".into())];
+        assert_eq![put(1, "status"), (1, "…".into())];
+        assert_eq![put(0, "status"), (0, "".into())];
+
+        // wide glyphs are never split
+        assert_eq![put(8, "漢字テスト"), (7, "漢字テ…".into())];
+        assert_eq![put(7, "漢字テスト"), (7, "漢字テ…".into())];
+        assert_eq![put(6, "漢字テスト"), (5, "漢字…".into())];
+        assert_eq![put(10, "漢字テスト"), (10, "漢字テスト".into())];
+
+        // nor are grapheme clusters
+        let accented = "e\u{301}".repeat(6);
+        assert_eq![put(4, &accented), (4, format!["{}…", "e\u{301}".repeat(3)])];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn put_safe() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 5).unwrap();
+
+        assert_eq![plane.putstr_safe(""), Some(0)];
+        assert_eq![plane.putstr_safe("abc"), Some(3)];
+        assert_eq![plane.putchar_safe('d'), Some(1)];
+        // a wide glyph doesn't fit in the last column
+        assert_eq![plane.putchar_safe('漢'), None];
+        assert_eq![plane.putchar_safe('e'), Some(1)];
+        assert_eq![plane.putchar_safe('f'), None];
+        assert_eq![plane.putstr_safe("g"), None];
+
+        // the output is truncated at the last column
+        plane.cursor_move_yx(0, 2).unwrap();
+        assert_eq![plane.putstr_safe("xyzw"), Some(3)];
+        assert_eq![plane.row_as_string(0).unwrap(), "abxyz"];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn move_family() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let parent = NcPlane::new_pile_sized(nc, 1, 1, 10, 10).unwrap();
+        let child1 = NcPlane::new_child_sized(parent, 1, 2, 2, 2).unwrap();
+        let child2 = NcPlane::new_child_sized(parent, 3, 4, 2, 2).unwrap();
+        assert_eq![child1.abs_yx(), (2, 3)];
+        assert_eq![child2.abs_yx(), (4, 5)];
+
+        parent.move_family_yx(5, 6).unwrap();
+        assert_eq![parent.abs_yx(), (5, 6)];
+        assert_eq![child1.abs_yx(), (6, 8)];
+        assert_eq![child2.abs_yx(), (8, 10)];
+
+        parent.move_family_rel(-2, 3).unwrap();
+        assert_eq![parent.abs_yx(), (3, 9)];
+        assert_eq![child1.abs_yx(), (4, 11)];
+        assert_eq![child2.abs_yx(), (6, 13)];
+
+        // the children keep their position relative to their parent
+        assert_eq![child1.yx(), (1, 2)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn resize_simple() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 2, 3, 4, 8).unwrap();
+        plane.putstr_yx(Some(0), Some(0), "abcdef").unwrap();
+        plane.putstr_yx(Some(1), Some(0), "ghi").unwrap();
+
+        // growing keeps everything
+        plane.resize_simple(6, 12).unwrap();
+        assert_eq![plane.dim_yx(), (6, 12)];
+        assert_eq![plane.yx(), (2, 3)];
+        assert_eq![plane.row_as_string(0).unwrap(), "abcdef"];
+        assert_eq![plane.row_as_string(1).unwrap(), "ghi"];
+
+        // shrinking keeps the top-left corner
+        plane.resize_simple(1, 3).unwrap();
+        assert_eq![plane.dim_yx(), (1, 3)];
+        assert_eq![plane.row_as_string(0).unwrap(), "abc"];
+
+        assert![plane.resize_simple(0, 0).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn scrollback() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 20).unwrap();
+        plane.set_scrolling(true);
+        plane.set_scrollback_size(50);
+
+        for i in 0..100 {
+            plane.putstrln(&format!["line {}", i]).unwrap();
+        }
+
+        // the buffer keeps the most recent lines scrolled off
+        assert_eq![plane.scrollback_len(), 50];
+        assert_eq![plane.scrollback_line(0).unwrap().text(), "line 95"];
+        assert_eq![plane.scrollback_line(49).unwrap().text(), "line 46"];
+        assert![plane.scrollback_line(50).is_none()];
+
+        // the scrolled back view, and the live view
+        plane.scroll_to_line(10).unwrap();
+        assert_eq![plane.row_as_string(0).unwrap(), "line 86"];
+        plane.scroll_to_line(0).unwrap();
+        assert_eq![plane.row_as_string(0).unwrap(), "line 96"];
+        assert_eq![plane.cursor_yx(), (4, 0)];
+
+        // long lines are wrapped and recorded too
+        plane.putstr(&"x".repeat(30)).unwrap();
+        plane.putstrln("").unwrap();
+        assert_eq![plane.scrollback_line(1).unwrap().text(), "line 96"];
+        assert_eq![plane.scrollback_line(0).unwrap().text(), "line 97"];
+        assert_eq![plane.row_as_string(3).unwrap(), "x".repeat(10)];
+
+        // every write that scrolls is recorded
+        plane.putstr_yx(Some(4), Some(0), "yx\n").unwrap();
+        assert_eq![plane.scrollback_line(0).unwrap().text(), "line 98"];
+        plane.putchar('\n').unwrap();
+        assert_eq![plane.scrollback_line(0).unwrap().text(), "line 99"];
+        plane.putegc_yx(Some(4), Some(19), "z", None).unwrap();
+        plane.putegc("w", None).unwrap();
+        assert_eq![plane.scrollback_line(0).unwrap().text(), "x".repeat(20)];
+        plane
+            .puttext(4, NcAlign::Left, "a b c d e f g h i j k l m n")
+            .unwrap();
+        assert_eq![plane.scrollback_line(0).unwrap().text(), "x".repeat(10)];
+
+        // writing while scrolled back returns to the live view first
+        let live = plane.row_as_string(4).unwrap();
+        plane.scroll_to_line(3).unwrap();
+        plane.putstr("!").unwrap();
+        assert_eq![plane.scroll_offset(), 0];
+        assert_eq![plane.row_as_string(4).unwrap(), format!["{}!", live]];
+
+        plane.set_scrollback_size(0);
+        assert_eq![plane.scrollback_len(), 0];
+        assert![plane.scroll_to_line(1).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn view_scroll() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 20).unwrap();
+        let e = plane.view_scroll(-1).unwrap_err();
+        assert![e.msg.contains("view_scroll(-1): no scrollback buffer")];
+
+        plane.set_scrolling(true);
+        plane.set_scrollback_size(500);
+        for i in 0..200 {
+            plane.putstrln(&format!["line {}", i]).unwrap();
+        }
+        assert_eq![plane.scrollback_len(), 196];
+        assert_eq![plane.row_as_string(0).unwrap(), "line 196"];
+
+        plane.view_scroll(-50).unwrap();
+        assert_eq![plane.scroll_offset(), 50];
+        assert_eq![plane.row_as_string(0).unwrap(), "line 146"];
+        plane.view_scroll(20).unwrap();
+        assert_eq![plane.row_as_string(0).unwrap(), "line 166"];
+
+        // the view stops at both ends
+        plane.view_scroll(-1000).unwrap();
+        assert_eq![plane.scroll_offset(), 196];
+        assert_eq![plane.row_as_string(0).unwrap(), "line 0"];
+        plane.view_scroll_to_bottom().unwrap();
+        plane.view_scroll(1000).unwrap();
+        assert_eq![plane.scroll_offset(), 0];
+        assert_eq![plane.row_as_string(0).unwrap(), "line 196"];
+        assert_eq![plane.cursor_yx(), (4, 0)];
+
+        plane.view_scroll_to_top().unwrap();
+        assert_eq![plane.row_as_string(4).unwrap(), "line 4"];
+        plane.view_scroll_to_bottom().unwrap();
+        assert_eq![plane.row_as_string(3).unwrap(), "line 199"];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn at_cursor() {
+    use crate::{NcRgb, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 10).unwrap();
+        plane.set_fg_rgb(0x112233);
+        plane.set_styles(NcStyle::Bold);
+        plane.putstr_yx(Some(1), Some(0), "ab界").unwrap();
+        plane.set_styles(NcStyle::None);
+
+        plane.cursor_move_yx(1, 1).unwrap();
+        assert_eq![plane.char_at_cursor().unwrap(), "b"];
+        let mut cell = plane.cell_at_cursor().unwrap();
+        assert_eq![cell.egc(plane), "b"];
+        assert_eq![cell.styles(), NcStyle::Bold];
+        assert_eq![cell.fg_rgb(), NcRgb(0x112233)];
+        cell.release(plane);
+        assert_eq![plane.cursor_yx(), (1, 1)];
+
+        // the right half of a wide glyph, and an empty cell
+        plane.cursor_move_yx(1, 3).unwrap();
+        assert_eq![plane.char_at_cursor().unwrap(), "界"];
+        plane.cursor_move_yx(0, 5).unwrap();
+        assert_eq![plane.char_at_cursor().unwrap(), ""];
+        assert_eq![plane.cursor_yx(), (0, 5)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn move_yx_clamped() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let (rows, cols) = nc.term_dim_yx();
+        let (rows, cols) = (rows as i32, cols as i32);
+        let stdplane = nc.stdplane();
+        let plane = NcPlane::new_child_sized(stdplane, 0, 0, 3, 4).unwrap();
+
+        // off the top left, and off the bottom right
+        assert_eq![plane.move_yx_clamped(-1000, -1000).unwrap(), (-2, -3)];
+        assert_eq![plane.yx(), (-2, -3)];
+        assert_eq![
+            plane.move_yx_clamped(1000, 1000).unwrap(),
+            (rows - 1, cols - 1)
+        ];
+        assert_eq![plane.yx(), (rows - 1, cols - 1)];
+
+        // off the top right, and off the bottom left
+        assert_eq![plane.move_yx_clamped(-1000, 1000).unwrap(), (-2, cols - 1)];
+        assert_eq![plane.move_yx_clamped(1000, -1000).unwrap(), (rows - 1, -3)];
+
+        // a valid position is kept
+        assert_eq![plane.move_yx_clamped(1, 1).unwrap(), (1, 1)];
+
+        // the position of a bound plane is relative to its parent
+        let child = NcPlane::new_child_sized(plane, 0, 0, 1, 1).unwrap();
+        assert_eq![child.move_yx_clamped(-1000, -1000).unwrap(), (-1, -1)];
+        assert_eq![child.abs_yx(), (0, 0)];
+
+        assert![stdplane.move_yx_clamped(1, 1).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn to_html() {
+    use crate::{NcChannels, NcStyle};
+
+    const EXPECTED: &str = "<pre>\
+        <span style=\"color: #ff0000; font-weight: bold\">Hi</span> &lt;&amp;&gt;\n\
+        <span style=\"background-color: #0000ff; text-decoration: underline line-through\">ok</span>\
+        <span style=\"text-decoration: underline wavy\">!</span>\n\
+        </pre>";
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 8).unwrap();
+
+        plane.set_fg_rgb(0xFF0000);
+        plane.set_styles(NcStyle::Bold);
+        plane.putstr_yx(Some(0), Some(0), "Hi").unwrap();
+        plane.set_channels(NcChannels::with_default());
+        plane.set_styles(NcStyle::None);
+        plane.putstr(" <&>").unwrap();
+
+        plane.set_bg_rgb(0x0000FF);
+        plane.set_styles(NcStyle::Underline | NcStyle::Struck);
+        plane.putstr_yx(Some(1), Some(0), "ok").unwrap();
+        plane.set_channels(NcChannels::with_default());
+        plane.set_styles(NcStyle::Undercurl);
+        plane.putstr("!").unwrap();
+
+        assert_eq![plane.to_html(), EXPECTED];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn replace() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 8).unwrap();
+
+        // same length
+        plane.putstr_yx(Some(0), Some(0), "foo bar").unwrap();
+        assert_eq![plane.replace_all("bar", "baz").unwrap(), 1];
+        assert_eq![plane.row_as_string(0).unwrap(), "foo baz"];
+
+        // shorter, shifting the following cells to the left
+        plane.putstr_yx(Some(1), Some(0), "foo foo").unwrap();
+        assert_eq![plane.replace_all("foo", "x").unwrap(), 3];
+        assert_eq![plane.row_as_string(0).unwrap(), "x baz"];
+        assert_eq![plane.row_as_string(1).unwrap(), "x x"];
+
+        // longer, shifting the following cells to the right and off the plane
+        plane.putstr_yx(Some(2), Some(0), "ab ab ab").unwrap();
+        assert_eq![plane.replace_all("ab", "abcd").unwrap(), 3];
+        assert_eq![plane.row_as_string(2).unwrap(), "abcd abc"];
+
+        assert![plane.replace_first("x", "yz").unwrap()];
+        assert_eq![plane.content_as_string(), "yz baz\nx x\nabcd abc"];
+        assert![!plane.replace_first("none", "x").unwrap()];
+        assert_eq![plane.replace_all("", "x").unwrap(), 0];
+        // the trailing empty cells are not part of the text
+        assert_eq![plane.replace_all("  ", "-").unwrap(), 0];
+
+        #[cfg(feature = "regex")]
+        {
+            assert_eq![
+                plane
+                    .search_and_replace_regex(r"^(\w)(\w) ", "$2$1-")
+                    .unwrap(),
+                1
+            ];
+            assert_eq![plane.row_as_string(0).unwrap(), "zy-baz"];
+            assert_eq![plane.search_and_replace_regex("x$", "y").unwrap(), 1];
+            assert_eq![plane.row_as_string(1).unwrap(), "x y"];
+            assert![plane.search_and_replace_regex("(", "").is_err()];
+        }
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn word_wrap_spans() {
+    use crate::{NcCell, NcRgb, NcRgba, NcSpan, NcStyle};
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 6, 20).unwrap();
+
+        let (red, blue, black) = (
+            NcRgba(0xFF_FF0000),
+            NcRgba(0xFF_0000FF),
+            NcRgba(0xFF_000000),
+        );
+        let spans = [
+            NcSpan::new("hello wor", red, black, NcStyle::Bold),
+            NcSpan::new("ld foo ", blue, black, NcStyle::None),
+            NcSpan::new(
+                "barbazquxquuxcorgegraultgarply",
+                red,
+                black,
+                NcStyle::Italic,
+            ),
+        ];
+        assert_eq![plane.word_wrap_spans(0, 10, &spans).unwrap(), 5];
+
+        assert_eq![plane.row_as_string(0).unwrap(), "hello"];
+        // a word made of two spans is not broken
+        assert_eq![plane.row_as_string(1).unwrap(), "world foo"];
+        // a word wider than the line is split
+        assert_eq![plane.row_as_string(2).unwrap(), "barbazquxq"];
+        assert_eq![plane.row_as_string(3).unwrap(), "uuxcorgegr"];
+        assert_eq![plane.row_as_string(4).unwrap(), "aultgarply"];
+
+        // each part keeps the style of its span
+        let mut cell = NcCell::new();
+        for (y, x, fg, style) in [
+            (1, 2, NcRgb(0xFF0000), NcStyle::Bold),
+            (1, 3, NcRgb(0x0000FF), NcStyle::None),
+            (3, 0, NcRgb(0xFF0000), NcStyle::Italic),
+        ] {
+            plane.at_yx_cell(y, x, &mut cell).unwrap();
+            assert_eq![cell.fg_rgb(), fg];
+            assert_eq![cell.styles(), style];
+            cell.release(plane);
+        }
+
+        assert![plane.word_wrap_spans(3, 10, &spans).is_err()];
+        assert_eq![plane.word_wrap_spans(0, 10, &[]).unwrap(), 0];
+
+        // a long word is split between graphemes, not between characters
+        plane.erase();
+        let accented = "e\u{301}".repeat(12);
+        let spans = [NcSpan::new(&accented, red, black, NcStyle::None)];
+        assert_eq![plane.word_wrap_spans(0, 10, &spans).unwrap(), 2];
+        assert_eq![plane.row_as_string(0).unwrap(), "e\u{301}".repeat(10)];
+        assert_eq![plane.row_as_string(1).unwrap(), "e\u{301}".repeat(2)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn qrcode() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 100, 100).unwrap();
+
+        // only checked if notcurses was built with QR code support
+        if let Ok((rows, cols)) = plane.qrcode(b"https://notcurses.com") {
+            assert![rows > 0 && cols > 0];
+            assert![rows <= 100 && cols <= 100];
+
+            // there's no room in a tiny plane
+            let tiny = NcPlane::new_pile_sized(nc, 0, 0, 2, 2).unwrap();
+            assert![tiny.qrcode(b"https://notcurses.com").is_err()];
+        }
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn set_resize_callback() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let parent = NcPlane::new_pile_sized(nc, 0, 0, 20, 20).unwrap();
+        let child = NcPlane::new_child_sized(parent, 0, 0, 5, 5).unwrap();
+
+        let calls = Rc::new(Cell::new(0));
+        let calls_fn = calls.clone();
+        child.set_resize_callback(move |plane| {
+            calls_fn.set(calls_fn.get() + 1);
+            let _ = plane.resize_simple(6, 6);
+        });
+        assert![child.resizecb().is_some()];
+
+        parent.resize_simple(10, 10).unwrap();
+        assert_eq![calls.get(), 1];
+        assert_eq![child.dim_yx(), (6, 6)];
+
+        // replacing the closure drops the previous one
+        child.set_resize_callback(|_| ());
+        assert_eq![Rc::strong_count(&calls), 1];
+        parent.resize_simple(12, 12).unwrap();
+        assert_eq![calls.get(), 1];
+
+        child.clear_resizecb();
+        assert![child.resizecb().is_none()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn destroy_bitmaps() {
+    use crate::{NcBlitter, NcCell, NcVisual, NcVisualOptions};
+    unsafe {
+        let nc = notcurses_init_test();
+
+        // only checked if the terminal supports pixel graphics
+        if nc.canpixel() {
+            let plane = NcPlane::new_child_sized(nc.stdplane(), 0, 0, 4, 4).unwrap();
+            let visual = NcVisual::from_rgba(&[0xFF; 8 * 8 * 4], 8, 8 * 4, 8).unwrap();
+            let options = NcVisualOptions::builder()
+                .plane(plane)
+                .blitter(NcBlitter::Pixel)
+                .build();
+            visual.blit(nc, Some(&options)).unwrap();
+            nc.render().unwrap();
+
+            // the cells of a plane with a bitmap can't be read
+            let mut cell = NcCell::new();
+            assert![plane.at_yx_cell(0, 0, &mut cell).is_err()];
+
+            plane.destroy_bitmaps();
+            nc.render().unwrap();
+            assert![plane.at_yx_cell(0, 0, &mut cell).is_ok()];
+            assert_eq![plane.cursor_yx(), (0, 0)];
+
+            visual.destroy();
+        }
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn text_cursor_positions() {
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10).unwrap();
+        plane.cursor_move_yx(0, 1).unwrap();
+
+        // narrow, wide, combined and tab glyphs
+        let positions: Vec<_> = plane.text_cursor_positions("a漢e\u{301}\tb").collect();
+        assert_eq![
+            positions,
+            [(0, 1, 0), (0, 2, 1), (0, 4, 4), (0, 5, 7), (0, 8, 8)]
+        ];
+
+        // without scrolling it stops at the end of the line, and at newlines
+        assert_eq![plane.text_cursor_positions("abcdefghijk").count(), 9];
+        assert_eq![plane.text_cursor_positions("ab\ncd").count(), 2];
+
+        // with scrolling, glyphs are wrapped and the last row is kept
+        plane.set_scrolling(true);
+        let positions: Vec<_> = plane.text_cursor_positions("12345678漢\nx\ny").collect();
+        assert_eq![
+            positions,
+            [
+                (0, 1, 0),
+                (0, 2, 1),
+                (0, 3, 2),
+                (0, 4, 3),
+                (0, 5, 4),
+                (0, 6, 5),
+                (0, 7, 6),
+                (0, 8, 7),
+                (1, 0, 8),
+                (1, 2, 11),
+                (2, 0, 12),
+                (2, 1, 13),
+                (2, 0, 14),
+            ]
+        ];
+
+        // the positions match the ones of putstr
+        plane.set_scrolling(false);
+        plane.erase();
+        let text = "x漢y";
+        let positions: Vec<_> = plane.text_cursor_positions(text).collect();
+        plane.putstr(text).unwrap();
+        let mut cell = crate::NcCell::new();
+        for (y, x, offset) in positions {
+            plane.at_yx_cell(y, x, &mut cell).unwrap();
+            assert![text[offset..].starts_with(cell.egc(plane))];
+            cell.release(plane);
+        }
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn to_sixel_string() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 3).unwrap();
+        plane.set_bg_rgb([0xFF, 0, 0]);
+        plane.putstr_yx(Some(0), Some(0), "   ").unwrap();
+        plane.set_bg_rgb([0, 0, 0xFF]);
+        plane.putstr_yx(Some(1), Some(0), "  ").unwrap();
+
+        let sixel = plane.to_sixel_string().unwrap();
+        assert![sixel.starts_with("\x1bP")];
+        assert![sixel.ends_with("\x1b\\")];
+
+        // each cell is 2 pixels tall, and the default background is black
+        let (rgba, rows, cols) = crate::visual::sixel::sixel_to_rgba(sixel.as_bytes()).unwrap();
+        assert_eq![(rows, cols), (4, 3)];
+        let (red, blue, black) = ([0xFF, 0, 0, 0xFF], [0, 0, 0xFF, 0xFF], [0, 0, 0, 0xFF]);
+        assert_eq![
+            rgba.chunks_exact(4).collect::<Vec<_>>(),
+            [red, red, red, red, red, red, blue, blue, black, blue, blue, black]
+        ];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn log_plane() {
+    use crate::{NcChannels, NcLogPlane, NcStyle};
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 10).unwrap();
+        let mut log = NcLogPlane::new(plane, 5);
+
+        for i in 0..8 {
+            log.log_line(
+                NcStyle::Bold,
+                NcChannels::from_rgb(0xFF0000, 0),
+                &format!["line {}", i],
+            )
+            .unwrap();
+        }
+
+        // the history keeps the most recent lines
+        let history: Vec<&str> = log.history().iter().map(|s| s.as_str()).collect();
+        assert_eq![history, ["line 3", "line 4", "line 5", "line 6", "line 7"]];
+
+        // the plane shows the last lines, and keeps its own styles
+        let plane = log.plane();
+        assert_eq![plane.row_as_string(0).unwrap(), "line 5"];
+        assert_eq![plane.row_as_string(2).unwrap(), "line 7"];
+        assert_eq![plane.styles(), NcStyle::None];
+
+        // no history
+        let mut log = NcLogPlane::new(plane, 0);
+        log.log_line(NcStyle::None, NcChannels::new(), "line 8")
+            .unwrap();
+        assert![log.history().is_empty()];
+        assert_eq![log.plane().row_as_string(2).unwrap(), "line 8"];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn set_base_from_plane() {
+    use crate::{NcChannels, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let parent = NcPlane::new_pile_sized(nc, 0, 0, 10, 20).unwrap();
+        let popup = NcPlane::new_child_sized(parent, 2, 2, 3, 6).unwrap();
+
+        // a multi-byte EGC is stored in the pool of the source plane
+        let channels = NcChannels::from_rgb(0xAABBCC, 0x112233);
+        parent.set_base("░", NcStyle::Italic, channels).unwrap();
+        popup.set_base_from_plane(parent).unwrap();
+
+        let mut base = popup.base().unwrap();
+        assert_eq![base.egc(popup), "░"];
+        assert_eq![base.styles(), NcStyle::Italic];
+        assert_eq![base.channels(), channels];
+        base.release(popup);
+
+        // the cells written after inheriting the channels get their colors
+        let channels = NcChannels::from_rgb(0xFF0000, 0x00FF00);
+        parent.set_channels(channels);
+        popup.inherit_channels_from(parent);
+        assert_eq![popup.channels(), channels];
+        popup.putstr_yx(Some(1), Some(1), "x").unwrap();
+
+        let mut cell = crate::NcCell::new();
+        popup.at_yx_cell(1, 1, &mut cell).unwrap();
+        assert_eq![cell.egc(popup), "x"];
+        assert_eq![cell.channels(), channels];
+        cell.release(popup);
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn putc() {
+    use crate::{NcCell, NcChannels, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 5, 10).unwrap();
+
+        // a multi-byte glyph is stored in the pool of the plane
+        let mut cell = NcCell::new();
+        let channels = NcChannels::from_rgb(0xFF8800, 0x000044);
+        NcCell::prime(plane, &mut cell, "╬", NcStyle::Bold, channels).unwrap();
+
+        let positions = [(0, 0), (1, 3), (4, 9)];
+        for (y, x) in positions {
+            assert_eq![plane.putc_yx(y, x, &cell).unwrap(), 1];
+        }
+        plane.cursor_move_yx(2, 5).unwrap();
+        assert_eq![plane.putc(&cell).unwrap(), 1];
+        assert_eq![plane.cursor_yx(), (2, 6)];
+
+        let mut read = NcCell::new();
+        for (y, x) in positions.into_iter().chain([(2, 5)]) {
+            plane.at_yx_cell(y, x, &mut read).unwrap();
+            assert_eq![read.egc(plane), "╬"];
+            assert_eq![read.styles(), NcStyle::Bold];
+            assert_eq![read.channels(), channels];
+            read.release(plane);
+        }
+        plane.at_yx_cell(0, 1, &mut read).unwrap();
+        assert_eq![read.egc(plane), ""];
+
+        cell.release(plane);
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn fill_region() {
+    use crate::{NcCell, NcChannels, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 6, 8).unwrap();
+        plane.cursor_move_yx(5, 7).unwrap();
+
+        let mut cell = NcCell::new();
+        let channels = NcChannels::from_rgb(0x00FF00, 0x440000);
+        NcCell::prime(plane, &mut cell, "▒", NcStyle::Underline, channels).unwrap();
+        assert_eq![plane.fill_region(1, 2, 3, 4, &cell).unwrap(), 12];
+        assert_eq![plane.cursor_yx(), (5, 7)];
+
+        let mut read = NcCell::new();
+        for y in 0..6 {
+            for x in 0..8 {
+                plane.at_yx_cell(y, x, &mut read).unwrap();
+                if (1..4).contains(&y) && (2..6).contains(&x) {
+                    assert_eq![read.egc(plane), "▒"];
+                    assert_eq![read.styles(), NcStyle::Underline];
+                    assert_eq![read.channels(), channels];
+                } else {
+                    assert_eq![read.egc(plane), "", "{}×{}", y, x];
+                }
+                read.release(plane);
+            }
+        }
+
+        // out of bounds
+        assert![plane.fill_region(4, 0, 3, 1, &cell).is_err()];
+        assert![plane.fill_region(0, 6, 1, 3, &cell).is_err()];
+        assert![plane.fill_region(0, 0, 0, 1, &cell).is_err()];
+        assert![plane.fill_region(u32::MAX, 0, 1, 1, &cell).is_err()];
+
+        cell.release(plane);
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn set_rgb8() {
+    use crate::NcRgb;
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 1).unwrap();
+
+        plane.set_fg_rgb8(255, 0, 128);
+        plane.set_bg_rgb8(1, 2, 3);
+        assert_eq![plane.fg_rgb(), NcRgb(0xFF0080)];
+        assert_eq![plane.bg_rgb(), NcRgb(0x010203)];
+        assert![!plane.fg_default() && !plane.bg_default()];
+
+        // out of range components are saturated
+        plane.set_fg_rgb8_clipped(300, -20, 128);
+        plane.set_bg_rgb8_clipped(-1, 256, 7);
+        assert_eq![plane.fg_rgb(), NcRgb(0xFF0080)];
+        assert_eq![plane.bg_rgb(), NcRgb(0x00FF07)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn greyscale_region() {
+    use crate::{NcChannels, NcRgb, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 6).unwrap();
+        let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+
+        plane.set_fg_rgb(0xFF8000);
+        plane.set_bg_rgb(0x2040C0);
+        for y in 0..3 {
+            plane.putstr_yx(Some(y), Some(0), "abcdef").unwrap();
+        }
+        plane.set_bg_default();
+        plane.putstr_yx(Some(2), Some(2), "C").unwrap();
+
+        assert_eq![plane.greyscale_region(1, 1, 2, 3).unwrap(), 6];
+        for y in 0..3 {
+            for x in 0..6 {
+                let egc = plane.at_yx(y, x, &mut style, &mut channels).unwrap();
+                let (fg, bg) = (channels.fg_rgb(), channels.bg_rgb());
+                if (y, x) == (2, 2) {
+                    assert_eq![egc, "C"];
+                    assert![channels.bg_default_p()];
+                } else {
+                    assert_eq![egc, &"abcdef"[x as usize..x as usize + 1]];
+                }
+                if (1..3).contains(&y) && (1..4).contains(&x) {
+                    let [_, r, g, b] = fg.0.to_be_bytes();
+                    assert![r == g && g == b, "{}×{}", y, x];
+                    assert_ne![fg, NcRgb(0)];
+                    if (y, x) != (2, 2) {
+                        let [_, r, g, b] = bg.0.to_be_bytes();
+                        assert![r == g && g == b && !channels.bg_default_p()];
+                    }
+                } else {
+                    assert_eq![fg, NcRgb(0xFF8000)];
+                    assert_eq![bg, NcRgb(0x2040C0)];
+                }
+            }
+        }
+
+        assert![plane.greyscale_region(2, 0, 2, 1).is_err()];
+        assert![plane.greyscale_region(0, 1, 1, u32::MAX).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn strdup_egc_at() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 8).unwrap();
+        // a regular character, a wide one, and a multi-codepoint one
+        plane.putstr_yx(Some(0), Some(0), "a字e\u{301}").unwrap();
+
+        assert_eq![plane.strdup_egc_at(0, 0).unwrap(), "a"];
+        assert_eq![plane.strdup_egc_at(0, 1).unwrap(), "字"];
+        assert_eq![plane.strdup_egc_at(0, 2).unwrap(), "字"];
+        assert_eq![plane.strdup_egc_at(0, 3).unwrap(), "e\u{301}"];
+        assert_eq![plane.strdup_egc_at(1, 0).unwrap(), ""];
+
+        // the string outlives the contents of the plane
+        let egc = plane.strdup_egc_at(0, 1).unwrap();
+        plane.erase();
+        assert_eq![egc, "字"];
+
+        assert![plane.strdup_egc_at(2, 0).is_err()];
+        assert![plane.strdup_egc_at(0, 8).is_err()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn copy_to() {
+    use crate::{NcChannels, NcRgb, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let src = NcPlane::new_pile_sized(nc, 0, 0, 2, 4).unwrap();
+        let dst = NcPlane::new_pile_sized(nc, 0, 0, 4, 6).unwrap();
+        let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+
+        src.set_styles(NcStyle::Bold);
+        src.set_fg_rgb(0xFF8000);
+        src.putstr_yx(Some(0), Some(0), "ab字").unwrap();
+        src.set_styles(NcStyle::Italic);
+        src.putstr_yx(Some(1), Some(0), "cdef").unwrap();
+
+        src.copy_to(dst, 1, 1).unwrap();
+        assert_eq![dst.at_yx(1, 1, &mut style, &mut channels).unwrap(), "a"];
+        assert_eq![style, NcStyle::Bold];
+        assert_eq![channels.fg_rgb(), NcRgb(0xFF8000)];
+        assert_eq![dst.strdup_egc_at(1, 3).unwrap(), "字"];
+        assert_eq![dst.strdup_egc_at(1, 4).unwrap(), "字"];
+        assert_eq![dst.at_yx(2, 4, &mut style, &mut channels).unwrap(), "f"];
+        assert_eq![style, NcStyle::Italic];
+        assert_eq![dst.strdup_egc_at(0, 1).unwrap(), ""];
+        assert_eq![dst.strdup_egc_at(1, 0).unwrap(), ""];
+        assert_eq![dst.strdup_egc_at(1, 5).unwrap(), ""];
+
+        // clipped to the last row, and the wide glyph doesn't fit
+        dst.erase();
+        src.copy_to(dst, 3, 3).unwrap();
+        assert_eq![dst.strdup_egc_at(3, 3).unwrap(), "a"];
+        assert_eq![dst.strdup_egc_at(3, 4).unwrap(), "b"];
+        assert_eq![dst.strdup_egc_at(3, 5).unwrap(), ""];
+
+        // entirely outside
+        src.copy_to(dst, 4, 0).unwrap();
+        src.copy_to(dst, 0, 6).unwrap();
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn input_mode() {
+    use crate::{NcCursorShape, NcTextInputMode};
+
+    assert_eq![NcCursorShape::BlinkBlock.escape(), "\x1b[1 q"];
+    assert_eq![NcCursorShape::Block.escape(), "\x1b[2 q"];
+    assert_eq![NcCursorShape::BlinkUnderline.escape(), "\x1b[3 q"];
+    assert_eq![NcCursorShape::Underline.escape(), "\x1b[4 q"];
+    assert_eq![NcCursorShape::BlinkBar.escape(), "\x1b[5 q"];
+    assert_eq![NcCursorShape::Bar.escape(), "\x1b[6 q"];
+
+    assert_eq![NcTextInputMode::Normal.cursor_shape().escape(), "\x1b[2 q"];
+    assert_eq![NcTextInputMode::Insert.cursor_shape().escape(), "\x1b[6 q"];
+    assert_eq![NcTextInputMode::Visual.cursor_shape().escape(), "\x1b[2 q"];
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 2).unwrap();
+        assert_eq![plane.input_mode(), NcTextInputMode::Normal];
+
+        // without changing the cursor of the terminal running the tests
+        plane.store_input_mode(NcTextInputMode::Insert);
+        assert_eq![plane.input_mode(), NcTextInputMode::Insert];
+        plane.store_input_mode(NcTextInputMode::Visual);
+        assert_eq![plane.input_mode(), NcTextInputMode::Visual];
+
+        plane.destroy().unwrap();
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn box_glyph_sets() {
+    use crate::{NcBoxMask, NcChannels, NcStyle};
+
+    // the corners, and then a horizontal and a vertical edge
+    fn corners(plane: &mut NcPlane) -> String {
+        [(0, 0), (0, 3), (2, 0), (2, 3), (0, 1), (1, 0)]
+            .map(|(y, x)| plane.strdup_egc_at(y, x).unwrap())
+            .concat()
+    }
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 3, 4).unwrap();
+
+        plane
+            .ascii_box(NcStyle::None, NcChannels::new(), 2, 3, NcBoxMask::None)
+            .unwrap();
+        assert_eq![corners(plane), "/\\\\/-|"];
+
+        plane.erase();
+        plane
+            .double_box(NcStyle::None, NcChannels::new(), 2, 3, NcBoxMask::None)
+            .unwrap();
+        assert_eq![corners(plane), "╔╗╚╝═║"];
+
+        plane.erase();
+        plane
+            .rounded_box(NcStyle::None, NcChannels::new(), 2, 3, NcBoxMask::None)
+            .unwrap();
+        assert_eq![corners(plane), "╭╮╰╯─│"];
+
+        // the sized variants measure from the cursor
+        plane.erase();
+        plane.cursor_move_yx(0, 0).unwrap();
+        plane
+            .rounded_box_sized(NcStyle::None, NcChannels::new(), 3, 4, NcBoxMask::None)
+            .unwrap();
+        assert_eq![corners(plane), "╭╮╰╯─│"];
+        plane.erase();
+        plane
+            .double_box_sized(NcStyle::None, NcChannels::new(), 3, 4, NcBoxMask::None)
+            .unwrap();
+        assert_eq![corners(plane), "╔╗╚╝═║"];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn autogrow() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 5).unwrap();
+        assert![!plane.autogrow_p()];
+        assert![plane.putstr("abcdefgh").is_err()];
+
+        // without scrolling, the plane grows to the right
+        plane.erase();
+        assert![!plane.set_autogrow(true)];
+        assert![plane.autogrow_p()];
+        plane.putstr("abcdefgh").unwrap();
+        assert_eq![plane.dim_yx(), (1, 8)];
+        assert_eq![plane.row_as_string(0).unwrap(), "abcdefgh"];
+
+        assert![plane.set_autogrow(false)];
+        assert![!plane.autogrow_p()];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn diff() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 6).unwrap();
+        let copy = NcPlane::new_pile_sized(nc, 0, 0, 2, 6).unwrap();
+        for p in [&mut *plane, &mut *copy] {
+            p.putstr_yx(Some(0), Some(0), "abcdef").unwrap();
+            p.putstr_yx(Some(1), Some(0), "漢字").unwrap();
+        }
+        let snapshot = plane.snapshot().unwrap();
+        assert_eq![snapshot.dim_yx(), (2, 6)];
+        assert![plane.diff(&snapshot).unwrap().is_empty()];
+
+        plane.putstr_yx(Some(0), Some(2), "XY").unwrap();
+        plane.putstr_yx(Some(1), Some(0), "zz").unwrap();
+        plane.putstr_yx(Some(1), Some(4), "テ").unwrap();
+        let diff = plane.diff(&snapshot).unwrap();
+        let changed: Vec<_> = diff
+            .iter()
+            .map(|d| (d.row, d.col, d.egc.as_str()))
+            .collect();
+        assert_eq![
+            changed,
+            [
+                (0, 2, "X"),
+                (0, 3, "Y"),
+                (1, 0, "z"),
+                (1, 1, "z"),
+                (1, 4, "テ")
+            ]
+        ];
+
+        copy.apply_diff(&diff).unwrap();
+        assert_eq![copy.content_as_string(), plane.content_as_string()];
+        assert![copy.diff(&plane.snapshot().unwrap()).unwrap().is_empty()];
+
+        // after a resize every cell is returned, except the 2 right halves
+        plane.resize_simple(3, 6).unwrap();
+        assert_eq![plane.diff(&snapshot).unwrap().len(), 3 * 6 - 2];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn cell_grid() {
+    use crate::{NcCell, NcChannels, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4).unwrap();
+        plane.set_styles(NcStyle::Bold);
+        plane.set_channels(NcChannels::from_rgb(0x112233, 0x445566));
+        plane.putstr_yx(Some(0), Some(0), "ab漢").unwrap();
+        plane.set_styles(NcStyle::None);
+        plane.putstr_yx(Some(1), Some(1), "cd").unwrap();
+        plane
+            .putstr_yx(Some(1), Some(3), "e\u{301}\u{301}")
+            .unwrap();
+
+        // round trip
+        let mut grid = plane.to_cell_grid().unwrap();
+        assert_eq![(grid.len(), grid[0].len()), (2, 4)];
+        let rows: Vec<&[NcCell]> = grid.iter().map(|row| row.as_slice()).collect();
+        let copy = NcPlane::from_cell_grid(nc, &rows, Some(&*plane), 3, 5).unwrap();
+        assert_eq![copy.yx(), (3, 5)];
+        assert_eq![copy.dim_yx(), (2, 4)];
+        assert_eq![copy.content_as_string(), "ab漢\n cde\u{301}\u{301}"];
+        assert_eq![copy.snapshot().unwrap(), plane.snapshot().unwrap()];
+        for row in grid.iter_mut() {
+            for cell in row.iter_mut() {
+                cell.release(plane);
+            }
+        }
+
+        // invalid grids
+        let c = NcCell::from_char7b('x').unwrap();
+        assert![NcPlane::from_cell_grid(nc, &[], None, 0, 0).is_err()];
+        assert![NcPlane::from_cell_grid(nc, &[&[]], None, 0, 0).is_err()];
+        assert![NcPlane::from_cell_grid(nc, &[&[c, c], &[c]], None, 0, 0).is_err()];
+        let mut pooled = NcCell::new();
+        NcCell::load(plane, &mut pooled, "e\u{301}\u{301}").unwrap();
+        let e = NcPlane::from_cell_grid(nc, &[&[c, pooled]], None, 0, 0).unwrap_err();
+        assert![e.msg.contains("the EGC at 0×1 is in the pool")];
+        pooled.release(plane);
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn channels() {
+    use crate::{NcChannels, NcRgb};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 1).unwrap();
+        assert_eq![plane.channels(), NcChannels::with_default()];
+
+        let channels = NcChannels::from_rgb(0x112233, 0x445566);
+        plane.set_channels(channels);
+        assert_eq![plane.channels(), channels];
+        assert_eq![plane.fg_rgb8(), (0x11, 0x22, 0x33)];
+        assert_eq![plane.bg_rgb8(), (0x44, 0x55, 0x66)];
+
+        plane.set_fg_rgb8(0xAA, 0xBB, 0xCC);
+        plane.set_bg_rgb8(0xDD, 0xEE, 0xFF);
+        assert_eq![plane.fg_rgb(), NcRgb(0xAABBCC)];
+        assert_eq![plane.bg_rgb(), NcRgb(0xDDEEFF)];
+        assert_eq![plane.channels(), NcChannels::from_rgb(0xAABBCC, 0xDDEEFF)];
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn with_scrolling() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4).unwrap();
+        plane.set_scrolling(true);
+
+        let res = plane.with_scrolling(false, |p| {
+            assert![!p.scrolling_p()];
+            p.putstr("abcdefghij").is_err()
+        });
+        assert![res];
+        assert![plane.scrolling_p()];
+
+        plane.set_scrolling(false);
+        assert![plane.with_scrolling(true, |p| p.putstr("\nklm").is_ok())];
+        assert_eq![plane.row_as_string(1).unwrap(), "klm"];
+        assert![!plane.scrolling_p()];
+
+        // the state is restored after a panic
+        #[cfg(feature = "std")]
+        {
+            use std::panic::{catch_unwind, AssertUnwindSafe};
+            let res = catch_unwind(AssertUnwindSafe(|| {
+                plane.with_scrolling(true, |_| panic!["in the closure"])
+            }));
+            assert![res.is_err()];
+            assert![!plane.scrolling_p()];
+        }
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "animation")]
+fn animation() {
+    use crate::NcVisual;
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 2, 4).unwrap();
+        assert![plane.bind_animation(vec![], 10.0).is_err()];
+        for fps in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let frame = NcVisual::from_rgba(&[0xFF; 4], 1, 4, 1).unwrap();
+            assert![plane.bind_animation(vec![&mut *frame], fps).is_err()];
+            frame.destroy();
+        }
+
+        let frames = vec![
+            NcVisual::from_rgba(&[0xFF; 4 * 4], 2, 8, 2).unwrap(),
+            NcVisual::from_rgba(&[0x80; 4 * 4], 2, 8, 2).unwrap(),
+        ];
+        let mut animation = plane.bind_animation(frames, 10.0).unwrap();
+        assert![!animation.is_playing()];
+        assert![!animation.update(nc).unwrap()];
+
+        animation.play();
+        assert![animation.is_playing()];
+        assert![animation.update(nc).unwrap()];
+        // the next frame is due in 100ms
+        assert![!animation.update(nc).unwrap()];
+
+        animation.set_fps(20.0).unwrap();
+        assert![(animation.fps() - 20.0).abs() < 0.01];
+        assert![animation.set_fps(0.0).is_err()];
+        animation.pause();
+        assert![!animation.is_playing()];
+
+        let (plane, frames) = animation.stop();
+        assert_eq![frames.len(), 2];
+        for frame in frames {
+            frame.destroy();
+        }
+        plane.destroy().unwrap();
+
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn putstr_styled() {
+    use crate::{NcChannels, NcStyle};
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_pile_sized(nc, 0, 0, 1, 10).unwrap();
+        let red = NcChannels::from_rgb(0xFF0000, 0x000000);
+
+        assert_eq![plane.putstr_styled(NcStyle::Bold, red, "red").unwrap(), 3];
+        assert_eq![plane.styles(), NcStyle::None];
+        assert_eq![plane.channels(), NcChannels::with_default()];
+        assert_eq![plane.putstr("plain").unwrap(), 5];
+
+        let (mut style, mut channels) = (NcStyle::None, NcChannels::new());
+        assert_eq![plane.at_yx(0, 0, &mut style, &mut channels).unwrap(), "r"];
+        assert_eq![(style, channels), (NcStyle::Bold, red)];
+        assert_eq![plane.at_yx(0, 3, &mut style, &mut channels).unwrap(), "p"];
+        assert_eq![
+            (style, channels),
+            (NcStyle::None, NcChannels::with_default())
+        ];
+
+        // restored on error too
+        assert![plane
+            .putstr_styled(NcStyle::Italic, red, "overflow")
+            .is_err()];
+        assert_eq![plane.styles(), NcStyle::None];
+        assert_eq![plane.channels(), NcChannels::with_default()];
+
+        notcurses_stop(nc);
+    }
+}
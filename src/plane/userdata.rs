@@ -0,0 +1,264 @@
+//! Rust-side data stored in the user pointer of an `NcPlane`.
+//
+// The safe API owns the plane's `userptr` when it points to a boxed
+// `NcPlaneUserData` allocated by this module. The allocation starts with a
+// tag, so that any other user pointer, set with the raw
+// `ncplane_set_userptr()` C API or the `NcPlaneOptions.userptr` field, is
+// never used as user data nor freed. The safe setters replace such a
+// foreign user pointer, without freeing it.
+//
+// The tag is cleared before the allocation is freed, when the plane is
+// destroyed with `NcPlane::destroy`, and when the context is stopped with
+// `Nc::stop`. A plane destroyed by the C API leaks its user data.
+//
+// The `NcPlaneOptions` built with a resize closure own a template of the
+// user data, that is copied to every plane created from them, and dropped
+// along with the options.
+
+use core::{any::Any, cell::RefCell, ffi::c_void};
+
+use super::{input_mode::NcTextInputMode, scrollback::NcPlaneScrollback};
+use crate::{
+    c_api::{self, NcResult_i32, NCRESULT_ERR, NCRESULT_OK},
+    NcPlane, NcResult,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, rc::Rc};
+#[cfg(feature = "std")]
+use std::rc::Rc;
+
+/// A shared Rust closure called when the parent of an [`NcPlane`] is resized.
+pub(crate) type NcResizeFnShared = Rc<RefCell<dyn FnMut(&mut NcPlane) -> NcResult<()>>>;
+
+/// The data stored behind the user pointer of an [`NcPlane`].
+#[derive(Default)]
+pub(crate) struct NcPlaneUserData {
+    pub(crate) resize_fn: Option<NcResizeFnShared>,
+    pub(crate) data: Option<Box<dyn Any>>,
+    pub(crate) scrollback: Option<NcPlaneScrollback>,
+    pub(crate) input_mode: Option<NcTextInputMode>,
+    #[cfg(feature = "regex")]
+    pub(crate) highlights: Option<super::highlight::NcPlaneHighlights>,
+}
+
+/// Who owns the user data behind a user pointer.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Owner {
+    /// A plane.
+    Plane,
+    /// An `NcPlaneOptions`, as a template for the planes created from it.
+    Options,
+}
+
+// The tags are the addresses of these statics, which can't be the value
+// at the start of any allocation made by someone else.
+static PLANE_TAG: u8 = 0;
+static OPTIONS_TAG: u8 = 0;
+
+impl Owner {
+    fn tag(self) -> usize {
+        match self {
+            Owner::Plane => &PLANE_TAG as *const u8 as usize,
+            Owner::Options => &OPTIONS_TAG as *const u8 as usize,
+        }
+    }
+}
+
+/// The allocation behind a user pointer owned by this module.
+#[repr(C)]
+struct Tagged {
+    /// The tag of the owner, or 0 once freed.
+    tag: usize,
+    userdata: NcPlaneUserData,
+}
+
+impl Tagged {
+    fn into_userptr(userdata: NcPlaneUserData, owner: Owner) -> *mut c_void {
+        Box::into_raw(Box::new(Tagged { tag: owner.tag(), userdata })) as *mut c_void
+    }
+
+    /// Returns the tagged allocation behind `userptr`, if it's owned by `owner`.
+    ///
+    /// # Safety
+    /// `userptr` must be null or point to at least `size_of::<usize>()`
+    /// readable bytes.
+    unsafe fn from_userptr<'a>(userptr: *mut c_void, owner: Owner) -> Option<&'a mut Tagged> {
+        if userptr.is_null() || (userptr as *const usize).read_unaligned() != owner.tag() {
+            return None;
+        }
+        Some(&mut *(userptr as *mut Tagged))
+    }
+
+    /// Frees the tagged allocation behind `userptr`, if it's owned by `owner`.
+    ///
+    /// # Safety
+    /// Same as [`from_userptr`][Tagged::from_userptr], and the user pointer
+    /// must not be used afterwards.
+    unsafe fn free(userptr: *mut c_void, owner: Owner) {
+        if let Some(tagged) = Tagged::from_userptr(userptr, owner) {
+            tagged.tag = 0;
+            drop(Box::from_raw(tagged as *mut Tagged));
+        }
+    }
+}
+
+impl NcPlaneUserData {
+    /// Moves the user data to the heap and returns it as a user pointer
+    /// owned by a plane.
+    pub(crate) fn into_userptr(self) -> *mut c_void {
+        Tagged::into_userptr(self, Owner::Plane)
+    }
+
+    /// Moves the user data to the heap and returns it as a user pointer
+    /// owned by an `NcPlaneOptions`.
+    pub(crate) fn into_options_userptr(self) -> *mut c_void {
+        Tagged::into_userptr(self, Owner::Options)
+    }
+
+    /// Returns a copy of the resize closure of the user data owned by an
+    /// `NcPlaneOptions`, if `userptr` is one.
+    ///
+    /// # Safety
+    /// `userptr` must be null or point to at least `size_of::<usize>()`
+    /// readable bytes.
+    pub(crate) unsafe fn options_resize_fn(userptr: *mut c_void) -> Option<NcResizeFnShared> {
+        Tagged::from_userptr(userptr, Owner::Options)?
+            .userdata
+            .resize_fn
+            .clone()
+    }
+
+    /// Drops the user data owned by an `NcPlaneOptions`. Does nothing if
+    /// `userptr` is not one.
+    ///
+    /// # Safety
+    /// Same as [`options_resize_fn`][Self::options_resize_fn], and the user
+    /// pointer must not be used afterwards.
+    pub(crate) unsafe fn drop_options_userptr(userptr: *mut c_void) {
+        Tagged::free(userptr, Owner::Options);
+    }
+
+    /// Gives a new `plane` its own copy of the user data of the options it
+    /// was created with, if they have any.
+    ///
+    /// # Safety
+    /// `plane` must be a valid plane.
+    pub(crate) unsafe fn adopt_options_userptr(plane: *mut NcPlane) {
+        if let Some(resize_fn) = Self::options_resize_fn(c_api::ncplane_userptr(plane)) {
+            let userdata = NcPlaneUserData { resize_fn: Some(resize_fn), ..Default::default() };
+            c_api::ncplane_set_userptr(plane, userdata.into_userptr());
+        }
+    }
+
+    /// Returns a mutable reference to the user data of `plane`, or `None`
+    /// if its user pointer is null or not owned by this module.
+    ///
+    /// # Safety
+    /// `plane` must be a valid plane, and the returned reference must not
+    /// outlive its user data.
+    pub(crate) unsafe fn get<'a>(plane: *mut NcPlane) -> Option<&'a mut NcPlaneUserData> {
+        Tagged::from_userptr(c_api::ncplane_userptr(plane), Owner::Plane)
+            .map(|tagged| &mut tagged.userdata)
+    }
+
+    /// Returns a shared reference to the user data of `plane`, or `None`
+    /// if its user pointer is null or not owned by this module.
+    pub(crate) fn get_ref(plane: &NcPlane) -> Option<&NcPlaneUserData> {
+        // ncplane_userptr only reads the plane
+        let plane = plane as *const NcPlane as *mut NcPlane;
+        unsafe { NcPlaneUserData::get(plane) }.map(|userdata| &*userdata)
+    }
+
+    /// Returns a mutable reference to the user data of `plane`, creating it
+    /// if it doesn't exist yet.
+    ///
+    /// A user pointer not owned by this module is replaced, without freeing it.
+    ///
+    /// # Safety
+    /// `plane` must be a valid plane, and the returned reference must not
+    /// outlive its user data.
+    pub(crate) unsafe fn get_or_insert<'a>(plane: *mut NcPlane) -> &'a mut NcPlaneUserData {
+        if NcPlaneUserData::get(plane).is_none() {
+            c_api::ncplane_set_userptr(plane, NcPlaneUserData::default().into_userptr());
+        }
+        NcPlaneUserData::get(plane).unwrap()
+    }
+
+    /// Drops the user data of `plane` and clears its user pointer, if the
+    /// user data doesn't hold anything.
+    ///
+    /// # Safety
+    /// `plane` must be a valid plane.
+    pub(crate) unsafe fn drop_if_empty(plane: *mut NcPlane) {
+        if let Some(userdata) = NcPlaneUserData::get(plane) {
+            if userdata.resize_fn.is_none()
+                && userdata.data.is_none()
+                && userdata.scrollback.is_none()
+                && userdata.input_mode.is_none()
+                && userdata.has_no_highlights()
+            {
+                let userptr = c_api::ncplane_set_userptr(plane, core::ptr::null_mut());
+                NcPlaneUserData::drop_userptr(userptr);
+            }
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    fn has_no_highlights(&self) -> bool {
+        self.highlights.is_none()
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn has_no_highlights(&self) -> bool {
+        true
+    }
+
+    /// Drops the user data behind a user pointer previously returned by
+    /// [`into_userptr`][Self::into_userptr]. Does nothing if it's null, or
+    /// if it's not owned by a plane.
+    ///
+    /// # Safety
+    /// Same as [`options_resize_fn`][Self::options_resize_fn], and the user
+    /// pointer must not be used afterwards.
+    pub(crate) unsafe fn drop_userptr(userptr: *mut c_void) {
+        Tagged::free(userptr, Owner::Plane);
+    }
+
+    /// Drops the user data of all the planes in the pile of `plane`, and
+    /// clears their user pointers.
+    ///
+    /// # Safety
+    /// `plane` must be a valid plane.
+    pub(crate) unsafe fn drop_pile(plane: *mut NcPlane) {
+        let mut plane = c_api::ncpile_top(plane);
+        while !plane.is_null() {
+            if NcPlaneUserData::get(plane).is_some() {
+                let userptr = c_api::ncplane_set_userptr(plane, core::ptr::null_mut());
+                NcPlaneUserData::drop_userptr(userptr);
+            }
+            plane = c_api::ncplane_below(plane);
+        }
+    }
+}
+
+/// The C resize callback that calls the Rust resize closure of the plane.
+pub(crate) unsafe extern "C" fn ncresizefn_trampoline(plane: *mut NcPlane) -> NcResult_i32 {
+    // clone the closure so that it outlives any change made to the user data
+    let resize_fn = match NcPlaneUserData::get(plane)
+        .and_then(|data| data.resize_fn.clone())
+        .or_else(|| NcPlaneUserData::options_resize_fn(c_api::ncplane_userptr(plane)))
+    {
+        Some(f) => f,
+        None => return NCRESULT_OK,
+    };
+    let res = match resize_fn.try_borrow_mut() {
+        Ok(mut f) => (*f)(&mut *plane),
+        // the closure is already running higher up the stack
+        Err(_) => return NCRESULT_ERR,
+    };
+    match res {
+        Ok(()) => NCRESULT_OK,
+        Err(e) => e.int,
+    }
+}
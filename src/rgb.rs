@@ -1,5 +1,6 @@
 //!
 
+use crate::NcPalette;
 use c_api::{NcRgb_u32, NcRgba_u32};
 
 /// 24 bits broken into 3x RGB components.
@@ -53,7 +54,95 @@ impl NcRgba {
                 | b as NcRgba_u32,
         )
     }
+
+    /// New opaque RGBA color from hue, saturation and lightness.
+    ///
+    /// The hue is in degrees and wraps around `360.0`, while the saturation
+    /// and the lightness are clamped to the `0.0..=1.0` range.
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let h = ((h % 360.0) + 360.0) % 360.0 / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        let c = (1.0 - abs(2.0 * l - 1.0)) * s;
+        let x = c * (1.0 - abs(h % 2.0 - 1.0));
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        let to_u8 = |v: f32| ((v + m).clamp(0.0, 1.0) * 255.0 + 0.5) as u8;
+        Self::new(to_u8(r), to_u8(g), to_u8(b), 0xFF)
+    }
+
+    /// Returns the hue in degrees, and the saturation and the lightness
+    /// in the `0.0..=1.0` range. The alpha component is ignored.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.rgb_f32();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        if d == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = d / (1.0 - abs(2.0 * l - 1.0));
+        let h = if max == r {
+            ((g - b) / d) % 6.0
+        } else if max == g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        ((h * 60.0 + 360.0) % 360.0, s.min(1.0), l)
+    }
+
+    /// Returns the relative luminance in the `0.0..=1.0` range,
+    /// using the Rec. 709 coefficients. The alpha component is ignored.
+    pub fn luminance(&self) -> f32 {
+        let (r, g, b) = self.rgb_f32();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Returns opaque black or white, whichever is more readable over this color.
+    ///
+    /// It's white for colors with a [`luminance`][Self::luminance] lower than
+    /// `0.5`, and black otherwise.
+    pub fn contrasting(&self) -> NcRgba {
+        if self.luminance() < 0.5 {
+            Self::new(0xFF, 0xFF, 0xFF, 0xFF)
+        } else {
+            Self::new(0, 0, 0, 0xFF)
+        }
+    }
+
+    /// Returns the [`nearest`][NcPalette#method.nearest] color in the
+    /// `palette`, keeping the alpha component.
+    pub fn quantize(&self, palette: &NcPalette) -> NcRgba {
+        let index = palette.nearest(*self);
+        Self(self.0 & 0xFF00_0000 | palette.get(index).0 & 0xFF_FFFF)
+    }
+
+    /// Returns the RGB components normalized to the `0.0..=1.0` range.
+    fn rgb_f32(&self) -> (f32, f32, f32) {
+        (
+            ((self.0 >> 16) & 0xFF) as f32 / 255.0,
+            ((self.0 >> 8) & 0xFF) as f32 / 255.0,
+            (self.0 & 0xFF) as f32 / 255.0,
+        )
+    }
 }
+/// The absolute value of a float, which is not in `core` for our MSRV.
+#[inline]
+fn abs(v: f32) -> f32 {
+    v.max(-v)
+}
+
 mod core_impls {
     use super::{
         c_api::{NcRgb_u32, NcRgba_u32},
@@ -174,7 +263,8 @@ mod core_impls {
 
     #[cfg(test)]
     mod test {
-        use super::{NcRgb, NcRgba};
+        use super::{super::abs, NcRgb, NcRgba};
+        use crate::NcPalette;
 
         #[test]
         fn rgbx_from() {
@@ -196,6 +286,93 @@ mod core_impls {
             assert_eq!(rgba_arr, <[u8; 4]>::from(rgba));
             assert_eq!(rgba_tup, <(u8, u8, u8, u8)>::from(rgba));
         }
+
+        #[test]
+        fn rgba_hsl() {
+            let colors = [
+                NcRgba::new(0, 0, 0, 0xFF),
+                NcRgba::new(0xFF, 0xFF, 0xFF, 0xFF),
+                NcRgba::new(0xFF, 0, 0, 0xFF),
+                NcRgba::new(0x11, 0x22, 0x33, 0xFF),
+                NcRgba::new(0x80, 0xC0, 0x40, 0xFF),
+                NcRgba::new(0xFA, 0x07, 0x9B, 0xFF),
+            ];
+            for color in colors {
+                let (h, s, l) = color.to_hsl();
+                let back = NcRgba::from_hsl(h, s, l);
+                for shift in [16, 8, 0] {
+                    let (a, b) = ((back.0 >> shift) & 0xFF, (color.0 >> shift) & 0xFF);
+                    assert![a.abs_diff(b) <= 1, "{color} -> {h} {s} {l}"];
+                }
+            }
+
+            let (h, s, l) = NcRgba::new(0, 0xFF, 0, 0xFF).to_hsl();
+            assert![abs(h - 120.0) < 0.01 && abs(s - 1.0) < 0.01 && abs(l - 0.5) < 0.01];
+
+            // out of range inputs are wrapped or clamped
+            assert_eq![
+                NcRgba::from_hsl(480.0, 2.0, 0.5),
+                NcRgba::new(0, 0xFF, 0, 0xFF)
+            ];
+            assert_eq![
+                NcRgba::from_hsl(-240.0, 1.0, -1.0),
+                NcRgba::new(0, 0, 0, 0xFF)
+            ];
+        }
+
+        #[test]
+        fn rgba_luminance() {
+            let black = NcRgba::new(0, 0, 0, 0xFF).luminance();
+            let blue = NcRgba::new(0, 0, 0xFF, 0xFF).luminance();
+            let red = NcRgba::new(0xFF, 0, 0, 0xFF).luminance();
+            let green = NcRgba::new(0, 0xFF, 0, 0xFF).luminance();
+            let white = NcRgba::new(0xFF, 0xFF, 0xFF, 0xFF).luminance();
+            assert![black < blue && blue < red && red < green && green < white];
+            assert![abs(black) < 0.001 && abs(white - 1.0) < 0.001];
+        }
+
+        #[test]
+        fn rgba_contrasting() {
+            let (black, white) = (
+                NcRgba::new(0, 0, 0, 0xFF),
+                NcRgba::new(0xFF, 0xFF, 0xFF, 0xFF),
+            );
+            for dark in [
+                black,
+                NcRgba::new(0, 0, 0x80, 0xFF),
+                NcRgba::new(0x60, 0x20, 0x20, 0),
+            ] {
+                assert_eq![dark.contrasting(), white];
+            }
+            for light in [
+                white,
+                NcRgba::new(0, 0xFF, 0, 0xFF),
+                NcRgba::new(0xF0, 0xE0, 0x80, 0),
+            ] {
+                assert_eq![light.contrasting(), black];
+            }
+        }
+
+        #[test]
+        fn rgba_quantize() {
+            let mut palette = NcPalette { chans: [0; 256] };
+            palette.set(1, NcRgb::new(0xFF, 0, 0));
+            palette.set(2, NcRgb::new(0, 0xFF, 0));
+            palette.set(3, NcRgb::new(0, 0, 0xFF));
+            palette.set(4, NcRgb::new(0xFF, 0xFF, 0xFF));
+            palette.set(5, NcRgb::new(0x80, 0x80, 0x80));
+
+            assert_eq![palette.nearest(NcRgba::new(0xF0, 0x10, 0x10, 0xFF)), 1];
+            assert_eq![palette.nearest(NcRgba::new(0x90, 0x80, 0x70, 0xFF)), 5];
+            assert_eq![palette.nearest(NcRgba::new(0xF0, 0xF0, 0xE0, 0xFF)), 4];
+            // ties resolve to the lowest index
+            assert_eq![palette.nearest(NcRgba::new(0x10, 0x10, 0x10, 0xFF)), 0];
+
+            assert_eq![
+                NcRgba::new(0x20, 0xE0, 0x30, 0x40).quantize(&palette),
+                NcRgba::new(0, 0xFF, 0, 0x40)
+            ];
+        }
     }
 }
 
@@ -0,0 +1,66 @@
+//! `NcVisualFrameIter`
+
+use core::iter::FusedIterator;
+
+use crate::{NcResult, NcVisual};
+
+/// An iterator over the frames of an [`NcVisual`], that decodes a new frame
+/// on each iteration.
+///
+/// Created with [`NcVisual::frames`].
+///
+/// Each item means that the next frame has been decoded into the visual, and
+/// can now be blitted. The iteration ends when the last frame is reached,
+/// and then the visual is rewound to the first frame, like with
+/// [`decode_loop`][NcVisual#method.decode_loop].
+#[derive(Debug)]
+pub struct NcVisualFrameIter<'a> {
+    visual: &'a mut NcVisual,
+    done: bool,
+}
+
+impl NcVisualFrameIter<'_> {
+    /// Returns a reference to the visual, with the current frame.
+    pub fn visual(&mut self) -> &mut NcVisual {
+        self.visual
+    }
+}
+
+impl Iterator for NcVisualFrameIter<'_> {
+    type Item = NcResult<()>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.visual.decode_loop() {
+            Ok(0) => Some(Ok(())),
+            Ok(_) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl FusedIterator for NcVisualFrameIter<'_> {}
+
+/// # NcVisual methods: frames
+impl NcVisual {
+    /// Returns an iterator that decodes the following frames of the visual.
+    ///
+    /// The first frame is decoded when the visual is created, so it's not
+    /// yielded. The iterator ends after the last frame, rewinding the visual
+    /// to the first one, so that calling this method again starts over.
+    ///
+    /// The iteration also ends after the first error.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn frames(&mut self) -> NcVisualFrameIter<'_> {
+        NcVisualFrameIter { visual: self, done: false }
+    }
+}
@@ -0,0 +1,106 @@
+//! `NcVisual` interoperability with the [`image`] crate.
+
+use image::{codecs::jpeg::JpegEncoder, DynamicImage, ImageFormat, RgbaImage};
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use crate::{NcError, NcResult, NcVisual};
+
+/// # NcVisual constructors from `image` types
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "image-decode")))]
+impl NcVisual {
+    /// Decodes an in-memory image, detecting its format, into a new `NcVisual`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_image_bytes<'a>(data: &[u8]) -> NcResult<&'a mut NcVisual> {
+        let image = image::load_from_memory(data)
+            .map_err(|e| NcError::new_msg(&format!["NcVisual::from_image_bytes(data) {}", e]))?;
+        Self::from_image(&image)
+    }
+
+    /// Decodes an in-memory PNG image into a new `NcVisual`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_png_bytes<'a>(data: &[u8]) -> NcResult<&'a mut NcVisual> {
+        let image = image::load_from_memory_with_format(data, ImageFormat::Png)
+            .map_err(|e| NcError::new_msg(&format!["NcVisual::from_png_bytes(data) {}", e]))?;
+        Self::from_image(&image)
+    }
+
+    /// Decodes an in-memory JPEG image into a new `NcVisual`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_jpeg_bytes<'a>(data: &[u8]) -> NcResult<&'a mut NcVisual> {
+        let image = image::load_from_memory_with_format(data, ImageFormat::Jpeg)
+            .map_err(|e| NcError::new_msg(&format!["NcVisual::from_jpeg_bytes(data) {}", e]))?;
+        Self::from_image(&image)
+    }
+
+    /// New `NcVisual` from an already decoded [`DynamicImage`].
+    ///
+    /// Images in any other color type are converted to 8-bit RGBA first.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn from_image<'a>(image: &DynamicImage) -> NcResult<&'a mut NcVisual> {
+        match image {
+            DynamicImage::ImageRgba8(rgba) => {
+                let (cols, rows) = rgba.dimensions();
+                Self::from_rgba(rgba.as_raw(), rows, cols * 4, cols)
+            }
+            _ => Self::from_image(&DynamicImage::ImageRgba8(image.to_rgba8())),
+        }
+    }
+}
+
+/// # NcVisual methods for `image` types
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "image-decode")))]
+impl NcVisual {
+    /// Copies the pixels of this `NcVisual` into a new [`RgbaImage`].
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_image(&self) -> NcResult<RgbaImage> {
+        let (rows, cols) = self
+            .geom(None, None)?
+            .pix_yx
+            .ok_or_else(|| NcError::new_msg("NcVisual.to_image() unknown pixel geometry"))?;
+        RgbaImage::from_raw(cols, rows, self.to_rgba_vec()?)
+            .ok_or_else(|| NcError::new_msg("NcVisual.to_image() invalid buffer"))
+    }
+
+    /// Encodes the pixels of this `NcVisual` as a PNG image, and saves it to
+    /// the file at `path`.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn save_png(&self, path: impl AsRef<Path>) -> NcResult<()> {
+        let path = path.as_ref();
+        self.to_image()?
+            .save_with_format(path, ImageFormat::Png)
+            .map_err(|e| NcError::new_msg(&format!["NcVisual.save_png({:?}) {}", path, e]))
+    }
+
+    /// Encodes the pixels of this `NcVisual` as a JPEG image with the given
+    /// `quality` (1-100), and saves it to the file at `path`.
+    ///
+    /// The alpha channel is discarded, since JPEG doesn't support it.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn save_jpeg(&self, path: impl AsRef<Path>, quality: u8) -> NcResult<()> {
+        let path = path.as_ref();
+        let err = |e: &dyn Display| {
+            NcError::new_msg(&format![
+                "NcVisual.save_jpeg({:?}, {}) {}",
+                path, quality, e
+            ])
+        };
+        let rgb = DynamicImage::ImageRgba8(self.to_image()?).to_rgb8();
+        let mut file = BufWriter::new(File::create(path).map_err(|e| err(&e))?);
+        JpegEncoder::new_with_quality(&mut file, quality.clamp(1, 100))
+            .encode_image(&rgb)
+            .map_err(|e| err(&e))?;
+        file.flush().map_err(|e| err(&e))
+    }
+}
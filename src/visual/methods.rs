@@ -3,14 +3,15 @@
 use core::ptr::{null, null_mut};
 
 #[cfg(not(feature = "std"))]
-use alloc::format;
+use alloc::{format, string::String, vec::Vec};
 
 use core::ffi::c_void;
 
+use super::sixel::sixel_to_rgba;
 use crate::{
     c_api::{self, NcResult_i32, NCRESULT_ERR},
     cstring, error, error_ref_mut, Nc, NcBlitter, NcDirect, NcError, NcPalette, NcPixel, NcPlane,
-    NcResult, NcRgba, NcScale, NcTime, NcVisual, NcVisualGeometry, NcVisualOptions,
+    NcResult, NcRgba, NcScale, NcTime, NcVisual, NcVisualFlag, NcVisualGeometry, NcVisualOptions,
 };
 
 /// # NcVisual Constructors & destructors
@@ -43,13 +44,26 @@ impl NcVisual {
     /// Opens an `NcVisual` at `file`, extracts the codec and parameters and
     /// decodes the first image to memory.
     ///
+    /// On failure the error message tells whether notcurses has been built
+    /// without multimedia support.
+    ///
     /// *C style function: [ncvisual_from_file()][c_api::ncvisual_from_file].*
     pub fn from_file<'a>(file: &str) -> NcResult<&'a mut NcVisual> {
         let cs = cstring![file];
-        error_ref_mut![
-            unsafe { c_api::ncvisual_from_file(cs.as_ptr()) },
-            &format!("NcVisual::from_file({})", file)
-        ]
+        let visual = unsafe { c_api::ncvisual_from_file(cs.as_ptr()) };
+        if visual.is_null() {
+            // the C function doesn't use the context argument
+            let reason = if unsafe { c_api::notcurses_canopen_images(null()) } {
+                "couldn't open or decode the file"
+            } else {
+                "notcurses was built without multimedia support"
+            };
+            return Err(NcError::new_msg(&format![
+                "NcVisual::from_file({}) {}",
+                file, reason
+            ]));
+        }
+        Ok(unsafe { &mut *visual })
     }
 
     /// Promotes an `NcPlane` to an `NcVisual`.
@@ -97,14 +111,40 @@ impl NcVisual {
         ]
     }
 
-    /// Constructs an `NcVisual` from a nul-terminated Sixel control `sequence`.
+    /// Constructs an `NcVisual` by decoding a Sixel escape sequence.
+    ///
+    /// The sequence is decoded in Rust, and both its introducer (`ESC P`…`q`)
+    /// and its string terminator (`ESC \`) are optional. The pixels that are
+    /// not drawn are transparent.
+    ///
+    /// It is an error if the sequence is invalid or there are no pixels.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn decode_sixel<'a>(data: &[u8]) -> NcResult<&'a mut NcVisual> {
+        let (rgba, rows, cols) = sixel_to_rgba(data)?;
+        Self::from_rgba(&rgba, rows, cols * 4, cols)
+    }
+
+    /// Constructs an `NcVisual` of `len_y`×`len_x` pixels by decoding a Sixel
+    /// escape sequence with notcurses.
+    ///
+    /// Unlike [`decode_sixel`][NcVisual#method.decode_sixel], the geometry of the
+    /// image must be known in advance.
+    ///
+    /// It is an error if any of the dimensions is zero.
     ///
     /// *C style function: [ncvisual_from_sixel()][c_api::ncvisual_from_sixel].*
-    pub fn from_sixel<'a>(sequence: &str, len_y: u32, len_x: u32) -> NcResult<&'a mut NcVisual> {
-        let cs = cstring![sequence];
+    pub fn from_sixel<'a>(data: &str, len_y: u32, len_x: u32) -> NcResult<&'a mut NcVisual> {
+        if len_y == 0 || len_x == 0 {
+            return Err(NcError::new_msg(&format![
+                "NcVisual::from_sixel(data, {}, {}): invalid dimensions",
+                len_y, len_x
+            ]));
+        }
+        let cs = cstring![data];
         error_ref_mut![
-            unsafe { c_api::ncvisual_from_file(cs.as_ptr()) },
-            &format!("NcVisual::from_sixel({}, {}, {})", sequence, len_y, len_x)
+            unsafe { c_api::ncvisual_from_sixel(cs.as_ptr(), len_y, len_x) },
+            &format!("NcVisual::from_sixel(data, {}, {})", len_y, len_x)
         ]
     }
 
@@ -460,6 +500,32 @@ impl NcVisual {
         error_ref_mut![c_api::ncvisual_blit(nc, self, options_ptr), "NcVisual.blit"]
     }
 
+    /// Blits the decoded frame to a new plane, and returns its text content,
+    /// joining the rows with `\n`, like [`NcPlane.content_as_string`].
+    ///
+    /// The plane and the [`ChildPlane`] flag of the `options` are ignored,
+    /// since the frame is always blitted to a new pile, that is destroyed
+    /// afterwards.
+    ///
+    /// It's useful for comparing the output of the blitters in tests.
+    ///
+    /// *(No equivalent C style function)*
+    ///
+    /// [`NcPlane.content_as_string`]: NcPlane#method.content_as_string
+    /// [`ChildPlane`]: crate::NcVisualFlag#associatedconstant.ChildPlane
+    pub fn blit_to_string(&mut self, nc: &mut Nc, options: &NcVisualOptions) -> NcResult<String> {
+        let options = NcVisualOptions {
+            n: null_mut(),
+            flags: options.flags & !NcVisualFlag::ChildPlane.0,
+            ..*options
+        };
+
+        let plane = unsafe { self.blit(nc, Some(&options))? };
+        let content = plane.content_as_string();
+        plane.destroy()?;
+        Ok(content)
+    }
+
     /// Resizes the visual to `cols` X `rows` pixels.
     ///
     /// This is a lossy transformation, unless the size is unchanged.
@@ -472,6 +538,46 @@ impl NcVisual {
         ]
     }
 
+    /// Resizes the visual to the largest size that fits in `max_rows` X
+    /// `max_cols` cells when blitted with `blitter`, preserving its aspect
+    /// ratio.
+    ///
+    /// The pixels per cell are taken from the pixel geometry of `nc` for the
+    /// `blitter`. The new size is rounded, and never less than 1 pixel.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn fit_to(
+        &mut self,
+        nc: &Nc,
+        max_rows: u32,
+        max_cols: u32,
+        blitter: NcBlitter,
+    ) -> NcResult<()> {
+        let err = |what: &str| {
+            NcError::new_msg(&format![
+                "NcVisual.fit_to({}, {}, {:?}) {}",
+                max_rows, max_cols, blitter, what
+            ])
+        };
+        if max_rows == 0 || max_cols == 0 {
+            return Err(err("empty budget"));
+        }
+
+        let vopts = NcVisualOptions::builder().blitter(blitter).build();
+        let geom = self.geom(Some(nc), Some(&vopts))?;
+        let ((pix_y, pix_x), (scale_y, scale_x)) = match (geom.pix_yx, geom.scale_yx) {
+            (Some(pix), Some(scale)) => (pix, scale),
+            _ => return Err(err("unknown pixel geometry")),
+        };
+
+        let budget_y = (max_rows * scale_y) as f64;
+        let budget_x = (max_cols * scale_x) as f64;
+        let factor = (budget_y / pix_y as f64).min(budget_x / pix_x as f64);
+        let rows = ((pix_y as f64 * factor + 0.5) as u32).max(1);
+        let cols = ((pix_x as f64 * factor + 0.5) as u32).max(1);
+        self.resize(rows, cols)
+    }
+
     /// Resizes the visual to  in the image to `rows` X `cols` pixels, without
     /// interpolating the color values.
     ///
@@ -519,6 +625,68 @@ impl NcVisual {
         ]
     }
 
+    /// Returns a copy of the pixels of this `NcVisual`, in row-major order,
+    /// with 4 bytes per pixel in RGBA order.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn to_rgba_vec(&self) -> NcResult<Vec<u8>> {
+        let (rows, cols) = self
+            .geom(None, None)?
+            .pix_yx
+            .ok_or_else(|| NcError::new_msg("NcVisual.to_rgba_vec() unknown pixel geometry"))?;
+
+        let mut buf = Vec::with_capacity((rows * cols * 4) as usize);
+        for y in 0..rows {
+            for x in 0..cols {
+                let pixel = self.at_yx(y, x)?;
+                buf.extend_from_slice(&[pixel.r(), pixel.g(), pixel.b(), pixel.a()]);
+            }
+        }
+        Ok(buf)
+    }
+
+    /// Returns a new visual that blends the pixels of this visual with the
+    /// ones of `other`, using `alpha` as the weight of `other`.
+    ///
+    /// An `alpha` of 0.0 returns a copy of this visual, 1.0 a copy of `other`,
+    /// and anything in between mixes every component linearly, including
+    /// the alpha component of the pixels.
+    ///
+    /// It is an error if `alpha` is not between 0.0 and 1.0, or if the
+    /// visuals don't have the same pixel dimensions.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn blend_with<'a>(&self, other: &NcVisual, alpha: f32) -> NcResult<&'a mut NcVisual> {
+        let err = |what: &str| {
+            NcError::new_msg(&format!["NcVisual.blend_with(other, {}) {}", alpha, what])
+        };
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(err("alpha out of range"));
+        }
+        let (rows, cols) = match (
+            self.geom(None, None)?.pix_yx,
+            other.geom(None, None)?.pix_yx,
+        ) {
+            (Some(yx), Some(other_yx)) if yx == other_yx => yx,
+            (Some(yx), Some(other_yx)) => {
+                return Err(err(&format![
+                    "different dimensions: {:?} and {:?}",
+                    yx, other_yx
+                ]));
+            }
+            _ => return Err(err("unknown pixel geometry")),
+        };
+
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * alpha + 0.5) as u8;
+        let (pixels, other_pixels) = (self.to_rgba_vec()?, other.to_rgba_vec()?);
+        let rgba: Vec<u8> = pixels
+            .iter()
+            .zip(&other_pixels)
+            .map(|(&a, &b)| mix(a, b))
+            .collect();
+        Self::from_rgba(&rgba, rows, cols * 4, cols)
+    }
+
     /// Displays frames.
     ///
     /// *Provide as an argument to ncvisual_stream().*
@@ -1,6 +1,6 @@
-// functions already exported by bindgen : 24
+// functions already exported by bindgen : 25
 // -----------------------------------------
-// (W) wrap: 20
+// (W) wrap: 21
 // (#) test: 0
 // -----------------------------------------
 //W  ncdirectf_free
@@ -19,6 +19,7 @@
 //W  ncvisual_from_rgba
 //W  ncvisual_from_rgb_packed
 //W  ncvisual_from_rgb_loose
+//W  ncvisual_from_sixel
 //W  ncvisual_media_defblitter
 //W  ncvisual_polyfill_yx
 //   ncvisual_plane_create
@@ -40,11 +41,18 @@
 #[allow(unused_imports)] // for doc comments
 use crate::{c_api::NcResult_i32, NcBlitter, NcChannel, NcPlane, NcScale, NcTime};
 
+mod frames;
 mod geometry;
+#[cfg(feature = "image-decode")]
+mod image_interop;
 mod methods;
 pub(crate) mod options;
 mod reimplemented;
+pub(crate) mod sixel;
+#[cfg(test)]
+mod test;
 
+pub use frames::NcVisualFrameIter;
 pub use geometry::NcVisualGeometry;
 pub use options::{NcVisualFlag, NcVisualOptions, NcVisualOptionsBuilder};
 
@@ -1,6 +1,6 @@
 //!
 
-use crate::{c_api::ffi, NcBlitter, NcPlane, NcRgba, NcScale};
+use crate::{c_api::ffi, Nc, NcBlitter, NcError, NcPlane, NcResult, NcRgba, NcScale, NcVisual};
 use core::ptr::null_mut;
 
 mod builder;
@@ -261,6 +261,19 @@ impl NcVisualOptions {
     pub fn is_horaligned(&self) -> bool {
         self.flags & NcVisualFlag::HorAligned != NcVisualFlag::None
     }
+
+    /// Returns the size in cells (rows, columns) of the plane that blitting
+    /// the `visual` with these options would produce, given the current
+    /// scaling and blitter.
+    ///
+    /// Useful for positioning a plane created by the blit beforehand.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn predicted_plane_size(&self, visual: &NcVisual, nc: &Nc) -> NcResult<(u32, u32)> {
+        visual.geom(Some(nc), Some(self))?.rcell_yx.ok_or_else(|| {
+            NcError::new_msg("NcVisualOptions.predicted_plane_size(): unknown cell geometry")
+        })
+    }
 }
 
 /// A bitmask of flags for [`NcVisualOptions`].
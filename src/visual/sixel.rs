@@ -0,0 +1,308 @@
+//! Sixel encoding and decoding.
+
+use crate::{NcError, NcResult, NcRgba};
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, format, string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+/// The maximum width and height of a decoded image, in pixels.
+const MAX_SIDE: usize = 1 << 14;
+
+/// The maximum number of pixels of a decoded image.
+const MAX_PIXELS: usize = 4096 * 4096;
+
+/// The number of color registers.
+const REGISTERS: usize = 1024;
+
+/// The default colors of the first 16 registers, as in the VT340.
+const DEFAULT_PALETTE: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [51, 51, 204],
+    [204, 36, 36],
+    [51, 204, 51],
+    [204, 51, 204],
+    [51, 204, 204],
+    [204, 204, 51],
+    [120, 120, 120],
+    [69, 69, 69],
+    [87, 87, 153],
+    [153, 69, 69],
+    [87, 153, 87],
+    [153, 87, 153],
+    [87, 153, 153],
+    [153, 153, 87],
+    [204, 204, 204],
+];
+
+/// Encodes `rows`×`cols` RGBA pixels as a Sixel escape sequence.
+///
+/// The pixels with an alpha of 0 are left undrawn. If there are more than
+/// 256 different colors, they are reduced to a 6×6×6 color cube.
+pub(crate) fn rgba_to_sixel(rgba: &[u8], rows: u32, cols: u32) -> String {
+    let (rows, cols) = (rows as usize, cols as usize);
+
+    // the color register of every pixel, if drawn
+    let mut colors: Vec<[u8; 3]> = Vec::new();
+    let mut indices: BTreeMap<[u8; 3], usize> = BTreeMap::new();
+    let mut registers: Vec<Option<usize>> = Vec::with_capacity(rows * cols);
+    fn find_or_add(
+        colors: &mut Vec<[u8; 3]>,
+        indices: &mut BTreeMap<[u8; 3], usize>,
+        rgb: [u8; 3],
+    ) -> usize {
+        *indices.entry(rgb).or_insert_with(|| {
+            colors.push(rgb);
+            colors.len() - 1
+        })
+    }
+    for px in rgba.chunks_exact(4).take(rows * cols) {
+        let rgb = [px[0], px[1], px[2]];
+        registers.push(if px[3] == 0 {
+            None
+        } else {
+            Some(find_or_add(&mut colors, &mut indices, rgb))
+        });
+    }
+    if colors.len() > 256 {
+        let level = |c: u8| ((c as u16 * 5 + 127) / 255 * 51) as u8;
+        let old_colors = core::mem::take(&mut colors);
+        indices.clear();
+        for register in registers.iter_mut().flatten() {
+            let [r, g, b] = old_colors[*register];
+            *register = find_or_add(&mut colors, &mut indices, [level(r), level(g), level(b)]);
+        }
+    }
+
+    // introducer with a transparent background, and raster attributes
+    let mut sixel = format!["\x1bP0;1;0q\"1;1;{};{}", cols, rows];
+    let percent = |c: u8| (c as u32 * 100 + 127) / 255;
+    for (i, [r, g, b]) in colors.iter().enumerate() {
+        sixel.push_str(&format![
+            "#{};2;{};{};{}",
+            i,
+            percent(*r),
+            percent(*g),
+            percent(*b)
+        ]);
+    }
+
+    for band in (0..rows).step_by(6) {
+        let band_rows = (rows - band).min(6);
+        let mut band_colors: Vec<usize> = (0..band_rows)
+            .flat_map(|dy| registers[(band + dy) * cols..(band + dy + 1) * cols].iter())
+            .flatten()
+            .copied()
+            .collect();
+        band_colors.sort_unstable();
+        band_colors.dedup();
+
+        for (n, color) in band_colors.into_iter().enumerate() {
+            if n > 0 {
+                // carriage return, to overlay the next color
+                sixel.push('$');
+            }
+            sixel.push_str(&format!["#{}", color]);
+            let sixel_at = |x: usize| {
+                let bits = (0..band_rows)
+                    .filter(|dy| registers[(band + dy) * cols + x] == Some(color))
+                    .fold(0, |bits, dy| bits | 1 << dy);
+                (63 + bits) as u8 as char
+            };
+            let mut x = 0;
+            while x < cols {
+                let ch = sixel_at(x);
+                let mut run = 1;
+                while x + run < cols && sixel_at(x + run) == ch {
+                    run += 1;
+                }
+                if run > 3 {
+                    sixel.push_str(&format!["!{}{}", run, ch]);
+                } else {
+                    (0..run).for_each(|_| sixel.push(ch));
+                }
+                x += run;
+            }
+        }
+        // next band
+        sixel.push('-');
+    }
+    sixel.push_str("\x1b\\");
+    sixel
+}
+
+/// Decodes a Sixel escape sequence into RGBA pixels.
+///
+/// The introducer and the string terminator are optional. The pixels that
+/// are not drawn are transparent.
+///
+/// Returns the pixels, the number of rows and the number of columns.
+pub(crate) fn sixel_to_rgba(data: &[u8]) -> NcResult<(Vec<u8>, u32, u32)> {
+    let err = |pos: usize, what: &str| {
+        NcError::new_msg(&format![
+            "NcVisual::decode_sixel(): {} at byte {}",
+            what, pos
+        ])
+    };
+
+    // skip the introducer: `ESC P` or `0x90`, the parameters, and `q`
+    let mut pos = 0;
+    match data {
+        [0x1B, b'P', ..] => pos = 2,
+        [0x90, ..] => pos = 1,
+        _ => (),
+    }
+    if pos > 0 {
+        while data
+            .get(pos)
+            .map_or(false, |b| b.is_ascii_digit() || *b == b';')
+        {
+            pos += 1;
+        }
+        if data.get(pos) != Some(&b'q') {
+            return Err(err(pos, "missing `q` after the introducer"));
+        }
+        pos += 1;
+    }
+
+    let mut palette = vec![[0; 3]; REGISTERS];
+    palette[..16].copy_from_slice(&DEFAULT_PALETTE);
+    let mut raster: Option<(usize, usize)> = None;
+    let mut rows: Vec<Vec<Option<[u8; 3]>>> = Vec::new();
+    let (mut color, mut band, mut x, mut repeat) = (0, 0, 0, 1);
+    // the width of the widest row drawn
+    let mut max_x = 0;
+
+    while let Some(&byte) = data.get(pos) {
+        let start = pos;
+        pos += 1;
+        match byte {
+            // the string terminator: `ESC \` or `0x9C`
+            0x1B if data.get(pos) == Some(&b'\\') => break,
+            0x9C => break,
+            b'"' => {
+                let params = parse_params(data, &mut pos);
+                if let [_, _, width, height] = params[..] {
+                    if width > MAX_SIDE || height > MAX_SIDE || width * height > MAX_PIXELS {
+                        return Err(err(start, "image too large"));
+                    }
+                    raster = Some((width, height));
+                }
+            }
+            b'#' => {
+                let params = parse_params(data, &mut pos);
+                color = params.first().copied().unwrap_or(0);
+                if color >= REGISTERS {
+                    return Err(err(start, "color register out of range"));
+                }
+                match params[..] {
+                    [_] => (),
+                    [_, 1, h, l, s] => palette[color] = hls_to_rgb(h, l, s),
+                    [_, 2, r, g, b] => {
+                        let c = |v: usize| (v.min(100) * 255 + 50) / 100;
+                        palette[color] = [c(r) as u8, c(g) as u8, c(b) as u8];
+                    }
+                    _ => return Err(err(start, "invalid color definition")),
+                }
+            }
+            b'!' => {
+                repeat = parse_params(data, &mut pos)
+                    .first()
+                    .copied()
+                    .unwrap_or(1)
+                    .max(1);
+                if repeat > MAX_SIDE {
+                    return Err(err(start, "repeat count too large"));
+                }
+            }
+            b'$' => x = 0,
+            b'-' => {
+                band += 6;
+                x = 0;
+            }
+            b'?'..=b'~' => {
+                let bits = byte - b'?';
+                max_x = max_x.max(x + repeat);
+                if max_x > MAX_SIDE || band + 6 > MAX_SIDE || max_x * (band + 6) > MAX_PIXELS {
+                    return Err(err(start, "image too large"));
+                }
+                for dy in (0..6).filter(|dy| bits & 1 << dy != 0) {
+                    let y = band + dy;
+                    if rows.len() <= y {
+                        rows.resize(y + 1, Vec::new());
+                    }
+                    let row = &mut rows[y];
+                    if row.len() < x + repeat {
+                        row.resize(x + repeat, None);
+                    }
+                    row[x..x + repeat].fill(Some(palette[color]));
+                }
+                x += repeat;
+                repeat = 1;
+            }
+            b' ' | b'\t' | b'\r' | b'\n' => (),
+            _ => return Err(err(start, &format!["invalid byte {:#04x}", byte])),
+        }
+    }
+
+    let (width, height) = raster.unwrap_or_else(|| {
+        (
+            rows.iter().map(|row| row.len()).max().unwrap_or(0),
+            rows.len(),
+        )
+    });
+    if width == 0 || height == 0 {
+        return Err(NcError::new_msg("NcVisual::decode_sixel(): empty image"));
+    }
+    if width * height > MAX_PIXELS {
+        return Err(NcError::new_msg(
+            "NcVisual::decode_sixel(): image too large",
+        ));
+    }
+
+    let mut rgba = vec![0; width * height * 4];
+    for (y, row) in rows.iter().take(height).enumerate() {
+        for (x, rgb) in row.iter().take(width).enumerate() {
+            if let Some([r, g, b]) = rgb {
+                let i = (y * width + x) * 4;
+                rgba[i..i + 4].copy_from_slice(&[*r, *g, *b, 0xFF]);
+            }
+        }
+    }
+    Ok((rgba, height as u32, width as u32))
+}
+
+/// Parses a list of numeric parameters separated by `;`, advancing `pos`.
+///
+/// Empty parameters are 0.
+fn parse_params(data: &[u8], pos: &mut usize) -> Vec<usize> {
+    let mut params: Vec<usize> = vec![0];
+    while let Some(&byte) = data.get(*pos) {
+        match byte {
+            b'0'..=b'9' => {
+                let last = params.last_mut().unwrap();
+                *last = last
+                    .saturating_mul(10)
+                    .saturating_add((byte - b'0') as usize);
+            }
+            b';' => params.push(0),
+            _ => break,
+        }
+        *pos += 1;
+    }
+    params
+}
+
+/// Converts a Sixel HLS color, whose hue starts at blue, to RGB.
+///
+/// The `hue` is in degrees, and `lightness` and `saturation` in percent.
+fn hls_to_rgb(hue: usize, lightness: usize, saturation: usize) -> [u8; 3] {
+    let rgba = NcRgba::from_hsl(
+        ((hue % 360 + 240) % 360) as f32,
+        saturation as f32 / 100.0,
+        lightness as f32 / 100.0,
+    );
+    let [_, r, g, b] = rgba.0.to_be_bytes();
+    [r, g, b]
+}
@@ -0,0 +1,123 @@
+//! Test `NcVisual` interoperability with the `image` crate.
+
+use crate::NcVisual;
+use image::{DynamicImage, ImageOutputFormat, LumaA, Rgb, RgbImage};
+use serial_test::serial;
+use std::io::Cursor;
+
+/// Returns a small synthetic image encoded in the given `format`.
+fn encoded_image(format: ImageOutputFormat) -> Vec<u8> {
+    let image = RgbImage::from_pixel(3, 2, Rgb([0x11, 0x22, 0x33]));
+    let mut bytes = Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgb8(image)
+        .write_to(&mut bytes, format)
+        .unwrap();
+    bytes.into_inner()
+}
+
+#[test]
+#[serial]
+fn from_png_bytes() {
+    let png = encoded_image(ImageOutputFormat::Png);
+    let visual = NcVisual::from_png_bytes(&png).unwrap();
+    assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((2, 3))];
+    visual.destroy();
+
+    assert![NcVisual::from_png_bytes(b"not a png").is_err()];
+}
+
+#[test]
+#[serial]
+fn from_jpeg_bytes() {
+    let jpeg = encoded_image(ImageOutputFormat::Jpeg(90));
+    let visual = NcVisual::from_jpeg_bytes(&jpeg).unwrap();
+    assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((2, 3))];
+    visual.destroy();
+
+    // the wrong format is an error
+    assert![NcVisual::from_png_bytes(&jpeg).is_err()];
+}
+
+#[test]
+#[serial]
+fn from_image_bytes() {
+    for format in [ImageOutputFormat::Png, ImageOutputFormat::Jpeg(90)] {
+        let visual = NcVisual::from_image_bytes(&encoded_image(format)).unwrap();
+        assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((2, 3))];
+        visual.destroy();
+    }
+}
+
+#[test]
+#[serial]
+fn save() {
+    let rgba: Vec<u8> = (0..8u8)
+        .flat_map(|i| [i * 30, 0xFF - i * 30, 0x40, 0xFF])
+        .collect();
+    let visual = NcVisual::from_rgba(&rgba, 2, 4 * 4, 4).unwrap();
+    assert_eq![visual.to_rgba_vec().unwrap(), rgba];
+
+    // the temporary files are named after the process, so that concurrent
+    // test runs don't overwrite each other's
+    let temp = |ext: &str| {
+        std::env::temp_dir().join(format![
+            "libnotcurses-sys-save-{}.{}",
+            std::process::id(),
+            ext
+        ])
+    };
+
+    // PNG is lossless
+    let png = temp("png");
+    visual.save_png(&png).unwrap();
+    let reloaded = NcVisual::from_png_bytes(&std::fs::read(&png).unwrap()).unwrap();
+    assert_eq![reloaded.to_rgba_vec().unwrap(), rgba];
+    reloaded.destroy();
+
+    let jpeg = temp("jpg");
+    visual.save_jpeg(&jpeg, 90).unwrap();
+    let reloaded = NcVisual::from_jpeg_bytes(&std::fs::read(&jpeg).unwrap()).unwrap();
+    assert_eq![reloaded.geom(None, None).unwrap().pix_yx, Some((2, 4))];
+    reloaded.destroy();
+
+    assert![visual.save_png("/nonexistent/dir/file.png").is_err()];
+    assert![visual.save_jpeg("/nonexistent/dir/file.jpg", 90).is_err()];
+    visual.destroy();
+    let _ = std::fs::remove_file(png);
+    let _ = std::fs::remove_file(jpeg);
+}
+
+#[test]
+#[serial]
+fn from_image() {
+    let images = [
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(3, 2, Rgb([0x11, 0x22, 0x33]))),
+        DynamicImage::ImageLumaA8(image::ImageBuffer::from_pixel(3, 2, LumaA([0x80, 0xFF]))),
+        DynamicImage::ImageRgba8(image::RgbaImage::new(3, 2)),
+        image::open(concat![
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/res/image-16x16.png"
+        ])
+        .unwrap(),
+    ];
+    for (i, image) in images.iter().enumerate() {
+        let visual = NcVisual::from_image(image).unwrap();
+        let expected = if i == 3 { (16, 16) } else { (2, 3) };
+        assert_eq![visual.geom(None, None).unwrap().pix_yx, Some(expected)];
+        visual.destroy();
+    }
+}
+
+#[test]
+#[serial]
+fn to_image() {
+    let image = image::RgbaImage::from_fn(5, 3, |x, y| {
+        image::Rgba([x as u8 * 50, y as u8 * 80, 0x7F, 0xFF - x as u8])
+    });
+    let visual = NcVisual::from_image(&DynamicImage::ImageRgba8(image.clone())).unwrap();
+    let exported = visual.to_image().unwrap();
+    visual.destroy();
+
+    assert_eq![exported.dimensions(), (5, 3)];
+    assert_eq![exported.as_raw(), image.as_raw()];
+}
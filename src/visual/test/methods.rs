@@ -0,0 +1,250 @@
+//! Test `NcVisual` methods and associated functions.
+
+#[cfg(feature = "std")]
+use crate::c_api;
+use crate::NcVisual;
+use serial_test::serial;
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn from_file() {
+    let png = concat![env!("CARGO_MANIFEST_DIR"), "/tests/res/image-16x16.png"];
+    let canopen_images = unsafe { c_api::notcurses_canopen_images(core::ptr::null()) };
+
+    match NcVisual::from_file(png) {
+        Ok(visual) => {
+            assert![canopen_images];
+            assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((16, 16))];
+            visual.destroy();
+        }
+        Err(e) => {
+            assert![!canopen_images];
+            assert![e.msg.contains("without multimedia support")];
+        }
+    }
+
+    let e = NcVisual::from_file("/nonexistent/image.png").unwrap_err();
+    assert![e.msg.contains("/nonexistent/image.png")];
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn frames() {
+    let gif = concat![env!("CARGO_MANIFEST_DIR"), "/tests/res/animation-4x4.gif"];
+    if !unsafe { c_api::notcurses_canopen_videos(core::ptr::null()) } {
+        return;
+    }
+    // a red frame, followed by a green one and a blue one
+    let visual = NcVisual::from_file(gif).unwrap();
+    fn rgb(visual: &NcVisual) -> (u8, u8, u8) {
+        let pixel = visual.at_yx(0, 0).unwrap();
+        (pixel.r(), pixel.g(), pixel.b())
+    }
+    assert_eq![rgb(visual), (0xFF, 0, 0)];
+
+    for _ in 0..2 {
+        let mut frames = visual.frames();
+        frames.next().unwrap().unwrap();
+        assert_eq![rgb(frames.visual()), (0, 0xFF, 0)];
+        frames.next().unwrap().unwrap();
+        assert_eq![rgb(frames.visual()), (0, 0, 0xFF)];
+        assert![frames.next().is_none()];
+        assert![frames.next().is_none()];
+        // rewound to the first frame
+        assert_eq![rgb(visual), (0xFF, 0, 0)];
+    }
+    visual.destroy();
+}
+
+#[test]
+#[serial]
+fn blend_with() {
+    fn rgba(visual: &NcVisual) -> [u8; 4] {
+        let pixel = visual.at_yx(1, 1).unwrap();
+        [pixel.r(), pixel.g(), pixel.b(), pixel.a()]
+    }
+
+    let a = NcVisual::from_rgba(&[0, 100, 200, 255].repeat(4), 2, 8, 2).unwrap();
+    let b = NcVisual::from_rgba(&[255, 0, 100, 0].repeat(4), 2, 8, 2).unwrap();
+
+    let blend = a.blend_with(b, 0.0).unwrap();
+    assert_eq![rgba(blend), [0, 100, 200, 255]];
+    blend.destroy();
+    let blend = a.blend_with(b, 1.0).unwrap();
+    assert_eq![rgba(blend), [255, 0, 100, 0]];
+    blend.destroy();
+    let blend = a.blend_with(b, 0.5).unwrap();
+    assert_eq![blend.geom(None, None).unwrap().pix_yx, Some((2, 2))];
+    assert_eq![rgba(blend), [128, 50, 150, 128]];
+    blend.destroy();
+
+    assert![a.blend_with(b, 1.5).is_err()];
+    assert![a.blend_with(b, f32::NAN).is_err()];
+    let c = NcVisual::from_rgba(&[0; 4 * 3], 1, 12, 3).unwrap();
+    let e = a.blend_with(c, 0.5).unwrap_err();
+    assert![e.msg.contains("different dimensions")];
+
+    a.destroy();
+    b.destroy();
+    c.destroy();
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "std")]
+fn blit_to_string() {
+    use crate::{NcBlitter, NcVisualOptions};
+
+    const O: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
+    const T: [u8; 4] = [0, 0, 0, 0];
+
+    unsafe {
+        let nc = c_api::notcurses_init_test();
+
+        // an opaque and a transparent pixel over a transparent and an opaque one
+        let rgba = [O, T, T, O].concat();
+        let visual = NcVisual::from_rgba(&rgba, 2, 8, 2).unwrap();
+
+        let half = NcVisualOptions::builder().blitter(NcBlitter::Half).build();
+        assert_eq![visual.blit_to_string(nc, &half).unwrap(), "▀▄"];
+
+        // the ascii blitter draws a space for every opaque pixel
+        let ascii = NcVisualOptions::builder().blitter(NcBlitter::Ascii).build();
+        assert_eq![visual.blit_to_string(nc, &ascii).unwrap(), " \n  "];
+
+        visual.destroy();
+        c_api::notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn decode_sixel() {
+    use crate::visual::sixel::rgba_to_sixel;
+
+    // a 3×2 image with a transparent pixel
+    #[rustfmt::skip]
+    let rgba = [
+        0xFF, 0, 0, 0xFF,   0, 0xFF, 0, 0xFF,   0, 0, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF,   0, 0, 0, 0,   0xFF, 0, 0xFF, 0xFF,
+    ];
+    let sixel = rgba_to_sixel(&rgba, 2, 3);
+
+    let visual = NcVisual::decode_sixel(sixel.as_bytes()).unwrap();
+    assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((2, 3))];
+    for y in 0..2 {
+        for x in 0..3 {
+            let i = (y * 3 + x) as usize * 4;
+            let pixel = visual.at_yx(y, x).unwrap();
+            assert_eq![
+                [pixel.r(), pixel.g(), pixel.b(), pixel.a()],
+                rgba[i..i + 4],
+                "pixel {}×{}",
+                y,
+                x
+            ];
+        }
+    }
+    visual.destroy();
+
+    // HLS colors, and no introducer nor terminator
+    let visual = NcVisual::decode_sixel(b"#1;1;120;50;100~").unwrap();
+    assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((6, 1))];
+    let pixel = visual.at_yx(5, 0).unwrap();
+    assert_eq![(pixel.r(), pixel.g(), pixel.b()), (0xFF, 0, 0)];
+    visual.destroy();
+
+    // invalid sequences
+    let e = NcVisual::decode_sixel(b"\x1bP0;1;0x").unwrap_err();
+    assert![e.msg.contains("missing `q`")];
+    let e = NcVisual::decode_sixel(b"\x1bPq#0;3;1;2;3~").unwrap_err();
+    assert![e.msg.contains("invalid color definition")];
+    let e = NcVisual::decode_sixel(b"\x1bPq~~\x07").unwrap_err();
+    assert![e.msg.contains("invalid byte 0x07")];
+    let e = NcVisual::decode_sixel(b"\x1bPq\x1b\\").unwrap_err();
+    assert![e.msg.contains("empty image")];
+
+    // too many pixels, even if each side is within the limits
+    let e = NcVisual::decode_sixel(b"\"1;1;10000;10000~").unwrap_err();
+    assert![e.msg.contains("image too large")];
+    let e = NcVisual::decode_sixel("!10000~-".repeat(300).as_bytes()).unwrap_err();
+    assert![e.msg.contains("image too large")];
+}
+
+#[test]
+#[serial]
+fn from_sixel() {
+    use crate::visual::sixel::rgba_to_sixel;
+
+    let rgba = [0xFF; 2 * 3 * 4];
+    let sixel = rgba_to_sixel(&rgba, 2, 3);
+
+    let visual = NcVisual::from_sixel(&sixel, 2, 3).unwrap();
+    assert_eq![visual.geom(None, None).unwrap().pix_yx, Some((2, 3))];
+    visual.destroy();
+
+    let e = NcVisual::from_sixel(&sixel, 0, 3).unwrap_err();
+    assert![e.msg.contains("invalid dimensions")];
+}
+
+#[test]
+#[serial]
+fn predicted_plane_size() {
+    use crate::{
+        c_api::{notcurses_init_test, notcurses_stop},
+        NcBlitter, NcVisualOptions,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    unsafe {
+        let nc = notcurses_init_test();
+        let rgba = vec![0xFF; 9 * 5 * 4];
+        let visual = NcVisual::from_rgba(&rgba, 9, 5 * 4, 5).unwrap();
+
+        for blitter in [NcBlitter::Ascii, NcBlitter::Half, NcBlitter::Quadrant] {
+            let options = NcVisualOptions::builder().blitter(blitter).build();
+            let predicted = options.predicted_plane_size(visual, nc).unwrap();
+
+            let plane = visual.blit(nc, Some(&options)).unwrap();
+            assert_eq![predicted, plane.dim_yx(), "{:?}", blitter];
+            plane.destroy().unwrap();
+        }
+
+        visual.destroy();
+        notcurses_stop(nc);
+    }
+}
+
+#[test]
+#[serial]
+fn fit_to() {
+    use crate::{
+        c_api::{notcurses_init_test, notcurses_stop},
+        NcBlitter, NcVisualOptions,
+    };
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    unsafe {
+        let nc = notcurses_init_test();
+        // a tall image, into a wide budget
+        let rgba = vec![0xFF; 40 * 8 * 4];
+        let visual = NcVisual::from_rgba(&rgba, 40, 8 * 4, 8).unwrap();
+
+        visual.fit_to(nc, 10, 40, NcBlitter::Half).unwrap();
+
+        let options = NcVisualOptions::builder().blitter(NcBlitter::Half).build();
+        let geom = visual.geom(Some(nc), Some(&options)).unwrap();
+        let (rows, cols) = geom.pix_yx.unwrap();
+        let (scale_y, _) = geom.scale_yx.unwrap();
+        // limited by the rows, with the aspect ratio preserved within rounding
+        assert_eq![rows, 10 * scale_y];
+        assert![(rows * 8).abs_diff(cols * 40) <= 40];
+
+        visual.destroy();
+        notcurses_stop(nc);
+    }
+}
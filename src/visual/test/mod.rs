@@ -0,0 +1,6 @@
+//! `NcVisual` tests.
+
+#[cfg(feature = "image-decode")]
+mod image_interop;
+
+mod methods;
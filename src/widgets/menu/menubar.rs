@@ -0,0 +1,298 @@
+//! `NcMenuBar` & `NcMenuBarItem`
+
+use core::ptr::null_mut;
+
+#[cfg(not(feature = "std"))]
+use alloc::{ffi::CString, format, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::ffi::CString;
+
+use crate::{
+    cstring,
+    widgets::{NcMenu, NcMenuItem, NcMenuOptions, NcMenuSection},
+    Nc, NcError, NcInput, NcKey, NcResult,
+};
+
+/// An entry of an [`NcMenuBar`].
+///
+/// The top level entries are the sections of the menu bar, and must have a
+/// `submenu` with their items. The items can't have a `submenu` of their own.
+///
+/// An entry with an empty `label` is a separator.
+#[derive(Clone, Debug, Default)]
+pub struct NcMenuBarItem<'a> {
+    /// The text of the entry, also returned when the item is activated.
+    pub label: &'a str,
+
+    /// The shortcut of the entry, if any.
+    ///
+    /// A section shortcut unrolls the section, and an item shortcut
+    /// activates the item.
+    pub shortcut: Option<NcInput>,
+
+    /// The items of a section.
+    pub submenu: Option<Vec<NcMenuBarItem<'a>>>,
+}
+
+impl<'a> NcMenuBarItem<'a> {
+    /// New `NcMenuBarItem` without a shortcut nor a submenu.
+    pub fn new(label: &'a str) -> Self {
+        Self { label, shortcut: None, submenu: None }
+    }
+
+    /// New `NcMenuBarItem` separator.
+    pub fn separator() -> Self {
+        Self::default()
+    }
+
+    /// Sets the shortcut of the entry.
+    pub fn shortcut(mut self, shortcut: NcInput) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Sets the items of a section.
+    pub fn submenu(mut self, submenu: Vec<NcMenuBarItem<'a>>) -> Self {
+        self.submenu = Some(submenu);
+        self
+    }
+}
+
+/// A menu bar over the standard plane of an [`Nc`], that owns its [`NcMenu`]
+/// and destroys it when dropped.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let mut bar = NcMenuBar::new(nc, &[
+///     NcMenuBarItem::new("File").shortcut(NcInput::with_alt('f')).submenu(vec![
+///         NcMenuBarItem::new("Open").shortcut(NcInput::with_ctrl('o')),
+///         NcMenuBarItem::separator(),
+///         NcMenuBarItem::new("Quit").shortcut(NcInput::with_ctrl('q')),
+///     ]),
+/// ])?;
+///
+/// let mut input = NcInput::new_empty();
+/// loop {
+///     bar.nc().render()?;
+///     bar.nc().get_blocking(Some(&mut input))?;
+///     if bar.handle_input(&input) == Some("Quit") {
+///         break;
+///     }
+/// }
+/// drop(bar);
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+pub struct NcMenuBar<'nc> {
+    nc: &'nc mut Nc,
+    menu: *mut NcMenu,
+    items: Vec<(String, Option<NcInput>)>,
+}
+
+impl<'nc> NcMenuBar<'nc> {
+    /// New `NcMenuBar` at the top of the standard plane of `nc`.
+    ///
+    /// Returns an error if a section doesn't have a submenu, or if an item has.
+    pub fn new(nc: &'nc mut Nc, items: &[NcMenuBarItem]) -> NcResult<NcMenuBar<'nc>> {
+        Self::with_flags(nc, items, 0)
+    }
+
+    /// New `NcMenuBar` with [`NcMenuOptions`] flags, like
+    /// [`NcMenuOptions::BOTTOM`].
+    pub fn with_flags(
+        nc: &'nc mut Nc,
+        items: &[NcMenuBarItem],
+        flags: u64,
+    ) -> NcResult<NcMenuBar<'nc>> {
+        // notcurses copies the strings, they only need to outlive the creation
+        let mut cstrings: Vec<CString> = Vec::new();
+        let mut cstr = |s: &str| {
+            if s.is_empty() {
+                null_mut()
+            } else {
+                cstrings.push(cstring![s]);
+                cstrings[cstrings.len() - 1].as_ptr() as *mut _
+            }
+        };
+
+        let mut owned = Vec::new();
+        let mut section_items: Vec<Vec<NcMenuItem>> = Vec::new();
+        for section in items {
+            let mut sitems = Vec::new();
+            if let Some(submenu) = &section.submenu {
+                for item in submenu {
+                    if item.submenu.is_some() {
+                        return Err(NcError::new_msg(&format![
+                            "NcMenuBar.new(): nested submenu at {:?} > {:?}",
+                            section.label, item.label
+                        ]));
+                    }
+                    let shortcut = item.shortcut.unwrap_or_else(NcInput::new_empty);
+                    sitems.push(NcMenuItem { desc: cstr(item.label), shortcut });
+                    if !item.label.is_empty() {
+                        owned.push((item.label.into(), item.shortcut));
+                    }
+                }
+            } else if !section.label.is_empty() {
+                return Err(NcError::new_msg(&format![
+                    "NcMenuBar.new(): section {:?} without submenu",
+                    section.label
+                ]));
+            }
+            section_items.push(sitems);
+        }
+
+        let mut sections: Vec<NcMenuSection> = items
+            .iter()
+            .zip(section_items.iter_mut())
+            .map(|(section, sitems)| {
+                if section.label.is_empty() {
+                    NcMenuSection::new_separator()
+                } else {
+                    NcMenuSection {
+                        name: cstr(section.label),
+                        items: sitems.as_mut_ptr(),
+                        itemcount: sitems.len() as i32,
+                        shortcut: section.shortcut.unwrap_or_else(NcInput::new_empty),
+                    }
+                }
+            })
+            .collect();
+
+        let mut options = NcMenuOptions::new(&mut sections);
+        options.flags = flags;
+        let menu = NcMenu::new(unsafe { nc.stdplane() }, &options)? as *mut NcMenu;
+
+        Ok(Self { nc, menu, items: owned })
+    }
+
+    /// Returns a mutable reference to the [`Nc`] context, e.g. for rendering.
+    pub fn nc(&mut self) -> &mut Nc {
+        self.nc
+    }
+
+    /// Returns a mutable reference to the underlying [`NcMenu`].
+    pub fn menu(&mut self) -> &mut NcMenu {
+        unsafe { &mut *self.menu }
+    }
+
+    /// Returns the label of the selected item, if there's an unrolled section.
+    pub fn selected(&mut self) -> Option<&str> {
+        let selected = self.menu().selected(None)?;
+        self.label(&selected)
+    }
+
+    /// Offers the `input` to the menu bar, and returns the label of the
+    /// item that it activates, if any.
+    ///
+    /// The item shortcuts activate their items, and so does pressing
+    /// [`Enter`][NcKey::Enter] over the selected item, which also rolls up
+    /// the menu. The arrow keys navigate an unrolled menu, and the
+    /// section shortcuts unroll their sections.
+    pub fn handle_input(&mut self, input: &NcInput) -> Option<&str> {
+        if self.menu().offer_input(*input) {
+            return None;
+        }
+        if let Some(i) = self
+            .items
+            .iter()
+            .position(|(_, shortcut)| shortcut.map_or(false, |s| s.equal_p(input)))
+        {
+            let _ = self.menu().rollup();
+            return Some(&self.items[i].0);
+        }
+        if input.id == NcKey::Enter.into() {
+            let selected = self.menu().selected(None)?;
+            let _ = self.menu().rollup();
+            return self.label(&selected);
+        }
+        None
+    }
+
+    /// Returns the owned label equal to `label`.
+    fn label(&self, label: &str) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(l, _)| l.as_str())
+    }
+}
+
+impl Drop for NcMenuBar<'_> {
+    fn drop(&mut self) {
+        self.menu().destroy();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NcMenuBar, NcMenuBarItem};
+    use crate::{
+        c_api::{notcurses_init_test, notcurses_stop},
+        NcInput, NcInputType, NcKey,
+    };
+    use serial_test::serial;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
+
+    fn key(key: NcKey) -> NcInput {
+        NcInput { id: key.into(), evtype: NcInputType::Press as u32, ..NcInput::new_empty() }
+    }
+
+    #[test]
+    #[serial]
+    fn menubar_navigation() {
+        unsafe {
+            let nc = notcurses_init_test();
+            {
+                let mut bar = NcMenuBar::new(
+                    &mut *nc,
+                    &[
+                        NcMenuBarItem::new("File")
+                            .shortcut(NcInput::with_alt('f'))
+                            .submenu(vec![
+                                NcMenuBarItem::new("New"),
+                                NcMenuBarItem::separator(),
+                                NcMenuBarItem::new("Quit").shortcut(NcInput::with_ctrl('q')),
+                            ]),
+                        NcMenuBarItem::new("Help").submenu(vec![NcMenuBarItem::new("About")]),
+                    ],
+                )
+                .unwrap();
+
+                // nothing is selected while rolled up
+                assert_eq![bar.selected(), None];
+                assert_eq![bar.handle_input(&key(NcKey::Enter)), None];
+
+                // the section shortcut unrolls the section
+                assert_eq![bar.handle_input(&NcInput::with_alt('f')), None];
+                assert_eq![bar.selected(), Some("New")];
+
+                // the separator is skipped
+                assert_eq![bar.handle_input(&key(NcKey::Down)), None];
+                assert_eq![bar.selected(), Some("Quit")];
+
+                assert_eq![bar.handle_input(&key(NcKey::Right)), None];
+                assert_eq![bar.selected(), Some("About")];
+                assert_eq![bar.handle_input(&key(NcKey::Enter)), Some("About")];
+                assert_eq![bar.selected(), None];
+
+                // item shortcuts work while rolled up
+                assert_eq![bar.handle_input(&NcInput::with_ctrl('q')), Some("Quit")];
+            }
+            assert![NcMenuBar::new(&mut *nc, &[NcMenuBarItem::new("File")]).is_err()];
+            assert![NcMenuBar::new(
+                &mut *nc,
+                &[NcMenuBarItem::new("File")
+                    .submenu(vec![NcMenuBarItem::new("Recent").submenu(vec![])])]
+            )
+            .is_err()];
+            notcurses_stop(nc);
+        }
+    }
+}
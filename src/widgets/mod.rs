@@ -16,6 +16,8 @@ pub use plot::*;
 pub use progbar::*;
 pub use reader::*;
 pub use reel::*;
-pub use selector::{NcSelector, NcSelectorBuilder, NcSelectorItem, NcSelectorOptions};
+pub use selector::{
+    NcSelector, NcSelectorBuilder, NcSelectorItem, NcSelectorOptions, NcSelectorResult,
+};
 pub use tabbed::*;
 pub use tree::*;
@@ -0,0 +1,201 @@
+//! `NcChecklist`
+
+use core::marker::PhantomData;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    rstring,
+    widgets::{NcMultiSelector, NcMultiSelectorOptions},
+    NcError, NcInput, NcKey, NcPlane, NcResult,
+};
+
+/// A list of items that can be checked independently, that owns its
+/// [`NcMultiSelector`] and destroys it when dropped.
+///
+/// Besides the keyboard navigation of [`NcMultiSelector::offer_input`],
+/// it allows to change the selection state of any item, and to query the
+/// selected items by their option.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// # let nc = unsafe { Nc::new()? };
+/// # let stdplane = unsafe { nc.stdplane() };
+/// let plane = NcPlane::new_child_sized(stdplane, 0, 0, 10, 30)?;
+/// let (a, b) = (NcString::new("a"), NcString::new("first"));
+/// let items = [NcMultiSelectorItem::new(&a, &b, false), NcMultiSelectorItem::new_empty()];
+/// let mut checklist = NcChecklist::new(plane, &NcMultiSelectorOptions::new(&items))?;
+/// checklist.select_all()?;
+/// assert_eq![checklist.selected_items(), ["a"]];
+/// # drop(checklist);
+/// # unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+#[doc(alias = "NcMultiselector")]
+pub struct NcChecklist<'a> {
+    ms: *mut NcMultiSelector,
+    options: Vec<String>,
+    // the multiselector doesn't expose its current item, so it's tracked here
+    current: usize,
+    states: Vec<bool>,
+    _plane: PhantomData<&'a mut NcPlane>,
+}
+
+impl<'a> NcChecklist<'a> {
+    /// New `NcChecklist` over `plane`, which it takes care of destroying.
+    ///
+    /// The list of items in `options` must be terminated by an
+    /// [empty item][crate::widgets::NcMultiSelectorItem::new_empty].
+    pub fn new(plane: &'a mut NcPlane, options: &NcMultiSelectorOptions) -> NcResult<Self> {
+        let mut labels = Vec::new();
+        let mut states = Vec::new();
+        if !options.items.is_null() {
+            let mut item = options.items;
+            while !unsafe { (*item).option }.is_null() {
+                labels.push(rstring![(*item).option].to_string());
+                states.push(unsafe { (*item).selected });
+                item = unsafe { item.add(1) };
+            }
+        }
+        let ms = NcMultiSelector::new(plane, options)? as *mut NcMultiSelector;
+        Ok(Self { ms, options: labels, current: 0, states, _plane: PhantomData })
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.options.len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    /// Returns the index of the current item.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the selection state of each item.
+    pub fn states(&self) -> &[bool] {
+        &self.states
+    }
+
+    /// Returns the options of the selected items.
+    pub fn selected_items(&self) -> Vec<&str> {
+        self.states
+            .iter()
+            .zip(self.options.iter())
+            .filter_map(|(selected, option)| selected.then_some(option.as_str()))
+            .collect()
+    }
+
+    /// Offers the `input` to the checklist, and returns the options of the
+    /// selected items if it's an [`Enter`][NcKey::Enter].
+    ///
+    /// [`Up`][NcKey::Up] and [`Down`][NcKey::Down] move the current item,
+    /// and the spacebar toggles it. Any other input is ignored.
+    pub fn handle_input(&mut self, input: &NcInput) -> NcResult<Option<Vec<String>>> {
+        if input.id == NcKey::Enter.into() {
+            return Ok(Some(
+                self.selected_items()
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            ));
+        }
+        if input.id == NcKey::Up.into() {
+            if self.multiselector().offer_input(*input) && !self.is_empty() {
+                self.current = (self.current + self.len() - 1) % self.len();
+            }
+        } else if input.id == NcKey::Down.into() {
+            if self.multiselector().offer_input(*input) && !self.is_empty() {
+                self.current = (self.current + 1) % self.len();
+            }
+        } else if input.id == ' ' as u32 && self.multiselector().offer_input(*input) {
+            self.update_states()?;
+        }
+        Ok(None)
+    }
+
+    /// Toggles the selection state of the item at `index`.
+    pub fn toggle_item(&mut self, index: usize) -> NcResult<()> {
+        if index >= self.len() {
+            return Err(NcError::new_msg(&format![
+                "NcChecklist.toggle_item({}) out of bounds ({} items)",
+                index,
+                self.len()
+            ]));
+        }
+        let steps = (index + self.len() - self.current) % self.len();
+        self.down(steps);
+        self.toggle_current();
+        self.down(self.len() - steps);
+        self.update_states()
+    }
+
+    /// Selects all the items.
+    pub fn select_all(&mut self) -> NcResult<()> {
+        self.set_all(true)
+    }
+
+    /// Deselects all the items.
+    pub fn deselect_all(&mut self) -> NcResult<()> {
+        self.set_all(false)
+    }
+
+    /// Sets the selection state of all the items.
+    fn set_all(&mut self, selected: bool) -> NcResult<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        // a whole lap around the list ends at the current item
+        for _ in 0..self.len() {
+            if self.states[self.current] != selected {
+                self.toggle_current();
+            }
+            self.down(1);
+        }
+        self.update_states()
+    }
+
+    /// Returns a mutable reference to the underlying [`NcMultiSelector`].
+    fn multiselector(&mut self) -> &mut NcMultiSelector {
+        unsafe { &mut *self.ms }
+    }
+
+    /// Reads back the selection state of each item from the multiselector.
+    fn update_states(&mut self) -> NcResult<()> {
+        let len = self.len() as u32;
+        self.states = self.multiselector().selected(len)?;
+        Ok(())
+    }
+
+    /// Toggles the current item.
+    fn toggle_current(&mut self) {
+        self.multiselector().offer_input(NcInput::new(' '));
+    }
+
+    /// Moves down `steps` items, wrapping around.
+    fn down(&mut self, steps: usize) {
+        let down = NcInput { id: NcKey::Down.into(), ..NcInput::new_empty() };
+        for _ in 0..steps % self.len() {
+            self.multiselector().offer_input(down);
+        }
+        self.current = (self.current + steps) % self.len();
+    }
+}
+
+impl Drop for NcChecklist<'_> {
+    fn drop(&mut self) {
+        self.multiselector().destroy();
+    }
+}
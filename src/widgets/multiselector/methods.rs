@@ -0,0 +1,138 @@
+use core::ptr::null;
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec, vec::Vec};
+
+use crate::{
+    c_api, error, error_ref_mut,
+    widgets::{NcMultiSelector, NcMultiSelectorItem, NcMultiSelectorOptions},
+    NcChannels, NcInput, NcPlane, NcResult, NcString,
+};
+
+impl NcMultiSelector {
+    /// Creates a multiselector over a `plane`.
+    ///
+    /// The multiselector will take care of destroying the plane.
+    ///
+    /// *C style function: [ncmultiselector_create()][c_api::ncmultiselector_create].*
+    pub fn new<'a>(
+        plane: &mut NcPlane,
+        options: &NcMultiSelectorOptions,
+    ) -> NcResult<&'a mut Self> {
+        error_ref_mut![
+            unsafe { c_api::ncmultiselector_create(plane, options) },
+            "NcMultiSelector.new()"
+        ]
+    }
+
+    /// Destroys the `NcMultiSelector`.
+    ///
+    /// Note that this also destroys the [`NcPlane`].
+    ///
+    /// *C style function: [ncmultiselector_destroy()][c_api::ncmultiselector_destroy].*
+    pub fn destroy(&mut self) {
+        unsafe { c_api::ncmultiselector_destroy(self) };
+    }
+
+    /// Offers an input to the multiselector.
+    ///
+    /// If it's relevant, this function returns true, and the input ought not be
+    /// processed further. If it's irrelevant to the multiselector, false is
+    /// returned.
+    ///
+    /// Relevant inputs include:
+    /// - a mouse click on an item.
+    /// - a mouse scrollwheel event.
+    /// - a mouse click on the scrolling arrows.
+    /// - up, down, pgup, or pgdown (navigates among items).
+    /// - space (toggles the current item).
+    ///
+    /// *C style function: [ncmultiselector_offer_input()][c_api::ncmultiselector_offer_input].*
+    pub fn offer_input(&mut self, input: impl Into<NcInput>) -> bool {
+        unsafe { c_api::ncmultiselector_offer_input(self, &input.into()) }
+    }
+
+    /// Returns the [`NcPlane`] backing this `NcMultiSelector`.
+    ///
+    /// *C style function: [ncmultiselector_plane()][c_api::ncmultiselector_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![
+            unsafe { c_api::ncmultiselector_plane(self) },
+            "NcMultiSelector.plane()"
+        ]
+    }
+
+    /// Returns the selection state of each item.
+    ///
+    /// `itemcount` must be the current number of items.
+    ///
+    /// *C style function: [ncmultiselector_selected()][c_api::ncmultiselector_selected].*
+    pub fn selected(&mut self, itemcount: u32) -> NcResult<Vec<bool>> {
+        let mut selected = vec![false; itemcount as usize];
+        error![
+            unsafe { c_api::ncmultiselector_selected(self, selected.as_mut_ptr(), itemcount) },
+            &format!["NcMultiSelector.selected({})", itemcount],
+            selected
+        ]
+    }
+}
+
+impl NcMultiSelectorItem {
+    /// New item.
+    pub fn new(option: &NcString, desc: &NcString, selected: bool) -> Self {
+        Self { option: option.as_ptr(), desc: desc.as_ptr(), selected }
+    }
+
+    /// New empty `NcMultiSelectorItem`, which terminates a list of items.
+    pub fn new_empty() -> Self {
+        Self { option: null(), desc: null(), selected: false }
+    }
+}
+
+/// # `NcMultiSelectorOptions` constructors
+impl NcMultiSelectorOptions {
+    /// New `NcMultiSelectorOptions` with just the list of items.
+    ///
+    /// The list must be terminated by an [empty item][NcMultiSelectorItem::new_empty].
+    pub fn new(items: &[NcMultiSelectorItem]) -> Self {
+        Self::with_all_options(None, None, None, items, 0, 0, 0, 0, 0, 0)
+    }
+
+    /// New `NcMultiSelectorOptions` with all options.
+    ///
+    /// The list must be terminated by an [empty item][NcMultiSelectorItem::new_empty].
+    pub fn with_all_options(
+        title: Option<&NcString>,
+        secondary: Option<&NcString>,
+        footer: Option<&NcString>,
+        items: &[NcMultiSelectorItem],
+        max_display: u32,
+        opchannels: impl Into<NcChannels>,
+        descchannels: impl Into<NcChannels>,
+        titlechannels: impl Into<NcChannels>,
+        footchannels: impl Into<NcChannels>,
+        boxchannels: impl Into<NcChannels>,
+    ) -> Self {
+        let title_ptr = if let Some(s) = title { s.as_ptr() } else { null() };
+        let secondary_ptr = if let Some(s) = secondary { s.as_ptr() } else { null() };
+        let footer_ptr = if let Some(s) = footer { s.as_ptr() } else { null() };
+
+        Self {
+            title: title_ptr,
+            secondary: secondary_ptr,
+            footer: footer_ptr,
+            // initial items, descriptions and selection states
+            items: items.as_ptr(),
+            // maximum number of options to display at once,
+            // 0 to use all available space
+            maxdisplay: max_display,
+            // exhaustive styling options
+            opchannels: opchannels.into().into(),
+            descchannels: descchannels.into().into(),
+            titlechannels: titlechannels.into().into(),
+            footchannels: footchannels.into().into(),
+            boxchannels: boxchannels.into().into(),
+            flags: 0x0,
+        }
+    }
+}
@@ -1,7 +1,26 @@
 //! `NcMultiSelector` widget.
 
+// functions already exported by bindgen : 5
+// ------------------------------------------
+// (#) test:  1
+// (W) wrap:  5
+// ------------------------------------------
+//W  ncmultiselector_create
+//W  ncmultiselector_destroy
+//W  ncmultiselector_offer_input
+//W  ncmultiselector_plane
+//W# ncmultiselector_selected
+
 use crate::c_api::ffi;
 
+mod checklist;
+mod methods;
+
+#[cfg(test)]
+mod test;
+
+pub use checklist::NcChecklist;
+
 /// high-level widget for selecting items from a set
 pub type NcMultiSelector = ffi::ncmultiselector;
 
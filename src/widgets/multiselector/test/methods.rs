@@ -0,0 +1,70 @@
+//! Test `NcMultiSelector` & `NcChecklist` methods.
+
+use crate::{
+    c_api::{notcurses_init_test, notcurses_stop},
+    widgets::{NcChecklist, NcMultiSelectorItem, NcMultiSelectorOptions},
+    NcInput, NcKey, NcPlane, NcString,
+};
+use serial_test::serial;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+fn key(key: NcKey) -> NcInput {
+    NcInput { id: key.into(), ..NcInput::new_empty() }
+}
+
+#[test]
+#[serial]
+fn checklist() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_child_sized(nc.stdplane(), 0, 0, 10, 30).unwrap();
+
+        let strings: Vec<_> = [("a", "first"), ("b", "second"), ("c", "third")]
+            .iter()
+            .map(|(o, d)| (NcString::new(o), NcString::new(d)))
+            .collect();
+        let mut items: Vec<_> = strings
+            .iter()
+            .enumerate()
+            .map(|(i, (o, d))| NcMultiSelectorItem::new(o, d, i == 2))
+            .collect();
+        items.push(NcMultiSelectorItem::new_empty());
+        let options = NcMultiSelectorOptions::new(&items);
+
+        let mut checklist = NcChecklist::new(plane, &options).unwrap();
+        assert_eq![checklist.len(), 3];
+        assert_eq![checklist.selected_items(), ["c"]];
+
+        // the spacebar toggles the current item
+        assert_eq![checklist.handle_input(&NcInput::new(' ')).unwrap(), None];
+        assert_eq![checklist.states(), [true, false, true]];
+        checklist.handle_input(&key(NcKey::Down)).unwrap();
+        assert_eq![checklist.current(), 1];
+        checklist.handle_input(&NcInput::new(' ')).unwrap();
+        assert_eq![checklist.selected_items(), ["a", "b", "c"]];
+
+        assert_eq![
+            checklist.handle_input(&key(NcKey::Enter)).unwrap(),
+            Some(vec!["a".into(), "b".into(), "c".into()])
+        ];
+
+        checklist.toggle_item(0).unwrap();
+        assert_eq![checklist.states(), [false, true, true]];
+        assert![checklist.toggle_item(3).is_err()];
+
+        checklist.deselect_all().unwrap();
+        assert![checklist.selected_items().is_empty()];
+        checklist.select_all().unwrap();
+        assert_eq![checklist.states(), [true, true, true]];
+
+        // the current item didn't change
+        assert_eq![checklist.current(), 1];
+        checklist.handle_input(&NcInput::new(' ')).unwrap();
+        assert_eq![checklist.states(), [true, false, true]];
+
+        drop(checklist);
+        notcurses_stop(nc);
+    }
+}
@@ -0,0 +1,3 @@
+//! `NcMultiSelector` tests.
+
+mod methods;
@@ -1,7 +1,13 @@
 //! `NcReader*` methods and associated functions.
 
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
 use super::{NcReader, NcReaderOptions};
-use crate::{c_api::ncreader_create, error_ref_mut, NcPlane, NcResult};
+use crate::{
+    c_api::{self, ncreader_create},
+    cstring, error, error_ref_mut, rstring_free, NcInput, NcPlane, NcResult,
+};
 
 /// # `NcReaderOptions` Constructors
 impl NcReaderOptions {
@@ -33,3 +39,104 @@ impl NcReader {
         error_ref_mut![unsafe { ncreader_create(plane, options) }]
     }
 }
+
+/// # `NcReader` methods
+impl NcReader {
+    /// Blows away the current contents, returning the cursor to the origin.
+    ///
+    /// *C style function: [ncreader_clear()][c_api::ncreader_clear].*
+    pub fn clear(&mut self) {
+        unsafe { c_api::ncreader_clear(self) };
+    }
+
+    /// Returns the current contents of the reader, as a single line.
+    ///
+    /// *C style function: [ncreader_contents()][c_api::ncreader_contents].*
+    pub fn contents(&self) -> String {
+        rstring_free![c_api::ncreader_contents(self)]
+    }
+
+    /// Destroys the `NcReader`, and its bound [`NcPlane`].
+    ///
+    /// *C style function: [ncreader_destroy()][c_api::ncreader_destroy].*
+    pub fn destroy(&mut self) {
+        unsafe { c_api::ncreader_destroy(self, core::ptr::null_mut()) };
+    }
+
+    /// Moves the cursor one cell down.
+    ///
+    /// Returns an error if the cursor can't be moved.
+    ///
+    /// *C style function: [ncreader_move_down()][c_api::ncreader_move_down].*
+    pub fn move_down(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncreader_move_down(self) },
+            "NcReader.move_down()"
+        ]
+    }
+
+    /// Moves the cursor one cell left.
+    ///
+    /// Returns an error if the cursor can't be moved.
+    ///
+    /// *C style function: [ncreader_move_left()][c_api::ncreader_move_left].*
+    pub fn move_left(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncreader_move_left(self) },
+            "NcReader.move_left()"
+        ]
+    }
+
+    /// Moves the cursor one cell right.
+    ///
+    /// Returns an error if the cursor can't be moved.
+    ///
+    /// *C style function: [ncreader_move_right()][c_api::ncreader_move_right].*
+    pub fn move_right(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncreader_move_right(self) },
+            "NcReader.move_right()"
+        ]
+    }
+
+    /// Moves the cursor one cell up.
+    ///
+    /// Returns an error if the cursor can't be moved.
+    ///
+    /// *C style function: [ncreader_move_up()][c_api::ncreader_move_up].*
+    pub fn move_up(&mut self) -> NcResult<()> {
+        error![
+            unsafe { c_api::ncreader_move_up(self) },
+            "NcReader.move_up()"
+        ]
+    }
+
+    /// Offers an input to the reader.
+    ///
+    /// If it's relevant, this function returns true, and the input ought not be
+    /// processed further. If it's irrelevant to the reader, false is returned.
+    ///
+    /// *C style function: [ncreader_offer_input()][c_api::ncreader_offer_input].*
+    pub fn offer_input(&mut self, input: impl Into<NcInput>) -> bool {
+        unsafe { c_api::ncreader_offer_input(self, &input.into()) }
+    }
+
+    /// Returns the [`NcPlane`] backing this `NcReader`.
+    ///
+    /// *C style function: [ncreader_plane()][c_api::ncreader_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::ncreader_plane(self) }, "NcReader.plane()"]
+    }
+
+    /// Writes an `egc` at the cursor position, overwriting the previous
+    /// content, and advances the cursor.
+    ///
+    /// *C style function: [ncreader_write_egc()][c_api::ncreader_write_egc].*
+    pub fn write_egc(&mut self, egc: &str) -> NcResult<()> {
+        let cs = cstring![egc];
+        error![
+            unsafe { c_api::ncreader_write_egc(self, cs.as_ptr()) },
+            &format!["NcReader.write_egc({:?})", egc]
+        ]
+    }
+}
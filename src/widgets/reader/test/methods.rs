@@ -0,0 +1,76 @@
+//! Test `NcReader` & `NcTextField` methods.
+
+use crate::{
+    c_api::{notcurses_init_test, notcurses_stop},
+    widgets::{NcTextField, NcTextFieldEvent, NcTextFieldOptions},
+    NcCol, NcInput, NcKey, NcRow,
+};
+use serial_test::serial;
+
+fn key(key: NcKey) -> NcInput {
+    NcInput { id: key.into(), ..NcInput::new_empty() }
+}
+
+#[test]
+#[serial]
+fn textfield() {
+    unsafe {
+        let nc = notcurses_init_test();
+        {
+            let options = NcTextFieldOptions { text: "hello".into(), ..Default::default() };
+            let mut field =
+                NcTextField::new(nc, (NcRow(0), NcCol(0)), (NcRow(1), NcCol(20)), options).unwrap();
+            assert_eq![field.contents(), "hello"];
+
+            // backspace
+            assert_eq![
+                field.handle_input(&key(NcKey::Backspace)),
+                NcTextFieldEvent::Changed
+            ];
+            assert_eq![field.contents(), "hell"];
+
+            // home & end, the text is overwritten
+            assert_eq![
+                field.handle_input(&key(NcKey::Home)),
+                NcTextFieldEvent::Moved
+            ];
+            field.handle_input(&NcInput::new('y'));
+            assert_eq![field.contents(), "yell"];
+            assert_eq![
+                field.handle_input(&key(NcKey::End)),
+                NcTextFieldEvent::Moved
+            ];
+            assert_eq![
+                field.handle_input(&key(NcKey::End)),
+                NcTextFieldEvent::Ignored
+            ];
+            assert_eq![
+                field.handle_input(&NcInput::new('s')),
+                NcTextFieldEvent::Changed
+            ];
+
+            // submission
+            assert_eq![
+                field.handle_input(&key(NcKey::Enter)),
+                NcTextFieldEvent::Submitted("yells".into())
+            ];
+            assert_eq![
+                field.handle_input(&key(NcKey::Esc)),
+                NcTextFieldEvent::Cancelled
+            ];
+
+            field.clear();
+            assert_eq![field.contents(), ""];
+        }
+        {
+            let options = NcTextFieldOptions { max_chars: Some(2), ..Default::default() };
+            let mut field =
+                NcTextField::new(nc, (NcRow(0), NcCol(0)), (NcRow(1), NcCol(20)), options).unwrap();
+            for ch in "abc".chars() {
+                field.handle_input(&NcInput::new(ch));
+            }
+            assert_eq![field.contents(), "ab"];
+        }
+        notcurses_stop(nc);
+    }
+}
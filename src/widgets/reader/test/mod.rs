@@ -0,0 +1,3 @@
+//! `NcReader` tests.
+
+mod methods;
@@ -0,0 +1,246 @@
+//! `NcTextField`, `NcTextFieldOptions` & `NcTextFieldEvent`
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::{
+    egc::str_width,
+    widgets::{NcReader, NcReaderOptions},
+    Nc, NcInput, NcInputType, NcKey, NcPlane, NcResult, NcYx,
+};
+
+/// The options of an [`NcTextField`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcTextFieldOptions {
+    /// The maximum number of characters, if any.
+    pub max_chars: Option<usize>,
+
+    /// Whether [`Enter`][NcKey::Enter] starts a new line instead of submitting
+    /// the text, which is then submitted with `Alt+Enter`.
+    pub allow_newline: bool,
+
+    /// Whether to show the terminal cursor and manage its placement.
+    pub cursor: bool,
+
+    /// The initial text.
+    pub text: String,
+}
+
+/// The outcome of offering an input to an [`NcTextField`], returned by
+/// [`handle_input`][NcTextField#method.handle_input].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NcTextFieldEvent {
+    /// The text was submitted.
+    Submitted(String),
+
+    /// The text was modified.
+    Changed,
+
+    /// The cursor was moved.
+    Moved,
+
+    /// The input was cancelled.
+    Cancelled,
+
+    /// The input was not relevant.
+    Ignored,
+}
+
+/// An editable text field, that owns its [`NcReader`] and destroys it when
+/// dropped.
+///
+/// On top of the editing provided by [`NcReader::offer_input`], it supports
+/// the [`Home`][NcKey::Home] and [`End`][NcKey::End] keys, submitting with
+/// [`Enter`][NcKey::Enter] and cancelling with [`Esc`][NcKey::Esc].
+///
+/// Note that the text is overwritten at the cursor, not inserted.
+///
+/// # Example
+/// ```no_run
+/// # use libnotcurses_sys::{*, widgets::*};
+/// # fn main() -> NcResult<()> {
+/// let nc = unsafe { Nc::new()? };
+/// let mut field = NcTextField::new(
+///     nc,
+///     (NcRow(1), NcCol(1)),
+///     (NcRow(1), NcCol(30)),
+///     NcTextFieldOptions::default(),
+/// )?;
+///
+/// let mut input = NcInput::new_empty();
+/// let text = loop {
+///     field.nc().render()?;
+///     field.nc().get_blocking(Some(&mut input))?;
+///     match field.handle_input(&input) {
+///         NcTextFieldEvent::Submitted(text) => break Some(text),
+///         NcTextFieldEvent::Cancelled => break None,
+///         _ => (),
+///     }
+/// };
+/// drop(field);
+/// unsafe { nc.stop()? };
+/// # Ok(())
+/// # }
+/// ```
+pub struct NcTextField<'a> {
+    nc: &'a mut Nc,
+    reader: *mut NcReader,
+    max_chars: Option<usize>,
+    allow_newline: bool,
+    contents: String,
+}
+
+impl<'a> NcTextField<'a> {
+    /// New `NcTextField` of `size` at `origin`, over the standard plane.
+    pub fn new(
+        nc: &'a mut Nc,
+        origin: impl Into<NcYx>,
+        size: impl Into<NcYx>,
+        options: NcTextFieldOptions,
+    ) -> NcResult<Self> {
+        let (origin, size) = (origin.into(), size.into());
+        let plane = NcPlane::new_child_sized(
+            unsafe { nc.stdplane() },
+            origin.y.0 as i32,
+            origin.x.0 as i32,
+            size.y.0,
+            size.x.0,
+        )?;
+
+        let mut reader_options = NcReaderOptions::new();
+        if options.cursor {
+            reader_options.flags |= NcReaderOptions::CURSOR as u64;
+        }
+        if options.allow_newline {
+            reader_options.flags |= NcReaderOptions::VERSCROLL as u64;
+        }
+        let reader = NcReader::with_options(plane, &reader_options)? as *mut NcReader;
+
+        let mut field = Self {
+            nc,
+            reader,
+            max_chars: options.max_chars,
+            allow_newline: options.allow_newline,
+            contents: String::new(),
+        };
+        let mut buf = [0; 4];
+        for ch in options
+            .text
+            .chars()
+            .take(options.max_chars.unwrap_or(usize::MAX))
+        {
+            field.reader().write_egc(ch.encode_utf8(&mut buf))?;
+        }
+        field.contents = field.reader().contents();
+        Ok(field)
+    }
+
+    /// Returns a mutable reference to the [`Nc`] context, e.g. for rendering.
+    pub fn nc(&mut self) -> &mut Nc {
+        self.nc
+    }
+
+    /// Returns a mutable reference to the underlying [`NcReader`].
+    pub fn reader(&mut self) -> &mut NcReader {
+        unsafe { &mut *self.reader }
+    }
+
+    /// Returns the current text.
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    /// Clears the text, returning the cursor to the origin.
+    pub fn clear(&mut self) {
+        self.reader().clear();
+        self.contents.clear();
+    }
+
+    /// Offers the `input` to the text field, and returns its outcome.
+    pub fn handle_input(&mut self, input: &NcInput) -> NcTextFieldEvent {
+        if input.evtype == NcInputType::Release as u32 {
+            return NcTextFieldEvent::Ignored;
+        }
+        let key = NcKey(input.id);
+        if key == NcKey::Enter {
+            if self.allow_newline && !input.alt_p() {
+                return self.newline();
+            }
+            return NcTextFieldEvent::Submitted(self.contents.clone());
+        } else if key == NcKey::Esc {
+            return NcTextFieldEvent::Cancelled;
+        } else if key == NcKey::Home {
+            return self.home();
+        } else if key == NcKey::End {
+            return self.end();
+        }
+
+        let is_text = !NcKey::is(input.id) && (input.nomod_p() || input.shift_p());
+        if is_text
+            && self
+                .max_chars
+                .map_or(false, |max| self.contents.chars().count() >= max)
+        {
+            return NcTextFieldEvent::Ignored;
+        }
+        if !self.reader().offer_input(*input) {
+            return NcTextFieldEvent::Ignored;
+        }
+        let contents = self.reader().contents();
+        if contents == self.contents {
+            NcTextFieldEvent::Moved
+        } else {
+            self.contents = contents;
+            NcTextFieldEvent::Changed
+        }
+    }
+
+    /// Moves the cursor to the beginning of the line.
+    fn home(&mut self) -> NcTextFieldEvent {
+        let mut moved = NcTextFieldEvent::Ignored;
+        while self.cursor_yx().1 > 0 && self.reader().move_left().is_ok() {
+            moved = NcTextFieldEvent::Moved;
+        }
+        moved
+    }
+
+    /// Moves the cursor to the end of the text in the line.
+    fn end(&mut self) -> NcTextFieldEvent {
+        let y = self.cursor_yx().0;
+        let end = match self
+            .reader()
+            .plane()
+            .and_then(|plane| plane.row_as_string(y))
+        {
+            Ok(row) => str_width(&row),
+            Err(_) => return NcTextFieldEvent::Ignored,
+        };
+        let mut moved = NcTextFieldEvent::Ignored;
+        while self.cursor_yx().1 < end && self.reader().move_right().is_ok() {
+            moved = NcTextFieldEvent::Moved;
+        }
+        moved
+    }
+
+    /// Moves the cursor to the beginning of the next line.
+    fn newline(&mut self) -> NcTextFieldEvent {
+        if self.reader().move_down().is_err() {
+            return NcTextFieldEvent::Ignored;
+        }
+        self.home();
+        NcTextFieldEvent::Moved
+    }
+
+    /// Returns the cursor position in the backing plane.
+    fn cursor_yx(&mut self) -> (u32, u32) {
+        self.reader()
+            .plane()
+            .map_or((0, 0), |plane| plane.cursor_yx())
+    }
+}
+
+impl Drop for NcTextField<'_> {
+    fn drop(&mut self) {
+        self.reader().destroy();
+    }
+}
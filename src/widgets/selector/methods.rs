@@ -4,12 +4,15 @@ use core::{
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::string::{String, ToString};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 use crate::{
-    c_api, cstring, error, error_ref_mut, error_str,
-    widgets::{NcSelector, NcSelectorBuilder, NcSelectorItem, NcSelectorOptions},
-    NcChannels, NcInput, NcPlane, NcResult, NcString,
+    c_api, cstring, error, error_ref_mut, error_str, rstring,
+    widgets::{NcSelector, NcSelectorBuilder, NcSelectorItem, NcSelectorOptions, NcSelectorResult},
+    NcChannels, NcInput, NcKey, NcPlane, NcResult, NcString,
 };
 
 impl NcSelector {
@@ -106,6 +109,54 @@ impl NcSelector {
         let cstr: *const c_char = unsafe { c_api::ncselector_previtem(self) };
         error_str![cstr, "Calling selector.previtem"]
     }
+
+    /// Adds an item with an `option` and its description.
+    ///
+    /// *C style function: [ncselector_additem()][c_api::ncselector_additem].*
+    pub fn add_item(&mut self, option: &str, desc: &str) -> NcResult<()> {
+        let (o, d) = (NcString::new(option), NcString::new(desc));
+        error![
+            unsafe { c_api::ncselector_additem(self, &NcSelectorItem::new(&o, &d)) },
+            &format!["NcSelector.add_item({:?}, {:?})", option, desc]
+        ]
+    }
+
+    /// Removes the item with the `option`.
+    ///
+    /// Returns an error if there's no such item.
+    ///
+    /// *C style function: [ncselector_delitem()][c_api::ncselector_delitem].*
+    pub fn remove_item(&mut self, option: &str) -> NcResult<()> {
+        let cs = cstring![option];
+        error![
+            unsafe { c_api::ncselector_delitem(self, cs.as_ptr()) },
+            &format!["NcSelector.remove_item({:?})", option]
+        ]
+    }
+
+    /// Returns the selected option, if there's one.
+    ///
+    /// *C style function: [ncselector_selected()][c_api::ncselector_selected].*
+    pub fn selected_item(&self) -> Option<&str> {
+        let res = unsafe { c_api::ncselector_selected(self) };
+        if res.is_null() {
+            None
+        } else {
+            Some(rstring![res])
+        }
+    }
+
+    /// Offers an input to the selector, and returns the resulting selection.
+    ///
+    /// The navigation inputs are the ones handled by
+    /// [`offer_input`][NcSelector#method.offer_input], and pressing
+    /// [`Enter`][NcKey::Enter] accepts the selected option.
+    ///
+    /// *(No equivalent C style function)*
+    pub fn handle_input(&mut self, input: &NcInput) -> NcSelectorResult {
+        let accepted = !self.offer_input(*input) && input.id == NcKey::Enter.into();
+        NcSelectorResult { accepted, selected: self.selected_item().map(String::from) }
+    }
 }
 
 impl NcSelectorItem {
@@ -19,9 +19,15 @@
 
 use crate::c_api::ffi;
 
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
 mod builder;
 mod methods;
 
+#[cfg(test)]
+mod test;
+
 pub use builder::NcSelectorBuilder;
 
 /// High-level widget for selecting one item from a set.
@@ -32,3 +38,14 @@ pub type NcSelectorOptions = ffi::ncselector_options;
 
 /// Item structure for [`NcSelector`].
 pub type NcSelectorItem = ffi::ncselector_item;
+
+/// The outcome of offering an input to an [`NcSelector`], returned by
+/// [`handle_input`][NcSelector#method.handle_input].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NcSelectorResult {
+    /// Whether the input accepted the selected option.
+    pub accepted: bool,
+
+    /// The selected option, if there's one.
+    pub selected: Option<String>,
+}
@@ -0,0 +1,58 @@
+//! Test `NcSelector` methods and associated functions.
+
+use crate::{
+    c_api::{notcurses_init_test, notcurses_stop},
+    widgets::{NcSelector, NcSelectorResult},
+    NcInput, NcInputType, NcKey, NcPlane,
+};
+use serial_test::serial;
+
+fn key(key: NcKey) -> NcInput {
+    NcInput { id: key.into(), evtype: NcInputType::Press as u32, ..NcInput::new_empty() }
+}
+
+#[test]
+#[serial]
+fn selector_navigation() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_child_sized(nc.stdplane(), 0, 0, 10, 30).unwrap();
+        let selector = NcSelector::builder()
+            .title("title")
+            .secondary("secondary")
+            .footer("footer")
+            .item("a", "first")
+            .item("b", "second")
+            .item("c", "third")
+            .finish(plane)
+            .unwrap();
+        assert_eq![selector.selected_item(), Some("a")];
+
+        let result = selector.handle_input(&key(NcKey::Down));
+        assert_eq![
+            result,
+            NcSelectorResult { accepted: false, selected: Some("b".into()) }
+        ];
+
+        // moving up wraps around
+        selector.handle_input(&key(NcKey::Up));
+        let result = selector.handle_input(&key(NcKey::Up));
+        assert_eq![result.selected.as_deref(), Some("c")];
+
+        let result = selector.handle_input(&key(NcKey::Enter));
+        assert_eq![
+            result,
+            NcSelectorResult { accepted: true, selected: Some("c".into()) }
+        ];
+
+        // adding and removing items
+        selector.add_item("d", "fourth").unwrap();
+        selector.handle_input(&key(NcKey::Down));
+        assert_eq![selector.selected_item(), Some("d")];
+        selector.remove_item("a").unwrap();
+        assert![selector.remove_item("a").is_err()];
+
+        selector.destroy().unwrap();
+        notcurses_stop(nc);
+    }
+}
@@ -0,0 +1,3 @@
+//! `NcSelector` tests.
+
+mod methods;
@@ -1,35 +1,268 @@
-//! `NcTabe` & `NcTabbed*` methods and associated functions.
+//! `NcTab` & `NcTabbed*` methods and associated functions.
+
+use core::{
+    ffi::c_void,
+    ptr::{null, null_mut},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, vec::Vec};
 
 use super::{NcTab, NcTabbed, NcTabbedOptions};
-// use crate::{error_ref_mut, ncreader_create, NcPlane, NcResult};
+use crate::{c_api, cstring, error, error_ref_mut, rstring, NcPlane, NcResult};
+
+/// A Rust closure called to draw the content of an [`NcTab`], stored in
+/// the user pointer of the tab.
+struct NcTabCallback(Box<dyn FnMut(&mut NcPlane)>);
+
+impl NcTabCallback {
+    /// Moves the closure to the heap and returns it as a user pointer.
+    fn into_curry<F: FnMut(&mut NcPlane) + 'static>(cb: F) -> *mut c_void {
+        Box::into_raw(Box::new(NcTabCallback(Box::new(cb)))) as *mut c_void
+    }
+
+    /// Drops the closure behind a user pointer returned by
+    /// [`into_curry`][Self::into_curry]. Does nothing if it's null.
+    unsafe fn drop_curry(curry: *mut c_void) {
+        if !curry.is_null() {
+            drop(Box::from_raw(curry as *mut NcTabCallback));
+        }
+    }
+}
+
+/// The C tab callback that calls the Rust closure in the `curry`.
+unsafe extern "C" fn nctab_cb_trampoline(
+    _tab: *mut NcTab,
+    plane: *mut NcPlane,
+    curry: *mut c_void,
+) {
+    if let (Some(cb), Some(plane)) = ((curry as *mut NcTabCallback).as_mut(), plane.as_mut()) {
+        (cb.0)(plane);
+    }
+}
 
 /// # `NcTabbedOptions` Constructors
 impl NcTabbedOptions {
-    /// `NcTabbedOptions` simple constructor
+    /// `NcTabbedOptions` simple constructor.
     pub const fn new() -> Self {
         Self {
-            // channels used for input
-            tchannels: 0,
-            // attributes used for input
-            tattrword: 0,
-            // bitfield of NCREADER_OPTION_*
+            // channel for the selected tab header
+            selchan: 0,
+            // channel for unselected tab headers
+            hdrchan: 0,
+            // channel for the tab separator
+            sepchan: 0,
+            // separator string, copied by the constructor
+            separator: null(),
+            // bitfield of NCTABBED_OPTION_*
             flags: 0,
         }
     }
 }
 
-/// # `NcTabbed` Constructors
+/// # `NcTabbed` Constructors & Destructors
+///
+/// The user pointers of the tabs are managed by the methods that take a
+/// closure, and must not be modified with the C API function
+/// [`nctab_set_userptr`][c_api::nctab_set_userptr].
 impl NcTabbed {
-    /// `NcTabbed` simple constructor
+    /// `NcTabbed` simple constructor.
+    ///
+    /// The `plane` is bound to the widget, and destroyed along with it.
     pub fn new<'a>(plane: &mut NcPlane) -> NcResult<&'a mut Self> {
-        Self::with_options(plane, NcTabbedOptions::new())
+        Self::with_options(plane, &NcTabbedOptions::new())
     }
 
-    /// `NcTabbed` constructor with options
+    /// `NcTabbed` constructor with options.
+    ///
+    /// *C style function: [nctabbed_create()][c_api::nctabbed_create].*
     pub fn with_options<'a>(
         plane: &mut NcPlane,
-        options: NcTabbedOptions,
+        options: &NcTabbedOptions,
     ) -> NcResult<&'a mut Self> {
-        error_ref_mut![unsafe { nctabbed_create(plane, &options) }]
+        error_ref_mut![
+            unsafe { c_api::nctabbed_create(plane, options) },
+            "NcTabbed.with_options()"
+        ]
+    }
+
+    /// Destroys the `NcTabbed`, its bound [`NcPlane`], and the closures of
+    /// its tabs.
+    ///
+    /// *C style function: [nctabbed_destroy()][c_api::nctabbed_destroy].*
+    pub fn destroy(&mut self) {
+        let mut curries = Vec::new();
+        if let Some(mut tab) = self.leftmost() {
+            for _ in 0..self.tabcount() {
+                curries.push(unsafe { c_api::nctab_set_userptr(tab, null_mut()) });
+                tab = tab.next();
+            }
+        }
+        unsafe { c_api::nctabbed_destroy(self) };
+        for curry in curries {
+            unsafe { NcTabCallback::drop_curry(curry) };
+        }
+    }
+}
+
+/// # `NcTabbed` methods
+impl NcTabbed {
+    /// Adds a new tab named `name`, after the selected one, whose content is
+    /// drawn by the `cb` closure.
+    ///
+    /// The first tab added is selected.
+    ///
+    /// *C style function: [nctabbed_add()][c_api::nctabbed_add].*
+    pub fn add<'a, F>(&mut self, name: &str, cb: F) -> NcResult<&'a mut NcTab>
+    where
+        F: FnMut(&mut NcPlane) + 'static,
+    {
+        let cname = cstring![name];
+        let curry = NcTabCallback::into_curry(cb);
+        let tab = unsafe {
+            c_api::nctabbed_add(
+                self,
+                null_mut(),
+                null_mut(),
+                Some(nctab_cb_trampoline),
+                cname.as_ptr(),
+                curry,
+            )
+        };
+        if tab.is_null() {
+            unsafe { NcTabCallback::drop_curry(curry) };
+        }
+        error_ref_mut![tab, &format!["NcTabbed.add({:?})", name]]
+    }
+
+    /// Removes the `tab`, dropping its closure.
+    ///
+    /// *C style function: [nctabbed_del()][c_api::nctabbed_del].*
+    pub fn del(&mut self, tab: &mut NcTab) -> NcResult<()> {
+        let curry = unsafe { c_api::nctab_userptr(tab) };
+        let res = unsafe { c_api::nctabbed_del(self, tab) };
+        if res >= c_api::NCRESULT_OK {
+            unsafe { NcTabCallback::drop_curry(curry) };
+        }
+        error![res, "NcTabbed.del()"]
+    }
+
+    /// Returns the [`NcPlane`] of the tab contents.
+    ///
+    /// *C style function: [nctabbed_content_plane()][c_api::nctabbed_content_plane].*
+    pub fn content_plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![
+            unsafe { c_api::nctabbed_content_plane(self) },
+            "NcTabbed.content_plane()"
+        ]
+    }
+
+    /// Returns the leftmost tab, or `None` if there are no tabs.
+    ///
+    /// *C style function: [nctabbed_leftmost()][c_api::nctabbed_leftmost].*
+    pub fn leftmost<'a>(&mut self) -> Option<&'a mut NcTab> {
+        unsafe { c_api::nctabbed_leftmost(self).as_mut() }
+    }
+
+    /// Selects the tab to the right of the selected one, and returns it,
+    /// or `None` if there are no tabs.
+    ///
+    /// *C style function: [nctabbed_next()][c_api::nctabbed_next].*
+    pub fn next<'a>(&mut self) -> Option<&'a mut NcTab> {
+        unsafe { c_api::nctabbed_next(self).as_mut() }
+    }
+
+    /// Returns the [`NcPlane`] bound to this widget.
+    ///
+    /// *C style function: [nctabbed_plane()][c_api::nctabbed_plane].*
+    pub fn plane(&mut self) -> NcResult<&mut NcPlane> {
+        error_ref_mut![unsafe { c_api::nctabbed_plane(self) }, "NcTabbed.plane()"]
+    }
+
+    /// Selects the tab to the left of the selected one, and returns it,
+    /// or `None` if there are no tabs.
+    ///
+    /// *C style function: [nctabbed_prev()][c_api::nctabbed_prev].*
+    pub fn prev<'a>(&mut self) -> Option<&'a mut NcTab> {
+        unsafe { c_api::nctabbed_prev(self).as_mut() }
+    }
+
+    /// Redraws the widget, calling the closure of the selected tab to draw
+    /// its content.
+    ///
+    /// *C style function: [nctabbed_redraw()][c_api::nctabbed_redraw].*
+    pub fn redraw(&mut self) {
+        unsafe { c_api::nctabbed_redraw(self) };
+    }
+
+    /// Selects the `tab`, and returns the previously selected one, if any.
+    ///
+    /// *C style function: [nctabbed_select()][c_api::nctabbed_select].*
+    pub fn select<'a>(&mut self, tab: &mut NcTab) -> Option<&'a mut NcTab> {
+        unsafe { c_api::nctabbed_select(self, tab).as_mut() }
+    }
+
+    /// Returns the selected tab, or `None` if there are no tabs.
+    ///
+    /// *C style function: [nctabbed_selected()][c_api::nctabbed_selected].*
+    pub fn selected<'a>(&mut self) -> Option<&'a mut NcTab> {
+        unsafe { c_api::nctabbed_selected(self).as_mut() }
+    }
+
+    /// Returns the number of tabs.
+    ///
+    /// *C style function: [nctabbed_tabcount()][c_api::nctabbed_tabcount].*
+    pub fn tabcount(&mut self) -> u32 {
+        unsafe { c_api::nctabbed_tabcount(self) as u32 }
+    }
+}
+
+/// # `NcTab` methods
+impl NcTab {
+    /// Returns the name of the tab.
+    ///
+    /// *C style function: [nctab_name()][c_api::nctab_name].*
+    pub fn name(&mut self) -> String {
+        String::from(rstring![c_api::nctab_name(self)])
+    }
+
+    /// Renames the tab.
+    ///
+    /// The headers are updated on the next [`redraw`][NcTabbed#method.redraw].
+    ///
+    /// *C style function: [nctab_set_name()][c_api::nctab_set_name].*
+    pub fn set_name(&mut self, name: &str) -> NcResult<()> {
+        let cname = cstring![name];
+        error![
+            unsafe { c_api::nctab_set_name(self, cname.as_ptr()) },
+            &format!["NcTab.set_name({:?})", name]
+        ]
+    }
+
+    /// Replaces the closure that draws the content of the tab with `cb`,
+    /// dropping the previous one.
+    ///
+    /// *C style function: [nctab_set_cb()][c_api::nctab_set_cb].*
+    pub fn set_cb<F>(&mut self, cb: F)
+    where
+        F: FnMut(&mut NcPlane) + 'static,
+    {
+        let old = unsafe { c_api::nctab_set_userptr(self, NcTabCallback::into_curry(cb)) };
+        unsafe { c_api::nctab_set_cb(self, Some(nctab_cb_trampoline)) };
+        unsafe { NcTabCallback::drop_curry(old) };
+    }
+
+    /// Returns the tab to the right of this one.
+    ///
+    /// *C style function: [nctab_next()][c_api::nctab_next].*
+    pub fn next<'a>(&mut self) -> &'a mut NcTab {
+        unsafe { &mut *c_api::nctab_next(self) }
+    }
+
+    /// Returns the tab to the left of this one.
+    ///
+    /// *C style function: [nctab_prev()][c_api::nctab_prev].*
+    pub fn prev<'a>(&mut self) -> &'a mut NcTab {
+        unsafe { &mut *c_api::nctab_prev(self) }
     }
 }
@@ -2,45 +2,50 @@
 
 // functions already exported by bindgen : 39
 // ------------------------------------------
+// (W) wrap: 18
+// ------------------------------------------
 //   nctab_cb
 //   nctab_move
 //   nctab_move_left
 //   nctab_move_right
-//   nctab_name
+//W  nctab_name
 //   nctab_name_width
-//   nctab_next
-//   nctab_prev
-//   nctab_set_cb
-//   nctab_set_name
+//W  nctab_next
+//W  nctab_prev
+//W  nctab_set_cb
+//W  nctab_set_name
 //   nctab_set_userptr
 //   nctab_userptr
-//   nctabbed_add
+//W  nctabbed_add
 //   nctabbed_channels
-//   nctabbed_content_plane
-//   nctabbed_create
-//   nctabbed_del
-//   nctabbed_destroy
+//W  nctabbed_content_plane
+//W  nctabbed_create
+//W  nctabbed_del
+//W  nctabbed_destroy
 //   nctabbed_ensure_selected_header_visible
-//   nctabbed_leftmost
-//   nctabbed_next
-//   nctabbed_plane
-//   nctabbed_prev
-//   nctabbed_redraw
+//W  nctabbed_leftmost
+//W  nctabbed_next
+//W  nctabbed_plane
+//W  nctabbed_prev
+//W  nctabbed_redraw
 //   nctabbed_rotate
-//   nctabbed_select
-//   nctabbed_selected
+//W  nctabbed_select
+//W  nctabbed_selected
 //   nctabbed_separator
 //   nctabbed_separator_width
 //   nctabbed_set_hdrchan
 //   nctabbed_set_selchan
 //   nctabbed_set_separator
 //   nctabbed_set_sepchan
-//   nctabbed_tabcount
+//W  nctabbed_tabcount
 //   nctablet_ncplane
 
 use crate::c_api::ffi;
 
-// mod methods;
+mod methods;
+
+#[cfg(test)]
+mod test;
 
 /// A tab for [`NcTabbed`].
 ///
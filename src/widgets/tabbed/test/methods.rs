@@ -0,0 +1,54 @@
+//! Test `NcTab` & `NcTabbed` methods.
+
+use crate::{
+    c_api::{notcurses_init_test, notcurses_stop},
+    widgets::NcTabbed,
+    NcPlane,
+};
+use core::cell::Cell;
+use serial_test::serial;
+
+#[cfg(not(feature = "std"))]
+use alloc::rc::Rc;
+#[cfg(feature = "std")]
+use std::rc::Rc;
+
+#[test]
+#[serial]
+fn tab_set_cb_and_name() {
+    unsafe {
+        let nc = notcurses_init_test();
+        let plane = NcPlane::new_child_sized(nc.stdplane(), 0, 0, 10, 40).unwrap();
+        let tabbed = NcTabbed::new(plane).unwrap();
+
+        let old_calls = Rc::new(Cell::new(0));
+        let calls = old_calls.clone();
+        let tab = tabbed
+            .add("one", move |_| calls.set(calls.get() + 1))
+            .unwrap();
+        assert_eq![tabbed.tabcount(), 1];
+        assert_eq![tab.name(), "one"];
+
+        tabbed.redraw();
+        assert_eq![old_calls.get(), 1];
+
+        // the old closure is dropped when replaced
+        let new_calls = Rc::new(Cell::new(0));
+        let calls = new_calls.clone();
+        tab.set_cb(move |plane| {
+            let _ = plane.putstr("new");
+            calls.set(calls.get() + 1)
+        });
+        assert_eq![Rc::strong_count(&old_calls), 1];
+
+        tab.set_name("uno").unwrap();
+        tabbed.redraw();
+        assert_eq![old_calls.get(), 1];
+        assert_eq![new_calls.get(), 1];
+        assert_eq![tabbed.selected().unwrap().name(), "uno"];
+
+        tabbed.destroy();
+        assert_eq![Rc::strong_count(&new_calls), 1];
+        notcurses_stop(nc);
+    }
+}
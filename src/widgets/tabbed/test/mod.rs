@@ -0,0 +1,3 @@
+//! `NcTabbed` tests.
+
+mod methods;
@@ -0,0 +1,131 @@
+//! `NcYx`, `NcRect`, `NcRow` & `NcCol`
+
+/// A vertical coordinate or length, in rows.
+///
+/// Using it instead of a bare `u32` makes passing a column where a row is
+/// expected a compile time error:
+///
+/// ```compile_fail
+/// # use libnotcurses_sys::{NcCol, NcRow, NcYx};
+/// let yx = NcYx::new(NcCol(5), NcRow(2));
+/// ```
+///
+/// See also: [`NcCol`], [`NcYx`].
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NcRow(pub u32);
+
+/// A horizontal coordinate or length, in columns.
+///
+/// Using it instead of a bare `u32` makes passing a row where a column is
+/// expected a compile time error:
+///
+/// ```compile_fail
+/// # use libnotcurses_sys::{NcCol, NcRow, NcYx};
+/// let yx = NcYx::from((NcCol(5), NcRow(2)));
+/// ```
+///
+/// See also: [`NcRow`], [`NcYx`].
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NcCol(pub u32);
+
+/// A pair of vertical (`y`) and horizontal (`x`) coordinates or lengths.
+///
+/// It's meant to be used by new APIs, which would otherwise receive two
+/// positional `u32` arguments that are easy to transpose. For that reason
+/// it can only be built from an [`NcRow`] and an [`NcCol`]:
+///
+/// ```compile_fail
+/// # use libnotcurses_sys::NcYx;
+/// let yx = NcYx::from((2, 5));
+/// ```
+///
+/// # Example
+/// ```
+/// # use libnotcurses_sys::{NcCol, NcRow, NcYx};
+/// let yx = NcYx::new(NcRow(2), NcCol(5));
+/// assert_eq![yx, NcYx::from((NcRow(2), NcCol(5)))];
+/// assert_eq![(2, 5), yx.into()];
+/// ```
+///
+/// See also: [`NcRow`], [`NcCol`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NcYx {
+    /// The vertical component.
+    pub y: NcRow,
+    /// The horizontal component.
+    pub x: NcCol,
+}
+
+impl NcYx {
+    /// New `NcYx` from a row and a column.
+    pub const fn new(y: NcRow, x: NcCol) -> Self {
+        Self { y, x }
+    }
+
+    /// Returns the vertical component.
+    pub const fn row(&self) -> NcRow {
+        self.y
+    }
+
+    /// Returns the horizontal component.
+    pub const fn col(&self) -> NcCol {
+        self.x
+    }
+}
+
+/// A rectangular area, made of an `origin` and a `size`.
+///
+/// # Example
+/// ```
+/// # use libnotcurses_sys::{NcCol, NcRect, NcRow, NcYx};
+/// let rect = NcRect::new(NcYx::new(NcRow(1), NcCol(2)), NcYx::new(NcRow(8), NcCol(27)));
+/// assert_eq![rect.size.x, NcCol(27)];
+/// ```
+///
+/// See also: [`NcYx`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NcRect {
+    /// The coordinates of the top left corner.
+    pub origin: NcYx,
+    /// The number of rows and columns.
+    pub size: NcYx,
+}
+
+impl NcRect {
+    /// New `NcRect` from its `origin` and `size`.
+    pub const fn new(origin: NcYx, size: NcYx) -> Self {
+        Self { origin, size }
+    }
+}
+
+mod core_impls {
+    use super::{NcCol, NcRow, NcYx};
+
+    crate::from_primitive![NcRow, u32];
+    crate::unit_impl_from![NcRow, u32];
+    crate::unit_impl_fmt![bases+display; NcRow];
+
+    crate::from_primitive![NcCol, u32];
+    crate::unit_impl_from![NcCol, u32];
+    crate::unit_impl_fmt![bases+display; NcCol];
+
+    impl From<(NcRow, NcCol)> for NcYx {
+        fn from(tuple: (NcRow, NcCol)) -> Self {
+            Self { y: tuple.0, x: tuple.1 }
+        }
+    }
+    impl From<NcYx> for (NcRow, NcCol) {
+        #[inline]
+        fn from(yx: NcYx) -> Self {
+            (yx.y, yx.x)
+        }
+    }
+    impl From<NcYx> for (u32, u32) {
+        #[inline]
+        fn from(yx: NcYx) -> Self {
+            (yx.y.0, yx.x.0)
+        }
+    }
+}